@@ -4,8 +4,14 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as RBAC;
-use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite};
+use crate::{
+    rbac::Rbac,
+    structs::{Capabilities, ConstraintKind},
+};
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::dispatch::DispatchResult;
 use frame_system::{Pallet as System, RawOrigin};
+use sp_std::vec;
 
 /// Assert that the last event equals the provided one.
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
@@ -13,6 +19,9 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 }
 
 const CALLER_ACCOUNT_STR: &str = "Iredia1";
+const ADMIN_ACCOUNT_STR: &str = "Iredia2";
+const NEW_OWNER_ACCOUNT_STR: &str = "Iredia3";
+const CLAIMER_ACCOUNT_STR: &str = "Iredia4";
 const GROUP_ID: [u8; 32] = *b"66736466618663776474645421676476";
 const GROUP_ID2: [u8; 32] = *b"16663776474646673646665421676476";
 const USER_ID: [u8; 32] = *b"12676474666576474646673646376637";
@@ -20,10 +29,109 @@ const ROLE_ID: [u8; 32] = *b"21676474666576474646673646376637";
 const ROLE_ID2: [u8; 32] = *b"23676474666576474646466746376631";
 const PERMISSION_ID: [u8; 32] = *b"41464667364637663721676474666576";
 const PERMISSION_ID2: [u8; 32] = *b"44464667364637663721676474666570";
+const CONSTRAINT_ID: [u8; 32] = *b"51676474666576474646673646376637";
+const EXPIRES_AT: u32 = 100;
 const ADMIN_STR: &[u8] = b"ADMIN";
 const GROUP_STR: &[u8] = b"Users";
+const USER_STR: &[u8] = b"Alice";
 const PERM_STR: &[u8] = b"CAN_DELETE";
 
+/// Fetch calls are rejected right away without the `legacy-fetch-calls` feature, which is
+/// benchmarked instead then.
+fn legacy_fetch<T: Config>(result: DispatchResult) -> Result<(), BenchmarkError> {
+    if cfg!(feature = "legacy-fetch-calls") {
+        result?;
+    } else {
+        assert_eq!(result, Err(Error::<T>::LegacyFetchCallsDisabled.into()));
+    }
+    Ok(())
+}
+
+/// Adds a delegate with all capabilities, as calls by delegates are the most expensive ones.
+fn add_admin<T: Config<EntityId = [u8; 32]>>(
+    caller: &T::AccountId,
+) -> Result<T::AccountId, BenchmarkError> {
+    let admin: T::AccountId = account(ADMIN_ACCOUNT_STR, 0, 0);
+    let capabilities = Capabilities {
+        manage_users: true,
+        manage_roles: true,
+        manage_permissions: true,
+    };
+    RBAC::<T>::add_delegate(
+        RawOrigin::Signed(caller.clone()).into(),
+        admin.clone(),
+        capabilities,
+    )?;
+    Ok(admin)
+}
+
+/// Adds a static constraint, which gets checked by each assignment, but never violated.
+fn add_static_constraint<T: Config<EntityId = [u8; 32]>>(
+    caller: &T::AccountId,
+) -> Result<(), BenchmarkError> {
+    let mut roles = vec![];
+    for i in 0..2 {
+        let mut role_id = CONSTRAINT_ID.clone();
+        role_id[0] = i as u8;
+        RBAC::<T>::add_role(
+            RawOrigin::Signed(caller.clone()).into(),
+            caller.clone(),
+            role_id,
+            ADMIN_STR.to_vec(),
+        )?;
+        roles.push(role_id);
+    }
+    RBAC::<T>::add_role_constraint(
+        RawOrigin::Signed(caller.clone()).into(),
+        caller.clone(),
+        CONSTRAINT_ID.clone(),
+        ConstraintKind::Static,
+        roles,
+    )?;
+    Ok(())
+}
+
+/// Nests `count` groups into `GROUP_ID`, each with a user of its own and with `USER_ID`, so
+/// all their users have to be checked, when `GROUP_ID` gains roles.
+fn add_group_members<T: Config<EntityId = [u8; 32]>>(
+    caller: &T::AccountId,
+    count: u32,
+) -> Result<(), BenchmarkError> {
+    for i in 0..count {
+        let mut group_id = GROUP_ID2.clone();
+        group_id[..4].copy_from_slice(&i.to_le_bytes());
+        let mut user_id = USER_ID.clone();
+        user_id[..4].copy_from_slice(&i.to_le_bytes());
+        RBAC::<T>::add_group(
+            RawOrigin::Signed(caller.clone()).into(),
+            caller.clone(),
+            group_id,
+            GROUP_STR.to_vec(),
+        )?;
+        RBAC::<T>::assign_group_to_group(
+            RawOrigin::Signed(caller.clone()).into(),
+            caller.clone(),
+            group_id,
+            GROUP_ID.clone(),
+        )?;
+        RBAC::<T>::assign_user_to_group(
+            RawOrigin::Signed(caller.clone()).into(),
+            caller.clone(),
+            user_id,
+            group_id,
+            None,
+        )?;
+        RBAC::<T>::assign_user_to_group(
+            RawOrigin::Signed(caller.clone()).into(),
+            caller.clone(),
+            USER_ID.clone(),
+            group_id,
+            None,
+        )?;
+    }
+    Ok(())
+}
+
 benchmarks! {
     where_clause { where
         T: Config<EntityId = [u8; 32]>
@@ -31,7 +139,8 @@ benchmarks! {
 
     add_role {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-    }: _(RawOrigin::Signed(caller.clone()), ROLE_ID.clone(), ADMIN_STR.to_vec())
+        let admin = add_admin::<T>(&caller)?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())
     verify {
         assert_last_event::<T>(Event::<T>::RoleAdded(
             caller.clone(),
@@ -42,8 +151,9 @@ benchmarks! {
 
     update_role {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
-    }: _(RawOrigin::Signed(caller.clone()), ROLE_ID.clone(), ADMIN_STR.to_vec())
+        let admin = add_admin::<T>(&caller)?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())
     verify {
         assert_last_event::<T>(Event::<T>::RoleUpdated(
             caller.clone(),
@@ -54,8 +164,9 @@ benchmarks! {
 
     disable_role {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
-    }: _(RawOrigin::Signed(caller.clone()), ROLE_ID.clone())
+        let admin = add_admin::<T>(&caller)?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), ROLE_ID.clone())
     verify {
         assert_last_event::<T>(Event::<T>::RoleRemoved(
             caller.clone(),
@@ -65,19 +176,30 @@ benchmarks! {
 
     fetch_role {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
-    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), ROLE_ID.clone())
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+    }: {
+        legacy_fetch::<T>(RBAC::<T>::fetch_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone()))?;
+    }
 
     fetch_roles {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
-        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), ROLE_ID2.clone(), ADMIN_STR.to_vec())?;
-    }: _(RawOrigin::Signed(caller.clone()), caller.clone())
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID2.clone(), ADMIN_STR.to_vec())?;
+    }: {
+        legacy_fetch::<T>(RBAC::<T>::fetch_roles(RawOrigin::Signed(caller.clone()).into(), caller.clone()))?;
+    }
 
     assign_role_to_user {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
-    }: _(RawOrigin::Signed(caller.clone()), ROLE_ID.clone(), USER_ID.clone())
+        let admin = add_admin::<T>(&caller)?;
+        // Worst case: a static constraint and the user in all groups nested into one holding a role
+        add_static_constraint::<T>(&caller)?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID2.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), GROUP_STR.to_vec())?;
+        RBAC::<T>::assign_role_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID2.clone(), GROUP_ID.clone())?;
+        add_group_members::<T>(&caller, T::BoundedDataLen::get() - 1)?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), ROLE_ID.clone(), USER_ID.clone(), Some(EXPIRES_AT.into()))
     verify {
         assert_last_event::<T>(Event::<T>::RoleAssignedToUser(
             caller.clone(),
@@ -88,9 +210,10 @@ benchmarks! {
 
     unassign_role_to_user {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
-        RBAC::<T>::assign_role_to_user(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), USER_ID.clone())?;
-    }: _(RawOrigin::Signed(caller.clone()), ROLE_ID.clone(), USER_ID.clone())
+        let admin = add_admin::<T>(&caller)?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::assign_role_to_user(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), USER_ID.clone(), None)?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), ROLE_ID.clone(), USER_ID.clone())
     verify {
         assert_last_event::<T>(Event::<T>::RoleUnassignedToUser(
             caller.clone(),
@@ -101,10 +224,13 @@ benchmarks! {
 
     assign_role_to_group {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
-        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), GROUP_ID.clone(), ADMIN_STR.to_vec())?;
-
-    }: _(RawOrigin::Signed(caller.clone()), ROLE_ID.clone(), GROUP_ID.clone())
+        let admin = add_admin::<T>(&caller)?;
+        // Worst case: a static constraint and all groups with their users nested into the group
+        add_static_constraint::<T>(&caller)?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), ADMIN_STR.to_vec())?;
+        add_group_members::<T>(&caller, T::BoundedDataLen::get() - 1)?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), ROLE_ID.clone(), GROUP_ID.clone())
     verify {
         assert_last_event::<T>(Event::<T>::RoleAssignedToGroup(
             caller.clone(),
@@ -115,10 +241,11 @@ benchmarks! {
 
     unassign_role_to_group {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
-        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), GROUP_ID.clone(), ADMIN_STR.to_vec())?;
-        RBAC::<T>::assign_role_to_group(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), GROUP_ID.clone())?;
-    }: _(RawOrigin::Signed(caller.clone()), ROLE_ID.clone(), GROUP_ID.clone())
+        let admin = add_admin::<T>(&caller)?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::assign_role_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), GROUP_ID.clone())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), ROLE_ID.clone(), GROUP_ID.clone())
     verify {
         assert_last_event::<T>(Event::<T>::RoleUnassignedToGroup(
             caller.clone(),
@@ -129,13 +256,16 @@ benchmarks! {
 
     fetch_user_roles {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
-        RBAC::<T>::assign_role_to_user(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), USER_ID.clone())?;
-    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), USER_ID.clone())
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::assign_role_to_user(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), USER_ID.clone(), None)?;
+    }: {
+        legacy_fetch::<T>(RBAC::<T>::fetch_user_roles(RawOrigin::Signed(caller.clone()).into(), caller.clone(), USER_ID.clone()))?;
+    }
 
     add_permission {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-    }: _(RawOrigin::Signed(caller.clone()), PERMISSION_ID.clone(), PERM_STR.to_vec())
+        let admin = add_admin::<T>(&caller)?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), PERMISSION_ID.clone(), PERM_STR.to_vec())
     verify {
         assert_last_event::<T>(Event::<T>::PermissionAdded(
             caller.clone(),
@@ -146,9 +276,10 @@ benchmarks! {
 
     update_permission {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
         RBAC::<T>::add_permission(
-            RawOrigin::Signed(caller.clone()).into(), PERMISSION_ID.clone(), PERM_STR.to_vec())?;
-    }: _(RawOrigin::Signed(caller.clone()), PERMISSION_ID.clone(), PERM_STR.to_vec())
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), PERM_STR.to_vec())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), PERMISSION_ID.clone(), PERM_STR.to_vec())
     verify {
         assert_last_event::<T>(Event::<T>::PermissionUpdated(
             caller.clone(),
@@ -159,9 +290,10 @@ benchmarks! {
 
     disable_permission {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
         RBAC::<T>::add_permission(
-            RawOrigin::Signed(caller.clone()).into(), PERMISSION_ID.clone(), PERM_STR.to_vec())?;
-    }: _(RawOrigin::Signed(caller.clone()), PERMISSION_ID.clone())
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), PERM_STR.to_vec())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), PERMISSION_ID.clone())
     verify {
         assert_last_event::<T>(Event::<T>::PermissionDisabled(
             caller.clone(),
@@ -172,23 +304,28 @@ benchmarks! {
     fetch_permission {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
         RBAC::<T>::add_permission(
-            RawOrigin::Signed(caller.clone()).into(), PERMISSION_ID.clone(), PERM_STR.to_vec())?;
-    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), PERMISSION_ID.clone())
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), PERM_STR.to_vec())?;
+    }: {
+        legacy_fetch::<T>(RBAC::<T>::fetch_permission(RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone()))?;
+    }
 
     fetch_permissions {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
         RBAC::<T>::add_permission(
-            RawOrigin::Signed(caller.clone()).into(), PERMISSION_ID.clone(), PERM_STR.to_vec())?;
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), PERM_STR.to_vec())?;
         RBAC::<T>::add_permission(
-            RawOrigin::Signed(caller.clone()).into(), PERMISSION_ID2.clone(), PERM_STR.to_vec())?;
-    }: _(RawOrigin::Signed(caller.clone()), caller.clone())
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID2.clone(), PERM_STR.to_vec())?;
+    }: {
+        legacy_fetch::<T>(RBAC::<T>::fetch_permissions(RawOrigin::Signed(caller.clone()).into(), caller.clone()))?;
+    }
 
     assign_permission_to_role {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        let admin = add_admin::<T>(&caller)?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
         RBAC::<T>::add_permission(
-            RawOrigin::Signed(caller.clone()).into(), PERMISSION_ID.clone(), ADMIN_STR.to_vec())?;
-    }: _(RawOrigin::Signed(caller.clone()), PERMISSION_ID.clone(), ROLE_ID.clone())
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), ADMIN_STR.to_vec())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), PERMISSION_ID.clone(), ROLE_ID.clone())
     verify {
         assert_last_event::<T>(Event::<T>::PermissionAssigned(
             caller.clone(),
@@ -199,12 +336,13 @@ benchmarks! {
 
     unassign_permission_to_role {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        let admin = add_admin::<T>(&caller)?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
         RBAC::<T>::add_permission(
-            RawOrigin::Signed(caller.clone()).into(), PERMISSION_ID.clone(), ADMIN_STR.to_vec())?;
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), ADMIN_STR.to_vec())?;
         RBAC::<T>::assign_permission_to_role(
-            RawOrigin::Signed(caller.clone()).into(), PERMISSION_ID.clone(), ROLE_ID.clone())?;
-    }: _(RawOrigin::Signed(caller.clone()), PERMISSION_ID.clone(), ROLE_ID.clone())
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), ROLE_ID.clone())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), PERMISSION_ID.clone(), ROLE_ID.clone())
     verify {
         assert_last_event::<T>(Event::<T>::PermissionUnassignedToRole(
             caller.clone(),
@@ -215,16 +353,19 @@ benchmarks! {
 
     fetch_role_permissions {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
         RBAC::<T>::add_permission(
-            RawOrigin::Signed(caller.clone()).into(), PERMISSION_ID.clone(), ADMIN_STR.to_vec())?;
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), ADMIN_STR.to_vec())?;
         RBAC::<T>::assign_permission_to_role(
-            RawOrigin::Signed(caller.clone()).into(), PERMISSION_ID.clone(), ROLE_ID.clone())?;
-    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), ROLE_ID.clone())
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), ROLE_ID.clone())?;
+    }: {
+        legacy_fetch::<T>(RBAC::<T>::fetch_role_permissions(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone()))?;
+    }
 
     add_group {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-    }: _(RawOrigin::Signed(caller.clone()), GROUP_ID.clone(), GROUP_STR.to_vec())
+        let admin = add_admin::<T>(&caller)?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), GROUP_ID.clone(), GROUP_STR.to_vec())
     verify {
         assert_last_event::<T>(Event::<T>::GroupAdded(
             caller.clone(),
@@ -235,9 +376,10 @@ benchmarks! {
 
     update_group {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
         RBAC::<T>::add_group(
-            RawOrigin::Signed(caller.clone()).into(), GROUP_ID.clone(), GROUP_STR.to_vec())?;
-    }: _(RawOrigin::Signed(caller.clone()), GROUP_ID.clone(), GROUP_STR.to_vec())
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), GROUP_STR.to_vec())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), GROUP_ID.clone(), GROUP_STR.to_vec())
     verify {
         assert_last_event::<T>(Event::<T>::GroupUpdated(
             caller.clone(),
@@ -248,9 +390,10 @@ benchmarks! {
 
     disable_group {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
         RBAC::<T>::add_group(
-            RawOrigin::Signed(caller.clone()).into(), GROUP_ID.clone(), GROUP_STR.to_vec())?;
-    }: _(RawOrigin::Signed(caller.clone()), GROUP_ID.clone())
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), GROUP_STR.to_vec())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), GROUP_ID.clone())
     verify {
         assert_last_event::<T>(Event::<T>::GroupDisabled(
             caller.clone(),
@@ -261,22 +404,31 @@ benchmarks! {
     fetch_group {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
         RBAC::<T>::add_group(
-            RawOrigin::Signed(caller.clone()).into(), GROUP_ID.clone(), GROUP_STR.to_vec())?;
-    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), GROUP_ID.clone())
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), GROUP_STR.to_vec())?;
+    }: {
+        legacy_fetch::<T>(RBAC::<T>::fetch_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone()))?;
+    }
 
     fetch_groups {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
         RBAC::<T>::add_group(
-            RawOrigin::Signed(caller.clone()).into(), GROUP_ID.clone(), GROUP_STR.to_vec())?;
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), GROUP_STR.to_vec())?;
         RBAC::<T>::add_group(
-            RawOrigin::Signed(caller.clone()).into(), GROUP_ID2.clone(), GROUP_STR.to_vec())?;
-    }: _(RawOrigin::Signed(caller.clone()), caller.clone())
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID2.clone(), GROUP_STR.to_vec())?;
+    }: {
+        legacy_fetch::<T>(RBAC::<T>::fetch_groups(RawOrigin::Signed(caller.clone()).into(), caller.clone()))?;
+    }
 
     assign_user_to_group {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), GROUP_ID.clone(), GROUP_STR.to_vec())?;
-
-    }: _(RawOrigin::Signed(caller.clone()), USER_ID.clone(), GROUP_ID.clone())
+        let admin = add_admin::<T>(&caller)?;
+        // Worst case: a static constraint and the user in all other groups, nested into the group
+        add_static_constraint::<T>(&caller)?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), GROUP_STR.to_vec())?;
+        RBAC::<T>::assign_role_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), GROUP_ID.clone())?;
+        add_group_members::<T>(&caller, T::BoundedDataLen::get() - 1)?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), USER_ID.clone(), GROUP_ID.clone(), Some(EXPIRES_AT.into()))
     verify {
         assert_last_event::<T>(Event::<T>::UserAssignedToGroup(
             caller.clone(),
@@ -287,9 +439,10 @@ benchmarks! {
 
     unassign_user_to_group {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), GROUP_ID.clone(), ADMIN_STR.to_vec())?;
-        RBAC::<T>::assign_user_to_group(RawOrigin::Signed(caller.clone()).into(), USER_ID.clone(), GROUP_ID.clone())?;
-    }: _(RawOrigin::Signed(caller.clone()), USER_ID.clone(), GROUP_ID.clone())
+        let admin = add_admin::<T>(&caller)?;
+        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::assign_user_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), USER_ID.clone(), GROUP_ID.clone(), None)?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), USER_ID.clone(), GROUP_ID.clone())
     verify {
         assert_last_event::<T>(Event::<T>::UserUnAssignedToGroup(
             caller.clone(),
@@ -300,37 +453,485 @@ benchmarks! {
 
     fetch_user_groups {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), GROUP_ID.clone(), ADMIN_STR.to_vec())?;
-        RBAC::<T>::assign_user_to_group(RawOrigin::Signed(caller.clone()).into(), USER_ID.clone(), GROUP_ID.clone())?;
-    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), USER_ID.clone())
+        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::assign_user_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), USER_ID.clone(), GROUP_ID.clone(), None)?;
+    }: {
+        legacy_fetch::<T>(RBAC::<T>::fetch_user_groups(RawOrigin::Signed(caller.clone()).into(), caller.clone(), USER_ID.clone()))?;
+    }
 
     fetch_user_permissions {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
-        RBAC::<T>::add_permission(RawOrigin::Signed(caller.clone()).into(), PERMISSION_ID.clone(), ADMIN_STR.to_vec())?;
-        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), GROUP_ID.clone(), ADMIN_STR.to_vec())?;
-        RBAC::<T>::assign_user_to_group(RawOrigin::Signed(caller.clone()).into(), USER_ID.clone(), GROUP_ID.clone())?;
-        RBAC::<T>::assign_role_to_group(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), GROUP_ID.clone())?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::add_permission(RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::assign_user_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), USER_ID.clone(), GROUP_ID.clone(), None)?;
+        RBAC::<T>::assign_role_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), GROUP_ID.clone())?;
         RBAC::<T>::assign_permission_to_role(
-            RawOrigin::Signed(caller.clone()).into(), PERMISSION_ID.clone(), ROLE_ID.clone())?;
-    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), USER_ID.clone())
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), ROLE_ID.clone())?;
+    }: {
+        legacy_fetch::<T>(RBAC::<T>::fetch_user_permissions(RawOrigin::Signed(caller.clone()).into(), caller.clone(), USER_ID.clone()))?;
+    }
 
     fetch_group_permissions {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
-        RBAC::<T>::add_permission(RawOrigin::Signed(caller.clone()).into(), PERMISSION_ID.clone(), ADMIN_STR.to_vec())?;
-        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), GROUP_ID.clone(), ADMIN_STR.to_vec())?;
-        RBAC::<T>::assign_role_to_group(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), GROUP_ID.clone())?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::add_permission(RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::assign_role_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), GROUP_ID.clone())?;
         RBAC::<T>::assign_permission_to_role(
-            RawOrigin::Signed(caller.clone()).into(), PERMISSION_ID.clone(), ROLE_ID.clone())?;
-    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), GROUP_ID.clone())
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), ROLE_ID.clone())?;
+    }: {
+        legacy_fetch::<T>(RBAC::<T>::fetch_group_permissions(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone()))?;
+    }
 
     fetch_group_roles {
         let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
-        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
-        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), GROUP_ID.clone(), ADMIN_STR.to_vec())?;
-        RBAC::<T>::assign_role_to_group(RawOrigin::Signed(caller.clone()).into(), ROLE_ID.clone(), GROUP_ID.clone())?;
-    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), GROUP_ID.clone())
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::assign_role_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), GROUP_ID.clone())?;
+    }: {
+        legacy_fetch::<T>(RBAC::<T>::fetch_group_roles(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone()))?;
+    }
+
+    has_permission {
+        let r in 1 .. 100;
+        let g in 1 .. 100;
+        let d in 0 .. T::MaxRoleDepth::get();
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        RBAC::<T>::add_permission(RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), ADMIN_STR.to_vec())?;
+        // Roles and groups of the user, which do not grant the permission
+        for i in 0 .. r {
+            let mut role_id = ROLE_ID.clone();
+            role_id[0] = i as u8;
+            RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), role_id, ADMIN_STR.to_vec())?;
+            RBAC::<T>::assign_role_to_user(RawOrigin::Signed(caller.clone()).into(), caller.clone(), role_id, USER_ID.clone(), None)?;
+        }
+        for i in 0 .. g {
+            let mut group_id = GROUP_ID.clone();
+            group_id[0] = i as u8;
+            RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), group_id, GROUP_STR.to_vec())?;
+            RBAC::<T>::assign_user_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), USER_ID.clone(), group_id, None)?;
+        }
+        // Only the last group's role grants the permission, by inheriting it over d levels
+        let mut group_id = GROUP_ID.clone();
+        group_id[0] = (g - 1) as u8;
+        let mut role_id = ROLE_ID2.clone();
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), role_id, ADMIN_STR.to_vec())?;
+        RBAC::<T>::assign_role_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), role_id, group_id)?;
+        for i in 1 ..= d {
+            let mut inherited_role_id = ROLE_ID2.clone();
+            inherited_role_id[0] = i as u8;
+            RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), inherited_role_id, ADMIN_STR.to_vec())?;
+            RBAC::<T>::assign_role_to_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), role_id, inherited_role_id)?;
+            role_id = inherited_role_id;
+        }
+        RBAC::<T>::assign_permission_to_role(
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), role_id)?;
+    }: {
+        assert!(RBAC::<T>::has_permission(&caller, USER_ID.clone(), PERMISSION_ID.clone()));
+    }
+
+    delete_role {
+        let u in 0 .. 100;
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
+        let bound = T::BoundedDataLen::get();
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        for i in 0 .. u {
+            let mut user_id = USER_ID.clone();
+            user_id[0] = i as u8;
+            RBAC::<T>::assign_role_to_user(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), user_id, None)?;
+        }
+        // Worst case: all other roles inherit it, all groups hold it and it holds all permissions
+        for i in 1 .. bound {
+            let mut role_id = ROLE_ID2.clone();
+            role_id[..4].copy_from_slice(&i.to_le_bytes());
+            RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), role_id, ADMIN_STR.to_vec())?;
+            RBAC::<T>::assign_role_to_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), role_id, ROLE_ID.clone())?;
+        }
+        for i in 0 .. bound {
+            let mut group_id = GROUP_ID.clone();
+            group_id[..4].copy_from_slice(&i.to_le_bytes());
+            RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), group_id, GROUP_STR.to_vec())?;
+            RBAC::<T>::assign_role_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), group_id)?;
+            let mut permission_id = PERMISSION_ID.clone();
+            permission_id[..4].copy_from_slice(&i.to_le_bytes());
+            RBAC::<T>::add_permission(RawOrigin::Signed(caller.clone()).into(), caller.clone(), permission_id, PERM_STR.to_vec())?;
+            RBAC::<T>::assign_permission_to_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), permission_id, ROLE_ID.clone())?;
+        }
+    }: _(RawOrigin::Signed(admin), caller.clone(), ROLE_ID.clone(), u)
+    verify {
+        assert_last_event::<T>(Event::<T>::RoleDeleted(
+            caller.clone(),
+            ROLE_ID.clone(),
+        ).into());
+    }
+
+    delete_permission {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
+        RBAC::<T>::add_permission(RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), ADMIN_STR.to_vec())?;
+        // Worst case: all roles hold the permission
+        for i in 0 .. T::BoundedDataLen::get() {
+            let mut role_id = ROLE_ID.clone();
+            role_id[..4].copy_from_slice(&i.to_le_bytes());
+            RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), role_id, ADMIN_STR.to_vec())?;
+            RBAC::<T>::assign_permission_to_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), role_id)?;
+        }
+    }: _(RawOrigin::Signed(admin), caller.clone(), PERMISSION_ID.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::PermissionDeleted(
+            caller.clone(),
+            PERMISSION_ID.clone(),
+        ).into());
+    }
+
+    delete_group {
+        let u in 0 .. 100;
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
+        let bound = T::BoundedDataLen::get();
+        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), GROUP_STR.to_vec())?;
+        for i in 0 .. u {
+            let mut user_id = USER_ID.clone();
+            user_id[0] = i as u8;
+            RBAC::<T>::assign_user_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), user_id, GROUP_ID.clone(), None)?;
+        }
+        // Worst case: all other groups are nested in the group and it holds all roles
+        for i in 1 .. bound {
+            let mut group_id = GROUP_ID2.clone();
+            group_id[..4].copy_from_slice(&i.to_le_bytes());
+            RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), group_id, GROUP_STR.to_vec())?;
+            RBAC::<T>::assign_group_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), group_id, GROUP_ID.clone())?;
+        }
+        for i in 0 .. bound {
+            let mut role_id = ROLE_ID.clone();
+            role_id[..4].copy_from_slice(&i.to_le_bytes());
+            RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), role_id, ADMIN_STR.to_vec())?;
+            RBAC::<T>::assign_role_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), role_id, GROUP_ID.clone())?;
+        }
+    }: _(RawOrigin::Signed(admin), caller.clone(), GROUP_ID.clone(), u)
+    verify {
+        assert_last_event::<T>(Event::<T>::GroupDeleted(
+            caller.clone(),
+            GROUP_ID.clone(),
+        ).into());
+    }
+
+    enable_role {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::disable_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), ROLE_ID.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::RoleEnabled(
+            caller.clone(),
+            ROLE_ID.clone(),
+        ).into());
+    }
+
+    enable_permission {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
+        RBAC::<T>::add_permission(
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone(), PERM_STR.to_vec())?;
+        RBAC::<T>::disable_permission(RawOrigin::Signed(caller.clone()).into(), caller.clone(), PERMISSION_ID.clone())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), PERMISSION_ID.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::PermissionEnabled(
+            caller.clone(),
+            PERMISSION_ID.clone(),
+        ).into());
+    }
+
+    enable_group {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
+        RBAC::<T>::add_group(
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), GROUP_STR.to_vec())?;
+        RBAC::<T>::disable_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), GROUP_ID.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::GroupEnabled(
+            caller.clone(),
+            GROUP_ID.clone(),
+        ).into());
+    }
+
+    assign_role_to_role {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
+        let bound = T::BoundedDataLen::get();
+        // Worst case: a static constraint, all other roles inherit the role and all groups hold it
+        add_static_constraint::<T>(&caller)?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID2.clone(), ADMIN_STR.to_vec())?;
+        for i in 0 .. bound - 4 {
+            let mut role_id = ROLE_ID2.clone();
+            role_id[..4].copy_from_slice(&i.to_le_bytes());
+            RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), role_id, ADMIN_STR.to_vec())?;
+            RBAC::<T>::assign_role_to_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), role_id, ROLE_ID.clone())?;
+        }
+        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), GROUP_STR.to_vec())?;
+        RBAC::<T>::assign_role_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), GROUP_ID.clone())?;
+        add_group_members::<T>(&caller, bound - 1)?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), ROLE_ID.clone(), ROLE_ID2.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::RoleAssignedToRole(
+            caller.clone(),
+            ROLE_ID.clone(),
+            ROLE_ID2.clone(),
+        ).into());
+    }
+
+    unassign_role_to_role {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID2.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::assign_role_to_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ROLE_ID2.clone())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), ROLE_ID.clone(), ROLE_ID2.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::RoleUnassignedToRole(
+            caller.clone(),
+            ROLE_ID.clone(),
+            ROLE_ID2.clone(),
+        ).into());
+    }
+
+    assign_group_to_group {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
+        // Worst case: a static constraint, the parent holds a role and all other groups are nested
+        add_static_constraint::<T>(&caller)?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), GROUP_STR.to_vec())?;
+        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID2.clone(), GROUP_STR.to_vec())?;
+        RBAC::<T>::assign_role_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), GROUP_ID2.clone())?;
+        add_group_members::<T>(&caller, T::BoundedDataLen::get() - 2)?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), GROUP_ID.clone(), GROUP_ID2.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::GroupAssignedToGroup(
+            caller.clone(),
+            GROUP_ID.clone(),
+            GROUP_ID2.clone(),
+        ).into());
+    }
+
+    unassign_group_to_group {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
+        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), GROUP_STR.to_vec())?;
+        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID2.clone(), GROUP_STR.to_vec())?;
+        RBAC::<T>::assign_group_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), GROUP_ID2.clone())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), GROUP_ID.clone(), GROUP_ID2.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::GroupUnassignedToGroup(
+            caller.clone(),
+            GROUP_ID.clone(),
+            GROUP_ID2.clone(),
+        ).into());
+    }
+
+    add_role_constraint {
+        let r in 2 .. T::BoundedDataLen::get();
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
+        let mut roles = vec![];
+        for i in 0 .. r {
+            let mut role_id = ROLE_ID.clone();
+            role_id[..4].copy_from_slice(&i.to_le_bytes());
+            RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), role_id, ADMIN_STR.to_vec())?;
+            roles.push(role_id);
+        }
+        // Worst case: all groups with their users hold one of the roles
+        RBAC::<T>::add_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), GROUP_ID.clone(), GROUP_STR.to_vec())?;
+        RBAC::<T>::assign_role_to_group(RawOrigin::Signed(caller.clone()).into(), caller.clone(), roles[0], GROUP_ID.clone())?;
+        add_group_members::<T>(&caller, T::BoundedDataLen::get() - 1)?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), CONSTRAINT_ID.clone(), ConstraintKind::Static, roles)
+    verify {
+        assert_last_event::<T>(Event::<T>::RoleConstraintAdded(
+            caller.clone(),
+            CONSTRAINT_ID.clone(),
+        ).into());
+    }
+
+    remove_role_constraint {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID2.clone(), ADMIN_STR.to_vec())?;
+        RBAC::<T>::add_role_constraint(
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), CONSTRAINT_ID.clone(),
+            ConstraintKind::Static, vec![ROLE_ID.clone(), ROLE_ID2.clone()])?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), CONSTRAINT_ID.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::RoleConstraintRemoved(
+            caller.clone(),
+            CONSTRAINT_ID.clone(),
+        ).into());
+    }
+
+    add_delegate {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin : T::AccountId = account(ADMIN_ACCOUNT_STR, 0, 0);
+        let capabilities = Capabilities {
+            manage_users: true,
+            manage_roles: true,
+            manage_permissions: true,
+        };
+    }: _(RawOrigin::Signed(caller.clone()), admin.clone(), capabilities)
+    verify {
+        assert_last_event::<T>(Event::<T>::DelegateAdded(
+            caller.clone(),
+            admin.clone(),
+            capabilities,
+        ).into());
+    }
+
+    remove_delegate {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin : T::AccountId = account(ADMIN_ACCOUNT_STR, 0, 0);
+        RBAC::<T>::add_delegate(RawOrigin::Signed(caller.clone()).into(), admin.clone(), Capabilities::default())?;
+    }: _(RawOrigin::Signed(caller.clone()), admin.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::DelegateRemoved(
+            caller.clone(),
+            admin.clone(),
+        ).into());
+    }
+
+    propose_ownership_transfer {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let new_owner : T::AccountId = account(NEW_OWNER_ACCOUNT_STR, 0, 0);
+    }: _(RawOrigin::Signed(caller.clone()), new_owner.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::OwnershipTransferProposed(
+            caller.clone(),
+            new_owner.clone(),
+        ).into());
+    }
+
+    accept_ownership_transfer {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let new_owner : T::AccountId = account(NEW_OWNER_ACCOUNT_STR, 0, 0);
+        RBAC::<T>::propose_ownership_transfer(RawOrigin::Signed(caller.clone()).into(), new_owner.clone())?;
+    }: _(RawOrigin::Signed(new_owner.clone()), caller.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::OwnershipTransferAccepted(
+            caller.clone(),
+            new_owner.clone(),
+        ).into());
+    }
+
+    continue_ownership_transfer {
+        // Expiring assignments are the most expensive entries to move
+        let e in 1 .. 100;
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let new_owner : T::AccountId = account(NEW_OWNER_ACCOUNT_STR, 0, 0);
+        RBAC::<T>::add_role(RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), ADMIN_STR.to_vec())?;
+        for i in 0 .. e {
+            let mut user_id = USER_ID.clone();
+            user_id[0] = i as u8;
+            RBAC::<T>::assign_role_to_user(
+                RawOrigin::Signed(caller.clone()).into(), caller.clone(), ROLE_ID.clone(), user_id, Some(EXPIRES_AT.into()))?;
+        }
+        RBAC::<T>::propose_ownership_transfer(RawOrigin::Signed(caller.clone()).into(), new_owner.clone())?;
+        RBAC::<T>::accept_ownership_transfer(RawOrigin::Signed(new_owner.clone()).into(), caller.clone())?;
+    }: _(RawOrigin::Signed(new_owner.clone()), caller.clone(), e)
+    verify {
+        assert_eq!(RBAC::<T>::get_role_users(&new_owner, ROLE_ID.clone()).unwrap().len(), e as usize);
+    }
+
+    add_user {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), USER_ID.clone(), USER_STR.to_vec())
+    verify {
+        assert_last_event::<T>(Event::<T>::UserAdded(
+            caller.clone(),
+            USER_ID.clone(),
+            USER_STR.to_vec(),
+        ).into());
+    }
+
+    update_user {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
+        RBAC::<T>::add_user(
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), USER_ID.clone(), USER_STR.to_vec())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), USER_ID.clone(), USER_STR.to_vec())
+    verify {
+        assert_last_event::<T>(Event::<T>::UserUpdated(
+            caller.clone(),
+            USER_ID.clone(),
+            USER_STR.to_vec(),
+        ).into());
+    }
+
+    disable_user {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
+        RBAC::<T>::add_user(
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), USER_ID.clone(), USER_STR.to_vec())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), USER_ID.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::UserDisabled(
+            caller.clone(),
+            USER_ID.clone(),
+        ).into());
+    }
+
+    claim_user {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let claimer : T::AccountId = account(CLAIMER_ACCOUNT_STR, 0, 0);
+        RBAC::<T>::add_user(
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), USER_ID.clone(), USER_STR.to_vec())?;
+    }: _(RawOrigin::Signed(claimer.clone()), caller.clone(), USER_ID.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::UserClaimed(
+            caller.clone(),
+            USER_ID.clone(),
+            claimer.clone(),
+        ).into());
+    }
+
+    approve_user_claim {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
+        let claimer : T::AccountId = account(CLAIMER_ACCOUNT_STR, 0, 0);
+        RBAC::<T>::add_user(
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), USER_ID.clone(), USER_STR.to_vec())?;
+        RBAC::<T>::claim_user(
+            RawOrigin::Signed(claimer.clone()).into(), caller.clone(), USER_ID.clone())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), USER_ID.clone(), claimer.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::UserBound(
+            caller.clone(),
+            USER_ID.clone(),
+            claimer.clone(),
+        ).into());
+    }
+
+    unbind_user {
+        let caller : T::AccountId = account(CALLER_ACCOUNT_STR, 0, 0);
+        let admin = add_admin::<T>(&caller)?;
+        let claimer : T::AccountId = account(CLAIMER_ACCOUNT_STR, 0, 0);
+        RBAC::<T>::add_user(
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), USER_ID.clone(), USER_STR.to_vec())?;
+        RBAC::<T>::claim_user(
+            RawOrigin::Signed(claimer.clone()).into(), caller.clone(), USER_ID.clone())?;
+        RBAC::<T>::approve_user_claim(
+            RawOrigin::Signed(caller.clone()).into(), caller.clone(), USER_ID.clone(), claimer.clone())?;
+    }: _(RawOrigin::Signed(admin), caller.clone(), USER_ID.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::UserUnbound(
+            caller.clone(),
+            USER_ID.clone(),
+            claimer.clone(),
+        ).into());
+    }
 }
 
 impl_benchmark_test_suite!(RBAC, crate::mock::new_test_ext(), crate::mock::Test);
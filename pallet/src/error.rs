@@ -1,5 +1,5 @@
 use codec::{Decode, Encode};
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, PalletError};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -11,7 +11,7 @@ pub type Result<T, RbacError> = core::result::Result<T, RbacError>;
 /// All possible user error types of the RBAC pallet than can occur, when passing
 /// wrong or invalid parameters. Must be serialize-able when used via RPC.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-#[derive(Debug, Encode, Decode, TypeInfo)]
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, PalletError)]
 pub enum RbacErrorType {
     /// Returned if the Entity already exists
     EntityAlreadyExist,
@@ -29,12 +29,24 @@ pub enum RbacErrorType {
     NameExceedMaxChar,
     /// Exceeds BoundedLen bounds
     StorageExceedsMaxBounds,
+    /// Returned if the Entity is already enabled
+    EntityAlreadyEnabled,
+    /// Returned if an assignment would create a cycle
+    CycleDetected,
+    /// Returned if an assignment exceeds the max depth of a hierarchy
+    MaxDepthExceeded,
+    /// Returned if an assignment or resolution violates a separation of duty constraint
+    SeparationOfDutyViolation,
+    /// Returned if a role constraint does not hold at least two distinct roles
+    InvalidRoleConstraint,
+    /// Returned if an assignment would expire at or before the current block
+    InvalidExpiry,
 }
 
 /// Struct encapsules all informations about occured error: error type and passed
 /// data which lead to that error. Must be serialize-able when used via RPC.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-#[derive(Debug, Encode, Decode, TypeInfo)]
+#[derive(Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
 pub struct RbacError {
     /// type of error, see RbacErrorType
     pub typ: RbacErrorType,
@@ -18,11 +18,13 @@ mod tests;
 mod benchmarking;
 
 pub mod error;
+pub mod origin;
 pub mod rbac;
 pub mod structs;
 
 pub mod weightinfo;
 pub mod weights;
+pub use origin::EnsureRbacPermission;
 pub use weightinfo::WeightInfo;
 pub mod migrations;
 
@@ -30,18 +32,27 @@ pub mod migrations;
 pub mod pallet {
 
     use codec::{Encode, MaxEncodedLen};
-    use frame_support::pallet_prelude::*;
+    use frame_support::{pallet_prelude::*, storage::with_storage_layer};
     use frame_system::pallet_prelude::*;
     use sp_io::hashing::blake2_256;
+    use sp_runtime::traits::{Convert, One, Saturating};
     use sp_std::fmt::Debug;
-    use sp_std::{vec, vec::Vec};
+    use sp_std::{
+        collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+        vec,
+        vec::Vec,
+    };
 
     use super::WeightInfo;
     use crate::{
-        error::{RbacError, RbacErrorType::*, Result},
+        error::{RbacError, RbacErrorType, RbacErrorType::*, Result},
         migrations,
-        rbac::{Group, Permission, Rbac, RbacKeyType, Role, Tag},
-        structs::{Entity, Permission2Role, Role2Group, Role2User, User2Group},
+        rbac::{DisabledEntityPolicy, Group, Permission, Rbac, RbacKeyType, Role, Tag, User},
+        structs::{
+            Assignment, Capabilities, Capability, ConstraintKind, Entity, Group2Group, ImportMode,
+            OwnershipTransfer, Permission2Role, PermissionGrant, RbacDomain, RbacOp, Role2Group,
+            Role2Role, Role2User, RoleConstraint, TransferStage, User2Group,
+        },
     };
 
     macro_rules! dpatch_dposit {
@@ -68,8 +79,93 @@ pub mod pallet {
         };
     }
 
+    macro_rules! rekey {
+        ($store:ident, $keys:expr) => {
+            let (from, to) = $keys;
+            if <$store<T>>::contains_key(&from) {
+                let val = <$store<T>>::take(&from);
+                <$store<T>>::insert(&to, val);
+            }
+        };
+    }
+
+    macro_rules! rekey_first {
+        ($store:ident, $keys:expr) => {{
+            let (from, to) = $keys;
+            match <$store<T>>::iter_prefix(&from).next() {
+                Some((id, val)) => {
+                    <$store<T>>::remove(&from, id);
+                    <$store<T>>::insert(&to, id, val);
+                    Some((id, val))
+                }
+                None => None,
+            }
+        }};
+    }
+
+    macro_rules! move_first_entity {
+        ($store:ident, $from:expr, $to:expr) => {{
+            let mut from_entities = <$store<T>>::get($from);
+            if from_entities.is_empty() {
+                None
+            } else {
+                let entity = from_entities.remove(0);
+                let entity_id = entity.id;
+                // The new owner's domain was empty, so it cannot exceed the bounds
+                let mut to_entities = <$store<T>>::get($to);
+                let _ = to_entities.try_push(entity);
+                <$store<T>>::insert($to, to_entities);
+                if from_entities.is_empty() {
+                    <$store<T>>::remove($from);
+                } else {
+                    <$store<T>>::insert($from, from_entities);
+                }
+                Some(entity_id)
+            }
+        }};
+    }
+
+    macro_rules! put_entity {
+        ($store:ident, $owner:expr, $entity:expr, $tag:expr) => {{
+            let entity: Entity<T::EntityId> = $entity;
+            let mut entities = <$store<T>>::get($owner);
+            let res = match entities.iter_mut().find(|e| e.id == entity.id) {
+                Some(stored) => {
+                    *stored = entity.clone();
+                    Ok(())
+                }
+                None => entities.try_push(entity.clone()),
+            };
+            match res {
+                Err(e) => RbacError::err(StorageExceedsMaxBounds, &e),
+                Ok(()) => {
+                    let key = Self::generate_key($owner, &entity.id, $tag);
+                    <$store<T>>::insert($owner, entities);
+                    <KeysLookUpStore<T>>::insert(key, entity);
+                    Ok(())
+                }
+            }
+        }};
+    }
+
+    // Imports the entities with their enabled flag, existing ones are handled by the mode
+    macro_rules! import_entities {
+        ($store:ident, $owner:expr, $entities:expr, $tag:expr, $mode:expr) => {{
+            for entity in $entities.iter() {
+                let key = Self::generate_key($owner, &entity.id, $tag);
+                if <KeysLookUpStore<T>>::contains_key(key)
+                    && !Self::overwrite_existing($mode, EntityAlreadyExist, &entity.id)?
+                {
+                    continue;
+                }
+
+                put_entity!($store, $owner, entity.clone(), $tag)?;
+            }
+        }};
+    }
+
     // current storage version
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
     #[pallet::pallet]
     #[pallet::without_storage_info]
@@ -92,6 +188,26 @@ pub mod pallet {
             + Default;
         #[pallet::constant]
         type BoundedDataLen: Get<u32>;
+        /// Max number of inheritance levels below a role, which are followed when resolving
+        /// permissions.
+        #[pallet::constant]
+        type MaxRoleDepth: Get<u32>;
+        /// Max number of nesting levels above a group, which are followed when resolving
+        /// groups and permissions.
+        #[pallet::constant]
+        type MaxGroupDepth: Get<u32>;
+        /// Max number of operations, which can be applied by a single `batch_apply` call.
+        /// Must not exceed `u16::MAX`, which bounds the index of a failed operation.
+        #[pallet::constant]
+        type MaxOps: Get<u32>;
+        /// Resolves a signing account to the RBAC user it acts as, see `EnsureRbacPermission`.
+        type AccountToUser: Convert<Self::AccountId, Self::EntityId>;
+        /// Defines whether disabled roles, groups and permissions are skipped or rejected,
+        /// when resolving the permissions of a user or group.
+        type DisabledPolicy: Get<DisabledEntityPolicy>;
+        /// Defines whether users have to be registered by `add_user`, before roles or groups
+        /// can be assigned to them.
+        type RequireRegisteredUsers: Get<bool>;
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -108,13 +224,61 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn user_of)]
+    pub type UserStore<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<Entity<T::EntityId>, T::BoundedDataLen>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn user_to_account_of)]
+    pub type User2AccountStore<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::EntityId,
+        T::AccountId,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn account_to_user_of)]
+    pub type Account2UserStore<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        T::EntityId,
+        OptionQuery,
+    >;
+
+    /// Claims of accounts to be bound to a registered user, awaiting the approval of the
+    /// owner. A user holds a single claim, so claims are bounded by the users of a domain.
+    #[pallet::storage]
+    #[pallet::getter(fn user_claim_of)]
+    pub type UserClaimStore<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::EntityId,
+        T::AccountId,
+        OptionQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn role_to_user_of)]
     pub type Role2UserStore<T: Config> = StorageMap<
         _,
         Blake2_128Concat,
         RbacKeyType,
-        BoundedVec<Role2User<T::EntityId>, T::BoundedDataLen>,
+        BoundedVec<Role2User<T::EntityId, BlockNumberFor<T>>, T::BoundedDataLen>,
         ValueQuery,
     >;
 
@@ -164,10 +328,132 @@ pub mod pallet {
         _,
         Blake2_128Concat,
         RbacKeyType,
-        BoundedVec<User2Group<T::EntityId>, T::BoundedDataLen>,
+        BoundedVec<User2Group<T::EntityId, BlockNumberFor<T>>, T::BoundedDataLen>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn role_to_role_of)]
+    pub type Role2RoleStore<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        RbacKeyType,
+        BoundedVec<Role2Role<T::EntityId>, T::BoundedDataLen>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn group_to_group_of)]
+    pub type Group2GroupStore<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        RbacKeyType,
+        BoundedVec<Group2Group<T::EntityId>, T::BoundedDataLen>,
+        ValueQuery,
+    >;
+
+    /// Reverse index of `Role2UserStore`, the users holding a role and their expiry
+    #[pallet::storage]
+    #[pallet::getter(fn role_users_of)]
+    pub type RoleUsersStore<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        RbacKeyType,
+        Blake2_128Concat,
+        T::EntityId,
+        Option<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Reverse index of `User2GroupStore`, the members of a group and their expiry
+    #[pallet::storage]
+    #[pallet::getter(fn group_users_of)]
+    pub type GroupUsersStore<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        RbacKeyType,
+        Blake2_128Concat,
+        T::EntityId,
+        Option<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Reverse index of `Role2GroupStore`, the groups a role is assigned to
+    #[pallet::storage]
+    #[pallet::getter(fn role_groups_of)]
+    pub type RoleGroupsStore<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        RbacKeyType,
+        Blake2_128Concat,
+        T::EntityId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Reverse index of `Permission2RoleStore`, the roles a permission is assigned to
+    #[pallet::storage]
+    #[pallet::getter(fn permission_roles_of)]
+    pub type PermissionRolesStore<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        RbacKeyType,
+        Blake2_128Concat,
+        T::EntityId,
+        (),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn role_constraint_of)]
+    pub type RoleConstraintStore<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<RoleConstraint<T::EntityId>, T::BoundedDataLen>,
         ValueQuery,
     >;
 
+    /// Expiring assignments queued by their expiry block and owner, so that owners do not
+    /// compete for the bounds of a block's queue
+    #[pallet::storage]
+    #[pallet::getter(fn expiry_of)]
+    pub type ExpiryStore<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<Assignment<T::EntityId>, T::BoundedDataLen>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn expiry_cursor)]
+    pub type ExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn admin_to_owner_of)]
+    pub type Admin2OwnerStore<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        Capabilities,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn ownership_transfer_of)]
+    pub type OwnershipTransferStore<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, OwnershipTransfer<T::AccountId>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn incoming_ownership_of)]
+    pub type IncomingOwnershipStore<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn keys_lookup_of)]
     pub type KeysLookUpStore<T: Config> =
@@ -195,8 +481,8 @@ pub mod pallet {
         /// Event emitted when a role has been unassigned from group. [who, roleId, groupId]
         RoleUnassignedToGroup(T::AccountId, T::EntityId, T::EntityId),
         FetchedGroupRoles(Vec<Role2Group<T::EntityId>>),
-        FetchedUserRoles(Vec<Role2User<T::EntityId>>),
-        FetchedUserGroups(Vec<User2Group<T::EntityId>>),
+        FetchedUserRoles(Vec<Role2User<T::EntityId, BlockNumberFor<T>>>),
+        FetchedUserGroups(Vec<User2Group<T::EntityId, BlockNumberFor<T>>>),
         FetchedUserPermissions(Vec<Entity<T::EntityId>>),
         FetchedGroupPermissions(Vec<Entity<T::EntityId>>),
 
@@ -226,6 +512,60 @@ pub mod pallet {
         UserAssignedToGroup(T::AccountId, T::EntityId, T::EntityId),
         /// Event emitted when a user to group relationship has been removed. [who, userId, groupId]
         UserUnAssignedToGroup(T::AccountId, T::EntityId, T::EntityId),
+
+        /// Event emitted when a role and all its assignments have been deleted. [who, roleId]
+        RoleDeleted(T::AccountId, T::EntityId),
+        /// Event emitted when a permission and all its assignments have been deleted. [who, permissionId]
+        PermissionDeleted(T::AccountId, T::EntityId),
+        /// Event emitted when a group and all its assignments have been deleted. [who, groupId]
+        GroupDeleted(T::AccountId, T::EntityId),
+        /// Event emitted when a disabled role has been enabled again. [who, roleId]
+        RoleEnabled(T::AccountId, T::EntityId),
+        /// Event emitted when a disabled permission has been enabled again. [who, permissionId]
+        PermissionEnabled(T::AccountId, T::EntityId),
+        /// Event emitted when a disabled group has been enabled again. [who, groupId]
+        GroupEnabled(T::AccountId, T::EntityId),
+        /// Event emitted when a role inherits another role. [who, roleId, inheritedRoleId]
+        RoleAssignedToRole(T::AccountId, T::EntityId, T::EntityId),
+        /// Event emitted when a role no longer inherits another role. [who, roleId, inheritedRoleId]
+        RoleUnassignedToRole(T::AccountId, T::EntityId, T::EntityId),
+        /// Event emitted when a group has been nested into another group. [who, groupId, parentGroupId]
+        GroupAssignedToGroup(T::AccountId, T::EntityId, T::EntityId),
+        /// Event emitted when a group has been removed from another group. [who, groupId, parentGroupId]
+        GroupUnassignedToGroup(T::AccountId, T::EntityId, T::EntityId),
+        /// Event emitted when a separation of duty constraint has been added. [who, constraintId]
+        RoleConstraintAdded(T::AccountId, T::EntityId),
+        /// Event emitted when a separation of duty constraint has been removed. [who, constraintId]
+        RoleConstraintRemoved(T::AccountId, T::EntityId),
+        /// Event emitted when an expired assignment has been pruned. [who, assignment]
+        AssignmentExpired(T::AccountId, Assignment<T::EntityId>),
+        /// Event emitted when a delegate has been added or updated. [who, admin, capabilities]
+        DelegateAdded(T::AccountId, T::AccountId, Capabilities),
+        /// Event emitted when a delegate has been removed. [who, admin]
+        DelegateRemoved(T::AccountId, T::AccountId),
+        /// Event emitted when an ownership transfer has been proposed. [who, newOwner]
+        OwnershipTransferProposed(T::AccountId, T::AccountId),
+        /// Event emitted when an ownership transfer has been accepted. [who, newOwner]
+        OwnershipTransferAccepted(T::AccountId, T::AccountId),
+        /// Event emitted when all entities and assignments have been moved. [who, newOwner]
+        OwnershipTransferCompleted(T::AccountId, T::AccountId),
+        /// Event emitted when all operations of a batch have been applied. [who, opCount]
+        BatchApplied(T::AccountId, u32),
+        /// Event emitted when a domain has been imported. [who, itemCount]
+        DomainImported(T::AccountId, u32),
+        /// Event emitted when a user has been added. [who, userId, userName]
+        UserAdded(T::AccountId, T::EntityId, Vec<u8>),
+        /// Event emitted when a user has been updated. [who, userId, userName]
+        UserUpdated(T::AccountId, T::EntityId, Vec<u8>),
+        /// Event emitted when a user has been disabled. [who, userId]
+        UserDisabled(T::AccountId, T::EntityId),
+        /// Event emitted when an account has claimed a user. [who, userId, account]
+        UserClaimed(T::AccountId, T::EntityId, T::AccountId),
+        /// Event emitted when a claim has been approved and the user is bound to the
+        /// account. [who, userId, account]
+        UserBound(T::AccountId, T::EntityId, T::AccountId),
+        /// Event emitted when a user is no longer bound to an account. [who, userId, account]
+        UserUnbound(T::AccountId, T::EntityId, T::AccountId),
     }
 
     // Errors inform users that something went wrong.
@@ -247,6 +587,30 @@ pub mod pallet {
         AssignmentDoesNotExist,
         /// Exceeds BoundedLen bounds
         StorageExceedsMaxBounds,
+        /// Returned if the Entity is already enabled
+        EntityAlreadyEnabled,
+        /// Role inheritance or group nesting would create a cycle
+        CycleDetected,
+        /// Role inheritance or group nesting exceeds MaxRoleDepth or MaxGroupDepth
+        MaxDepthExceeded,
+        /// Roles of a separation of duty constraint would be held together
+        SeparationOfDutyViolation,
+        /// Role constraint does not hold at least two distinct roles
+        InvalidRoleConstraint,
+        /// Assignment would expire at or before the current block
+        InvalidExpiry,
+        /// Returned if there is no proposed or accepted ownership transfer
+        OwnershipTransferDoesNotExist,
+        /// Domain is locked, while its ownership is being transferred
+        OwnershipTransferInProgress,
+        /// New owner is the current owner or does already own a domain
+        InvalidOwnershipTransfer,
+        /// Operation at the given index of a batch failed, no operation has been applied
+        BatchOpFailed { index: u16, error: RbacErrorType },
+        /// More users hold the role or group to be deleted than given by the call
+        InvalidAssignmentWitness,
+        /// Deprecated fetch calls are only dispatchable with the `legacy-fetch-calls` feature
+        LegacyFetchCallsDisabled,
     }
 
     #[pallet::hooks]
@@ -254,6 +618,18 @@ pub mod pallet {
         fn on_runtime_upgrade() -> frame_support::weights::Weight {
             migrations::on_runtime_upgrade::<T>()
         }
+
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::prune_expired_assignments(now, remaining_weight)
+        }
+
+        fn integrity_test() {
+            // Indices of failed batch operations are reported as u16
+            assert!(
+                T::MaxOps::get() <= u16::MAX as u32,
+                "MaxOps must not exceed u16::MAX"
+            );
+        }
     }
 
     impl<T: Config> Error<T> {
@@ -264,456 +640,3133 @@ pub mod pallet {
                 EntityDoesNotExist => Err(Error::<T>::EntityDoesNotExist.into()),
                 EntityAuthorizationFailed => Err(Error::<T>::EntityAuthorizationFailed.into()),
                 EntityDisabled => Err(Error::<T>::EntityDisabled.into()),
+                EntityAlreadyEnabled => Err(Error::<T>::EntityAlreadyEnabled.into()),
                 AssignmentAlreadyExist => Err(Error::<T>::AssignmentAlreadyExist.into()),
                 AssignmentDoesNotExist => Err(Error::<T>::AssignmentDoesNotExist.into()),
                 StorageExceedsMaxBounds => Err(Error::<T>::StorageExceedsMaxBounds.into()),
+                CycleDetected => Err(Error::<T>::CycleDetected.into()),
+                MaxDepthExceeded => Err(Error::<T>::MaxDepthExceeded.into()),
+                SeparationOfDutyViolation => Err(Error::<T>::SeparationOfDutyViolation.into()),
+                InvalidRoleConstraint => Err(Error::<T>::InvalidRoleConstraint.into()),
+                InvalidExpiry => Err(Error::<T>::InvalidExpiry.into()),
             }
         }
     }
 
-    // Dispatchable functions allows users to interact with the pallet and invoke state changes.
-    // These functions materialize as "extrinsics", which are often compared to transactions.
-    // Dispatchable functions must be annotated with a weight and must return a DispatchResult.
-    #[pallet::call]
     impl<T: Config> Pallet<T> {
-        #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::fetch_role())]
-        pub fn fetch_role(
-            origin: OriginFor<T>,
-            owner: T::AccountId,
-            entity: T::EntityId,
-        ) -> DispatchResult {
-            // Check that an extrinsic was signed and get the signer
-            // This fn returns an error if the extrinsic is not signed
-            // https://docs.substrate.io/v3/runtime/origins
-            ensure_signed(origin)?;
+        /// Checks whether a permission has been assigned to a role, without loading
+        /// the permission entities of that role.
+        fn role_grants_permission(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+            permission_id: T::EntityId,
+        ) -> bool {
+            let key = Self::generate_key(owner, &role_id, Tag::Permission2Role);
+            let assign = Permission2Role {
+                permission: permission_id,
+                role: role_id,
+            };
 
-            dpatch_dposit!(Self::get_role(&owner, entity), Event::RoleFetched)
+            <Permission2RoleStore<T>>::get(key)
+                .binary_search(&assign)
+                .is_ok()
         }
 
-        #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::fetch_roles())]
-        pub fn fetch_roles(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResult {
-            ensure_signed(origin)?;
+        /// Checks if an entity exists, regardless of being enabled, and returns its key.
+        fn check_entity_exists_get_key(
+            owner: &T::AccountId,
+            entity_id: &T::EntityId,
+            tag: Tag,
+        ) -> Result<RbacKeyType, RbacError> {
+            let key = Self::generate_key(owner, entity_id, tag);
 
-            dpatch_dposit!(Self::get_roles(&owner), Event::AllRolesFetched)
+            if !<KeysLookUpStore<T>>::contains_key(key) {
+                return RbacError::err(EntityDoesNotExist, entity_id);
+            }
+
+            Ok(key)
         }
 
-        /// create role call
-        #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::add_role())]
-        pub fn add_role(
-            origin: OriginFor<T>,
-            role_id: T::EntityId,
-            name: Vec<u8>,
-        ) -> DispatchResult {
-            let sender = ensure_signed(origin)?;
+        /// Checks if an entity exists and is disabled, and returns its key.
+        fn check_entity_disabled_get_key(
+            owner: &T::AccountId,
+            entity_id: &T::EntityId,
+            tag: Tag,
+        ) -> Result<RbacKeyType, RbacError> {
+            let key = Self::check_entity_exists_get_key(owner, entity_id, tag)?;
 
-            // Verify that the name len is 64 max
-            ensure!(name.len() <= 64, Error::<T>::EntityNameExceedMax64);
+            if <KeysLookUpStore<T>>::get(key).enabled {
+                return RbacError::err(EntityAlreadyEnabled, entity_id);
+            }
 
-            dpatch_dposit_par!(
-                Self::create_role(&sender, role_id, &name),
-                Event::RoleAdded(sender, role_id, name)
-            )
+            Ok(key)
         }
 
-        /// update role call
-        #[pallet::call_index(3)]
-        #[pallet::weight(T::WeightInfo::update_role())]
-        pub fn update_role(
-            origin: OriginFor<T>,
-            role_id: T::EntityId,
-            name: Vec<u8>,
-        ) -> DispatchResult {
-            let sender = ensure_signed(origin)?;
-
-            // Verify that the name len is 64 max
-            ensure!(name.len() <= 64, Error::<T>::EntityNameExceedMax64);
+        /// Fetches an entity while resolving permissions. Disabled entities are either
+        /// skipped by returning `None` or rejected, as defined by `Config::DisabledPolicy`.
+        fn resolve_entity(
+            owner: &T::AccountId,
+            entity_id: &T::EntityId,
+            tag: Tag,
+        ) -> Result<Option<Entity<T::EntityId>>, RbacError> {
+            match Self::get_entity(owner, entity_id, tag) {
+                Ok(entity) => Ok(Some(entity)),
+                Err(RbacError {
+                    typ: EntityDisabled,
+                    ..
+                }) if T::DisabledPolicy::get() == DisabledEntityPolicy::Skip => Ok(None),
+                Err(e) => Err(e),
+            }
+        }
 
-            dpatch_dposit_par!(
-                Self::update_existing_role(&sender, role_id, &name),
-                Event::RoleUpdated(sender, role_id, name)
-            )
+        /// Checks whether a user may be granted permissions while resolving them. Disabled
+        /// users are handled like other disabled entities, unregistered users are only
+        /// accepted, as long as `Config::RequireRegisteredUsers` is not set.
+        fn resolve_user(owner: &T::AccountId, user_id: &T::EntityId) -> Result<bool, RbacError> {
+            match Self::resolve_entity(owner, user_id, Tag::User) {
+                Ok(user) => Ok(user.is_some()),
+                Err(RbacError {
+                    typ: EntityDoesNotExist,
+                    ..
+                }) => Ok(!T::RequireRegisteredUsers::get()),
+                Err(e) => Err(e),
+            }
         }
 
-        #[pallet::call_index(4)]
-        #[pallet::weight(T::WeightInfo::disable_role())]
-        pub fn disable_role(origin: OriginFor<T>, role_id: T::EntityId) -> DispatchResult {
-            let sender = ensure_signed(origin)?;
+        /// Verifies that roles and groups can be assigned to a user. Registered users have to
+        /// be enabled, unregistered ones are rejected if `Config::RequireRegisteredUsers` is set.
+        fn check_user(owner: &T::AccountId, user_id: &T::EntityId) -> Result<(), RbacError> {
+            match Self::check_entity_get_key(owner, user_id, Tag::User) {
+                Err(RbacError {
+                    typ: EntityDoesNotExist,
+                    ..
+                }) if !T::RequireRegisteredUsers::get() => Ok(()),
+                Err(e) => Err(e),
+                Ok(_) => Ok(()),
+            }
+        }
 
-            dpatch_dposit_par!(
-                Self::disable_existing_role(&sender, role_id),
-                Event::RoleRemoved(sender, role_id)
-            )
+        /// Retains matching values of a stored BoundedVec and removes the storage entry
+        /// once the BoundedVec is empty. Use it together with `mutate_exists`.
+        fn retain_or_remove<V>(
+            maybe_val: &mut Option<BoundedVec<V, T::BoundedDataLen>>,
+            f: impl FnMut(&V) -> bool,
+        ) {
+            if let Some(val) = maybe_val {
+                val.retain(f);
+
+                if val.is_empty() {
+                    *maybe_val = None;
+                }
+            }
         }
 
-        #[pallet::call_index(5)]
-        #[pallet::weight(T::WeightInfo::fetch_user_roles())]
-        pub fn fetch_user_roles(
-            origin: OriginFor<T>,
-            owner: T::AccountId,
-            user_id: T::EntityId,
-        ) -> DispatchResult {
-            ensure_signed(origin)?;
+        /// Returns the inherited roles of a role, which are assigned directly.
+        fn inherited_roles(owner: &T::AccountId, role_id: &T::EntityId) -> Vec<T::EntityId> {
+            let key = Self::generate_key(owner, role_id, Tag::Role2Role);
 
-            dpatch_dposit!(
-                Self::get_user_roles(&owner, user_id),
-                Event::FetchedUserRoles
-            )
+            <Role2RoleStore<T>>::get(key)
+                .iter()
+                .map(|r2r| r2r.inherited_role)
+                .collect()
         }
 
-        /// assign a role to user call
-        #[pallet::call_index(6)]
-        #[pallet::weight(T::WeightInfo::assign_role_to_user())]
-        pub fn assign_role_to_user(
-            origin: OriginFor<T>,
-            role_id: T::EntityId,
-            user_id: T::EntityId,
-        ) -> DispatchResult {
-            let sender = ensure_signed(origin)?;
+        /// Returns the groups, which contain a group directly.
+        fn parent_groups(owner: &T::AccountId, group_id: &T::EntityId) -> Vec<T::EntityId> {
+            let key = Self::generate_key(owner, group_id, Tag::Group2Group);
 
-            dpatch_dposit_par!(
-                Self::create_role_to_user(&sender, role_id, user_id),
-                Event::RoleAssignedToUser(sender, role_id, user_id)
-            )
+            <Group2GroupStore<T>>::get(key)
+                .iter()
+                .map(|g2g| g2g.parent)
+                .collect()
         }
 
-        /// unassign role to user relationship call
-        #[pallet::call_index(7)]
-        #[pallet::weight(T::WeightInfo::unassign_role_to_user())]
-        pub fn unassign_role_to_user(
-            origin: OriginFor<T>,
-            role_id: T::EntityId,
-            user_id: T::EntityId,
-        ) -> DispatchResult {
-            let sender = ensure_signed(origin)?;
-
+        /// Verifies that linking `entity_id` to `linked_id` neither creates a cycle nor
+        /// exceeds `max_depth` for any entity of the resulting hierarchy. The `edges` are
+        /// all existing links of that hierarchy.
+        fn check_hierarchy(
+            edges: &[(T::EntityId, T::EntityId)],
+            entity_id: T::EntityId,
+            linked_id: T::EntityId,
+            max_depth: u32,
+        ) -> Result<(), RbacError> {
+            // Levels below entity_id, starting with the newly linked entity
+            let mut depth: u32 = 0;
+            let mut level = vec![linked_id];
+            while !level.is_empty() {
+                if level.contains(&entity_id) {
+                    return RbacError::err(CycleDetected, &linked_id);
+                }
+                depth += 1;
+
+                let mut next: Vec<T::EntityId> = vec![];
+                for (_, linked) in edges.iter().filter(|(from, _)| level.contains(from)) {
+                    if !next.contains(linked) {
+                        next.push(*linked);
+                    }
+                }
+                level = next;
+            }
+
+            // Levels above entity_id, which are linked to it directly or indirectly
+            let mut level = vec![entity_id];
+            loop {
+                let mut next: Vec<T::EntityId> = vec![];
+                for (from, _) in edges.iter().filter(|(_, to)| level.contains(to)) {
+                    if !next.contains(from) {
+                        next.push(*from);
+                    }
+                }
+                if next.is_empty() {
+                    break;
+                }
+                depth += 1;
+                level = next;
+            }
+
+            if depth > max_depth {
+                return RbacError::err(MaxDepthExceeded, &linked_id);
+            }
+
+            Ok(())
+        }
+
+        /// Verifies the role hierarchy, see `check_hierarchy`.
+        fn check_role_hierarchy(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+            inherited_role_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            let edges: Vec<(T::EntityId, T::EntityId)> = <RoleStore<T>>::get(owner)
+                .iter()
+                .flat_map(|role| {
+                    Self::inherited_roles(owner, &role.id)
+                        .into_iter()
+                        .map(move |inherited| (role.id, inherited))
+                })
+                .collect();
+
+            Self::check_hierarchy(&edges, role_id, inherited_role_id, T::MaxRoleDepth::get())
+        }
+
+        /// Verifies the group hierarchy, see `check_hierarchy`.
+        fn check_group_hierarchy(
+            owner: &T::AccountId,
+            group_id: T::EntityId,
+            parent_group_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            let edges: Vec<(T::EntityId, T::EntityId)> = <GroupStore<T>>::get(owner)
+                .iter()
+                .flat_map(|group| {
+                    Self::parent_groups(owner, &group.id)
+                        .into_iter()
+                        .map(move |parent| (group.id, parent))
+                })
+                .collect();
+
+            Self::check_hierarchy(&edges, group_id, parent_group_id, T::MaxGroupDepth::get())
+        }
+
+        /// Returns the entity itself and all entities linked to it within `max_depth` levels,
+        /// which are not processed yet. Disabled entities are handled as defined by
+        /// `Config::DisabledPolicy`, a skipped entity does not pass on its links either.
+        fn expand_hierarchy(
+            owner: &T::AccountId,
+            entity_id: T::EntityId,
+            tag: Tag,
+            max_depth: u32,
+            links: fn(&T::AccountId, &T::EntityId) -> Vec<T::EntityId>,
+            processed: &mut BTreeSet<T::EntityId>,
+        ) -> Result<Vec<T::EntityId>, RbacError> {
+            let mut entities: Vec<T::EntityId> = vec![];
+            let mut level = vec![entity_id];
+            let mut depth: u32 = 0;
+
+            while !level.is_empty() && depth <= max_depth {
+                let mut next: Vec<T::EntityId> = vec![];
+
+                for entity in level {
+                    // use to avoid duplicate transversal
+                    if !processed.insert(entity) {
+                        continue;
+                    }
+
+                    if Self::resolve_entity(owner, &entity, tag)?.is_none() {
+                        continue;
+                    }
+
+                    entities.push(entity);
+                    next.extend(links(owner, &entity));
+                }
+
+                level = next;
+                depth += 1;
+            }
+
+            Ok(entities)
+        }
+
+        /// Returns the role itself and all roles it inherits, see `expand_hierarchy`.
+        fn expand_role(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+            processed_roles: &mut BTreeSet<T::EntityId>,
+        ) -> Result<Vec<T::EntityId>, RbacError> {
+            Self::expand_hierarchy(
+                owner,
+                role_id,
+                Tag::Role,
+                T::MaxRoleDepth::get(),
+                Self::inherited_roles,
+                processed_roles,
+            )
+        }
+
+        /// Adds all resolved permissions of a single role, without following its inheritance.
+        /// Permissions are keyed by their id, so each one is collected only once.
+        fn collect_role_permissions(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+            permissions: &mut BTreeMap<T::EntityId, Entity<T::EntityId>>,
+        ) -> Result<(), RbacError> {
+            let key = Self::generate_key(owner, &role_id, Tag::Permission2Role);
+
+            for p2r in <Permission2RoleStore<T>>::get(key).iter() {
+                if permissions.contains_key(&p2r.permission) {
+                    continue;
+                }
+                if let Some(perm) = Self::resolve_entity(owner, &p2r.permission, Tag::Permission)? {
+                    permissions.insert(p2r.permission, perm);
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Returns the group itself and all groups containing it, see `expand_hierarchy`.
+        fn expand_group(
+            owner: &T::AccountId,
+            group_id: T::EntityId,
+            processed_groups: &mut BTreeSet<T::EntityId>,
+        ) -> Result<Vec<T::EntityId>, RbacError> {
+            Self::expand_hierarchy(
+                owner,
+                group_id,
+                Tag::Group,
+                T::MaxGroupDepth::get(),
+                Self::parent_groups,
+                processed_groups,
+            )
+        }
+
+        /// Returns the paths from an entity to all entities linked to it within `max_depth`
+        /// levels, ordered by length. A path never revisits an entity, but an entity may be
+        /// reached by several paths. Disabled entities are handled as defined by
+        /// `Config::DisabledPolicy`, a skipped entity is never part of a path.
+        fn hierarchy_paths(
+            owner: &T::AccountId,
+            entity_id: T::EntityId,
+            tag: Tag,
+            max_depth: u32,
+            links: fn(&T::AccountId, &T::EntityId) -> Vec<T::EntityId>,
+        ) -> Result<Vec<Vec<T::EntityId>>, RbacError> {
+            let mut paths: Vec<Vec<T::EntityId>> = vec![];
+            let mut level: Vec<Vec<T::EntityId>> = vec![vec![entity_id]];
+            let mut depth: u32 = 0;
+
+            while !level.is_empty() && depth <= max_depth {
+                let mut next: Vec<Vec<T::EntityId>> = vec![];
+
+                for path in level {
+                    let entity = match path.last() {
+                        Some(entity) => *entity,
+                        None => continue,
+                    };
+
+                    if Self::resolve_entity(owner, &entity, tag)?.is_none() {
+                        continue;
+                    }
+
+                    for linked in links(owner, &entity) {
+                        if !path.contains(&linked) {
+                            let mut extended = path.clone();
+                            extended.push(linked);
+                            next.push(extended);
+                        }
+                    }
+                    paths.push(path);
+                }
+
+                level = next;
+                depth += 1;
+            }
+
+            Ok(paths)
+        }
+
+        /// Returns the shortest path from an entity to the first entity linked to it within
+        /// `max_depth` levels, for which `target` returns a result, together with that
+        /// result. Disabled entities are handled like by `hierarchy_paths`.
+        fn find_path<R>(
+            owner: &T::AccountId,
+            entity_id: T::EntityId,
+            tag: Tag,
+            max_depth: u32,
+            links: fn(&T::AccountId, &T::EntityId) -> Vec<T::EntityId>,
+            mut target: impl FnMut(&T::EntityId) -> Result<Option<R>, RbacError>,
+        ) -> Result<Option<(Vec<T::EntityId>, R)>, RbacError> {
+            // Any path to an entity already visited is at least as long
+            let mut visited: BTreeSet<T::EntityId> = BTreeSet::new();
+            visited.insert(entity_id);
+            let mut level: Vec<Vec<T::EntityId>> = vec![vec![entity_id]];
+            let mut depth: u32 = 0;
+
+            while !level.is_empty() && depth <= max_depth {
+                let mut next: Vec<Vec<T::EntityId>> = vec![];
+
+                for path in level {
+                    let entity = match path.last() {
+                        Some(entity) => *entity,
+                        None => continue,
+                    };
+
+                    if Self::resolve_entity(owner, &entity, tag)?.is_none() {
+                        continue;
+                    }
+
+                    if let Some(found) = target(&entity)? {
+                        return Ok(Some((path, found)));
+                    }
+
+                    for linked in links(owner, &entity) {
+                        if visited.insert(linked) {
+                            let mut extended = path.clone();
+                            extended.push(linked);
+                            next.push(extended);
+                        }
+                    }
+                }
+
+                level = next;
+                depth += 1;
+            }
+
+            Ok(None)
+        }
+
+        /// Searches the inheritance graph of a role for all paths to roles, which have been
+        /// assigned the permission. Paths are ordered by length.
+        fn find_permission_paths(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+            permission_id: T::EntityId,
+        ) -> Result<Vec<Vec<T::EntityId>>, RbacError> {
+            Ok(Self::hierarchy_paths(
+                owner,
+                role_id,
+                Tag::Role,
+                T::MaxRoleDepth::get(),
+                Self::inherited_roles,
+            )?
+            .into_iter()
+            .filter(|roles| match roles.last() {
+                Some(role) => Self::role_grants_permission(owner, *role, permission_id),
+                None => false,
+            })
+            .collect())
+        }
+
+        /// Searches the inheritance graph of a role for the shortest path to a role, which has
+        /// been assigned the permission, and stops at the first one found.
+        fn find_permission_path(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+            permission_id: T::EntityId,
+        ) -> Result<Option<Vec<T::EntityId>>, RbacError> {
+            let path = Self::find_path(
+                owner,
+                role_id,
+                Tag::Role,
+                T::MaxRoleDepth::get(),
+                Self::inherited_roles,
+                |role| Ok(Self::role_grants_permission(owner, *role, permission_id).then_some(())),
+            )?;
+
+            Ok(path.map(|(roles, _)| roles))
+        }
+
+        /// Finds the first grant of a permission to a user, see `get_permission_grant`.
+        fn find_permission_grant(
+            owner: &T::AccountId,
+            user_id: T::EntityId,
+            permission_id: T::EntityId,
+        ) -> Result<Option<PermissionGrant<T::EntityId>>, RbacError> {
+            // Disabled or non-existing entities never grant a permission
+            Self::get_permission(owner, permission_id)?;
+            if !Self::resolve_user(owner, &user_id)? {
+                return Ok(None);
+            }
+            Self::check_dynamic_constraints(owner, user_id)?;
+
+            let role_2_user_key = Self::generate_key(owner, &user_id, Tag::Role2User);
+
+            for r2u in <Role2UserStore<T>>::get(role_2_user_key)
+                .iter()
+                .filter(|r2u| Self::is_active(&r2u.expires_at))
+            {
+                if let Some(roles) = Self::find_permission_path(owner, r2u.role, permission_id)? {
+                    return Ok(Some(PermissionGrant {
+                        groups: vec![],
+                        roles,
+                    }));
+                }
+            }
+
+            let user_2_group_key = Self::generate_key(owner, &user_id, Tag::User2Group);
+
+            for u2g in <User2GroupStore<T>>::get(user_2_group_key)
+                .iter()
+                .filter(|u2g| Self::is_active(&u2g.expires_at))
+            {
+                let grant = Self::find_path(
+                    owner,
+                    u2g.group,
+                    Tag::Group,
+                    T::MaxGroupDepth::get(),
+                    Self::parent_groups,
+                    |group| {
+                        let key = Self::generate_key(owner, group, Tag::Role2Group);
+                        for r2g in <Role2GroupStore<T>>::get(key).iter() {
+                            if let Some(roles) =
+                                Self::find_permission_path(owner, r2g.role, permission_id)?
+                            {
+                                return Ok(Some(roles));
+                            }
+                        }
+                        Ok(None)
+                    },
+                )?;
+
+                if let Some((groups, roles)) = grant {
+                    return Ok(Some(PermissionGrant { groups, roles }));
+                }
+            }
+
+            Ok(None)
+        }
+
+        /// Returns the given entities followed by all entities linked to them directly or
+        /// indirectly within `max_depth` levels, regardless of being enabled.
+        fn hierarchy_closure(
+            owner: &T::AccountId,
+            entities: Vec<T::EntityId>,
+            max_depth: u32,
+            links: fn(&T::AccountId, &T::EntityId) -> Vec<T::EntityId>,
+        ) -> Vec<T::EntityId> {
+            let mut closure = entities.clone();
+            let mut level = entities;
+            let mut depth: u32 = 0;
+
+            while !level.is_empty() && depth < max_depth {
+                let mut next: Vec<T::EntityId> = vec![];
+
+                for entity in level.iter() {
+                    for linked in links(owner, entity) {
+                        if !closure.contains(&linked) {
+                            closure.push(linked);
+                            next.push(linked);
+                        }
+                    }
+                }
+
+                level = next;
+                depth += 1;
+            }
+
+            closure
+        }
+
+        /// Returns the given groups followed by all groups containing them, see
+        /// `hierarchy_closure`.
+        fn group_closure(owner: &T::AccountId, groups: Vec<T::EntityId>) -> Vec<T::EntityId> {
+            Self::hierarchy_closure(owner, groups, T::MaxGroupDepth::get(), Self::parent_groups)
+        }
+
+        /// Returns the given roles followed by all roles they inherit, see `hierarchy_closure`.
+        fn role_closure(owner: &T::AccountId, roles: Vec<T::EntityId>) -> Vec<T::EntityId> {
+            Self::hierarchy_closure(owner, roles, T::MaxRoleDepth::get(), Self::inherited_roles)
+        }
+
+        /// Returns the roles, which have been assigned to any of the given groups.
+        fn groups_roles(owner: &T::AccountId, groups: &[T::EntityId]) -> Vec<T::EntityId> {
+            let mut roles: Vec<T::EntityId> = vec![];
+
+            for group in groups.iter() {
+                let key = Self::generate_key(owner, group, Tag::Role2Group);
+
+                for r2g in <Role2GroupStore<T>>::get(key).iter() {
+                    if !roles.contains(&r2g.role) {
+                        roles.push(r2g.role);
+                    }
+                }
+            }
+
+            roles
+        }
+
+        /// Verifies that the roles hold at most one role of every constraint of the given kind.
+        fn check_role_constraints(
+            owner: &T::AccountId,
+            roles: &[T::EntityId],
+            kind: ConstraintKind,
+        ) -> Result<(), RbacError> {
+            for constraint in <RoleConstraintStore<T>>::get(owner).iter() {
+                if constraint.kind == kind {
+                    Self::check_role_constraint(constraint, roles)?;
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Verifies that the roles hold at most one role of the constraint.
+        fn check_role_constraint(
+            constraint: &RoleConstraint<T::EntityId>,
+            roles: &[T::EntityId],
+        ) -> Result<(), RbacError> {
+            if constraint
+                .roles
+                .iter()
+                .filter(|r| roles.contains(r))
+                .count()
+                > 1
+            {
+                return RbacError::err(SeparationOfDutyViolation, &constraint.id);
+            }
+
+            Ok(())
+        }
+
+        /// Verifies the dynamic constraints for the enabled roles a user holds, directly or
+        /// through groups. Used when permissions get resolved.
+        fn check_dynamic_constraints(
+            owner: &T::AccountId,
+            user_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            let roles: Vec<T::EntityId> = Self::get_user_effective_roles(owner, user_id)
+                .into_iter()
+                .filter(|role| Self::get_role(owner, *role).is_ok())
+                .collect();
+
+            Self::check_role_constraints(owner, &roles, ConstraintKind::Dynamic)
+        }
+
+        /// Verifies the static constraints for a group and for all users, which are members
+        /// of it or of any group nested into it, when they would additionally hold new roles
+        /// and the roles these inherit.
+        fn check_group_constraints(
+            owner: &T::AccountId,
+            group_id: T::EntityId,
+            new_roles: &[T::EntityId],
+        ) -> Result<(), RbacError> {
+            let constraints = <RoleConstraintStore<T>>::get(owner);
+            if new_roles.is_empty() || !constraints.iter().any(|c| c.kind == ConstraintKind::Static)
+            {
+                return Ok(());
+            }
+            let new_roles = Self::role_closure(owner, new_roles.to_vec());
+
+            let groups: BTreeSet<T::EntityId> = [group_id].into_iter().collect();
+            Self::check_members_constraints(
+                owner,
+                &groups,
+                BTreeSet::new(),
+                &group_id,
+                |mut roles| {
+                    roles.extend_from_slice(&new_roles);
+                    Self::check_role_constraints(owner, &roles, ConstraintKind::Static)
+                },
+            )
+        }
+
+        /// Verifies the static constraints for all roles, which inherit a role or are the role
+        /// itself, and for all users and groups holding them, when the role would additionally
+        /// inherit another role and the roles this one inherits.
+        fn check_role_link_constraints(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+            inherited_role_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            let constraints = <RoleConstraintStore<T>>::get(owner);
+            if !constraints.iter().any(|c| c.kind == ConstraintKind::Static) {
+                return Ok(());
+            }
+            let new_roles = Self::role_closure(owner, vec![inherited_role_id]);
+
+            let holders: Vec<T::EntityId> = <RoleStore<T>>::get(owner)
+                .iter()
+                .map(|role| role.id)
+                .filter(|id| Self::role_closure(owner, vec![*id]).contains(&role_id))
+                .collect();
+
+            let mut groups: BTreeSet<T::EntityId> = BTreeSet::new();
+            let mut users: BTreeSet<T::EntityId> = BTreeSet::new();
+
+            for holder in holders {
+                let mut roles = Self::role_closure(owner, vec![holder]);
+                roles.extend_from_slice(&new_roles);
+                Self::check_role_constraints(owner, &roles, ConstraintKind::Static)?;
+
+                let key = Self::generate_key(owner, &holder, Tag::RoleGroups);
+                groups.extend(<RoleGroupsStore<T>>::iter_key_prefix(key));
+
+                let key = Self::generate_key(owner, &holder, Tag::RoleUsers);
+                for user in <RoleUsersStore<T>>::iter_key_prefix(key) {
+                    Self::add_checked_user(&mut users, user, &role_id)?;
+                }
+            }
+
+            Self::check_members_constraints(owner, &groups, users, &role_id, |mut roles| {
+                roles.extend_from_slice(&new_roles);
+                Self::check_role_constraints(owner, &roles, ConstraintKind::Static)
+            })
+        }
+
+        /// Verifies that no group or user does already hold more than one role of a new
+        /// static constraint, directly, through groups or by inheritance.
+        fn check_constraint_holders(
+            owner: &T::AccountId,
+            constraint: &RoleConstraint<T::EntityId>,
+        ) -> Result<(), RbacError> {
+            let holders: Vec<T::EntityId> = <RoleStore<T>>::get(owner)
+                .iter()
+                .map(|role| role.id)
+                .filter(|id| {
+                    Self::role_closure(owner, vec![*id])
+                        .iter()
+                        .any(|role| constraint.roles.contains(role))
+                })
+                .collect();
+
+            let mut groups: BTreeSet<T::EntityId> = BTreeSet::new();
+            let mut users: BTreeSet<T::EntityId> = BTreeSet::new();
+
+            for holder in holders {
+                let key = Self::generate_key(owner, &holder, Tag::RoleGroups);
+                groups.extend(<RoleGroupsStore<T>>::iter_key_prefix(key));
+
+                let key = Self::generate_key(owner, &holder, Tag::RoleUsers);
+                for user in <RoleUsersStore<T>>::iter_key_prefix(key) {
+                    Self::add_checked_user(&mut users, user, &constraint.id)?;
+                }
+            }
+
+            Self::check_members_constraints(owner, &groups, users, &constraint.id, |roles| {
+                Self::check_role_constraint(constraint, &roles)
+            })
+        }
+
+        /// Runs `check` on the roles of each group and on the roles of all users, which are
+        /// given or are members of any of the groups or of a group nested into them. At most
+        /// `BoundedDataLen` users get checked, so the check stays within its benchmarked
+        /// weight, otherwise it fails with `StorageExceedsMaxBounds` for `entity_id`.
+        fn check_members_constraints(
+            owner: &T::AccountId,
+            groups: &BTreeSet<T::EntityId>,
+            mut users: BTreeSet<T::EntityId>,
+            entity_id: &T::EntityId,
+            check: impl Fn(Vec<T::EntityId>) -> Result<(), RbacError>,
+        ) -> Result<(), RbacError> {
+            if groups.is_empty() && users.is_empty() {
+                return Ok(());
+            }
+
+            for group in groups.iter() {
+                check(Self::role_closure(
+                    owner,
+                    Self::groups_roles(owner, &Self::group_closure(owner, vec![*group])),
+                ))?;
+            }
+
+            let members: Vec<T::EntityId> = <GroupStore<T>>::get(owner)
+                .iter()
+                .map(|group| group.id)
+                .filter(|id| {
+                    Self::group_closure(owner, vec![*id])
+                        .iter()
+                        .any(|group| groups.contains(group))
+                })
+                .collect();
+
+            for group in members {
+                let key = Self::generate_key(owner, &group, Tag::GroupUsers);
+                for user in <GroupUsersStore<T>>::iter_key_prefix(key) {
+                    Self::add_checked_user(&mut users, user, entity_id)?;
+                }
+            }
+
+            for user in users {
+                check(Self::get_user_effective_roles(owner, user))?;
+            }
+
+            Ok(())
+        }
+
+        /// Adds a user to the users, whose constraints get checked by
+        /// `check_members_constraints`, as long as they stay within `BoundedDataLen`.
+        fn add_checked_user(
+            users: &mut BTreeSet<T::EntityId>,
+            user: T::EntityId,
+            entity_id: &T::EntityId,
+        ) -> Result<(), RbacError> {
+            users.insert(user);
+
+            if users.len() > T::BoundedDataLen::get() as usize {
+                return RbacError::err(StorageExceedsMaxBounds, entity_id);
+            }
+
+            Ok(())
+        }
+
+        /// Checks whether an account may manage the given owner's RBAC tree with the given
+        /// capability. Owners always can, other accounts need a delegation.
+        fn is_admin(who: &T::AccountId, owner: &T::AccountId, capability: Capability) -> bool {
+            who == owner
+                || <Admin2OwnerStore<T>>::get(owner, who)
+                    .map(|capabilities| capabilities.allows(capability))
+                    .unwrap_or(false)
+        }
+
+        /// Rejects the deprecated fetch calls, unless the `legacy-fetch-calls` feature is on.
+        fn ensure_legacy_fetch_calls() -> DispatchResult {
+            ensure!(
+                cfg!(feature = "legacy-fetch-calls"),
+                Error::<T>::LegacyFetchCallsDisabled
+            );
+            Ok(())
+        }
+
+        /// Checks the delegation like `is_admin` and that the domain is not locked by an
+        /// ownership transfer.
+        fn ensure_admin(
+            who: &T::AccountId,
+            owner: &T::AccountId,
+            capability: Capability,
+        ) -> DispatchResult {
+            ensure!(
+                !Self::is_transferring(owner),
+                Error::<T>::OwnershipTransferInProgress
+            );
+            ensure!(
+                Self::is_admin(who, owner, capability),
+                Error::<T>::EntityAuthorizationFailed
+            );
+            Ok(())
+        }
+
+        /// Checks whether the domain of an account is being moved from or to another account.
+        fn is_transferring(owner: &T::AccountId) -> bool {
+            let outgoing = match <OwnershipTransferStore<T>>::get(owner) {
+                Some(transfer) => transfer.stage != TransferStage::Proposed,
+                None => false,
+            };
+            outgoing || <IncomingOwnershipStore<T>>::contains_key(owner)
+        }
+
+        /// Checks whether an account does not own any roles, permissions or groups and has
+        /// not delegated its domain, as delegates of the moved domain would overwrite them.
+        fn is_domain_empty(owner: &T::AccountId) -> bool {
+            !<RoleStore<T>>::contains_key(owner)
+                && !<PermissionStore<T>>::contains_key(owner)
+                && !<GroupStore<T>>::contains_key(owner)
+                && !<RoleConstraintStore<T>>::contains_key(owner)
+                && !<UserStore<T>>::contains_key(owner)
+                && <User2AccountStore<T>>::iter_prefix(owner).next().is_none()
+                && <Admin2OwnerStore<T>>::iter_prefix(owner).next().is_none()
+        }
+
+        /// Moves a single entity or assignment of a domain to its new owner, advancing the
+        /// transfer's stage once a store is exhausted. Returns false, once all has been moved.
+        fn transfer_ownership_step(
+            owner: &T::AccountId,
+            transfer: &mut OwnershipTransfer<T::AccountId>,
+        ) -> bool {
+            let new_owner = transfer.new_owner.clone();
+            let keys = |id: &T::EntityId, tag: Tag| {
+                (
+                    Self::generate_key(owner, id, tag),
+                    Self::generate_key(&new_owner, id, tag),
+                )
+            };
+
+            match transfer.stage {
+                TransferStage::Proposed => return false,
+                TransferStage::Roles => {
+                    // Constraints only consist of roles, so these are moved along
+                    if <RoleConstraintStore<T>>::contains_key(owner) {
+                        let constraints = <RoleConstraintStore<T>>::take(owner);
+                        <RoleConstraintStore<T>>::insert(&new_owner, constraints);
+                    }
+
+                    // The assignments of a role are moved one at a time ahead of the role
+                    match <RoleStore<T>>::get(owner).first().map(|role| role.id) {
+                        Some(role_id) => {
+                            if let Some((user_id, _)) =
+                                rekey_first!(RoleUsersStore, keys(&role_id, Tag::RoleUsers))
+                            {
+                                Self::transfer_role_to_user(owner, &new_owner, role_id, user_id);
+                            } else if rekey_first!(RoleGroupsStore, keys(&role_id, Tag::RoleGroups))
+                                .is_none()
+                            {
+                                move_first_entity!(RoleStore, owner, &new_owner);
+                                rekey!(KeysLookUpStore, keys(&role_id, Tag::Role));
+                                rekey!(Permission2RoleStore, keys(&role_id, Tag::Permission2Role));
+                                rekey!(Role2RoleStore, keys(&role_id, Tag::Role2Role));
+                            }
+                        }
+                        None => transfer.stage = TransferStage::Permissions,
+                    }
+                }
+                TransferStage::Permissions => {
+                    match <PermissionStore<T>>::get(owner)
+                        .first()
+                        .map(|permission| permission.id)
+                    {
+                        Some(permission_id) => {
+                            if rekey_first!(
+                                PermissionRolesStore,
+                                keys(&permission_id, Tag::PermissionRoles)
+                            )
+                            .is_none()
+                            {
+                                move_first_entity!(PermissionStore, owner, &new_owner);
+                                rekey!(KeysLookUpStore, keys(&permission_id, Tag::Permission));
+                            }
+                        }
+                        None => transfer.stage = TransferStage::Groups,
+                    }
+                }
+                TransferStage::Groups => {
+                    match <GroupStore<T>>::get(owner).first().map(|group| group.id) {
+                        Some(group_id) => {
+                            if let Some((user_id, _)) =
+                                rekey_first!(GroupUsersStore, keys(&group_id, Tag::GroupUsers))
+                            {
+                                Self::transfer_user_to_group(owner, &new_owner, user_id, group_id);
+                            } else {
+                                move_first_entity!(GroupStore, owner, &new_owner);
+                                rekey!(KeysLookUpStore, keys(&group_id, Tag::Group));
+                                rekey!(Role2GroupStore, keys(&group_id, Tag::Role2Group));
+                                rekey!(Group2GroupStore, keys(&group_id, Tag::Group2Group));
+                            }
+                        }
+                        None => transfer.stage = TransferStage::Users,
+                    }
+                }
+                TransferStage::Users => match move_first_entity!(UserStore, owner, &new_owner) {
+                    Some(user_id) => {
+                        rekey!(KeysLookUpStore, keys(&user_id, Tag::User));
+                    }
+                    None => transfer.stage = TransferStage::Delegates,
+                },
+                TransferStage::Delegates => {
+                    let next = <Admin2OwnerStore<T>>::iter_prefix(owner).next();
+
+                    match next {
+                        Some((admin, capabilities)) => {
+                            <Admin2OwnerStore<T>>::remove(owner, &admin);
+                            if admin != new_owner {
+                                <Admin2OwnerStore<T>>::insert(&new_owner, &admin, capabilities);
+                            }
+                        }
+                        None => transfer.stage = TransferStage::Bindings,
+                    }
+                }
+                TransferStage::Bindings => {
+                    let binding = <User2AccountStore<T>>::iter_prefix(owner).next();
+                    let claim = <UserClaimStore<T>>::iter_prefix(owner).next();
+
+                    match (binding, claim) {
+                        (Some((user, account)), _) => {
+                            <User2AccountStore<T>>::remove(owner, user);
+                            <Account2UserStore<T>>::remove(owner, &account);
+                            <User2AccountStore<T>>::insert(&new_owner, user, &account);
+                            <Account2UserStore<T>>::insert(&new_owner, &account, user);
+                        }
+                        (None, Some((user, account))) => {
+                            <UserClaimStore<T>>::remove(owner, user);
+                            <UserClaimStore<T>>::insert(&new_owner, user, &account);
+                        }
+                        (None, None) => return false,
+                    }
+                }
+            }
+
+            true
+        }
+
+        /// Moves the assignment of a role to a user to the new owner of a domain.
+        fn transfer_role_to_user(
+            owner: &T::AccountId,
+            new_owner: &T::AccountId,
+            role_id: T::EntityId,
+            user_id: T::EntityId,
+        ) {
+            let from = Self::generate_key(owner, &user_id, Tag::Role2User);
+            let mut from_roles = <Role2UserStore<T>>::get(&from);
+            let r2u = match from_roles.iter().position(|r2u| r2u.role == role_id) {
+                Some(idx) => from_roles.remove(idx),
+                None => return,
+            };
+            if from_roles.is_empty() {
+                <Role2UserStore<T>>::remove(&from);
+            } else {
+                <Role2UserStore<T>>::insert(&from, from_roles);
+            }
+
+            Self::reschedule_expiry(
+                owner,
+                new_owner,
+                &r2u.expires_at,
+                Assignment::Role2User {
+                    role: role_id,
+                    user: user_id,
+                },
+            );
+
+            // The new owner's domain was empty, so it only holds moved assignments
+            let to = Self::generate_key(new_owner, &user_id, Tag::Role2User);
+            let mut to_roles = <Role2UserStore<T>>::get(&to);
+            let idx = to_roles.partition_point(|x| x < &r2u);
+            let _ = to_roles.try_insert(idx, r2u);
+            <Role2UserStore<T>>::insert(&to, to_roles);
+        }
+
+        /// Moves the assignment of a user to a group to the new owner of a domain.
+        fn transfer_user_to_group(
+            owner: &T::AccountId,
+            new_owner: &T::AccountId,
+            user_id: T::EntityId,
+            group_id: T::EntityId,
+        ) {
+            let from = Self::generate_key(owner, &user_id, Tag::User2Group);
+            let mut from_groups = <User2GroupStore<T>>::get(&from);
+            let u2g = match from_groups.iter().position(|u2g| u2g.group == group_id) {
+                Some(idx) => from_groups.remove(idx),
+                None => return,
+            };
+            if from_groups.is_empty() {
+                <User2GroupStore<T>>::remove(&from);
+            } else {
+                <User2GroupStore<T>>::insert(&from, from_groups);
+            }
+
+            Self::reschedule_expiry(
+                owner,
+                new_owner,
+                &u2g.expires_at,
+                Assignment::User2Group {
+                    user: user_id,
+                    group: group_id,
+                },
+            );
+
+            // The new owner's domain was empty, so it only holds moved assignments
+            let to = Self::generate_key(new_owner, &user_id, Tag::User2Group);
+            let mut to_groups = <User2GroupStore<T>>::get(&to);
+            let idx = to_groups.partition_point(|x| x < &u2g);
+            let _ = to_groups.try_insert(idx, u2g);
+            <User2GroupStore<T>>::insert(&to, to_groups);
+        }
+
+        /// Moves a queued expiring assignment to the new owner of a domain.
+        fn reschedule_expiry(
+            owner: &T::AccountId,
+            new_owner: &T::AccountId,
+            expires_at: &Option<BlockNumberFor<T>>,
+            assignment: Assignment<T::EntityId>,
+        ) {
+            if let Some(block) = expires_at {
+                let mut queued = false;
+                <ExpiryStore<T>>::mutate_exists(block, owner, |v| {
+                    Self::retain_or_remove(v, |a| {
+                        let matches = *a == assignment;
+                        queued |= matches;
+                        !matches
+                    })
+                });
+
+                if queued {
+                    // The new owner's domain was empty, so its queues only hold moved entries
+                    <ExpiryStore<T>>::mutate(block, new_owner, |queue| {
+                        let _ = queue.try_push(assignment);
+                    });
+                }
+            }
+        }
+
+        /// Sums up the weights of the calls, which correspond to the operations of a batch.
+        pub(crate) fn batch_weight(ops: &[RbacOp<T::EntityId, BlockNumberFor<T>>]) -> Weight {
+            ops.iter().fold(T::DbWeight::get().reads(2), |weight, op| {
+                weight.saturating_add(match op {
+                    RbacOp::AddRole { .. } => T::WeightInfo::add_role(),
+                    RbacOp::UpdateRole { .. } => T::WeightInfo::update_role(),
+                    RbacOp::DisableRole { .. } => T::WeightInfo::disable_role(),
+                    RbacOp::AddPermission { .. } => T::WeightInfo::add_permission(),
+                    RbacOp::UpdatePermission { .. } => T::WeightInfo::update_permission(),
+                    RbacOp::DisablePermission { .. } => T::WeightInfo::disable_permission(),
+                    RbacOp::AddGroup { .. } => T::WeightInfo::add_group(),
+                    RbacOp::UpdateGroup { .. } => T::WeightInfo::update_group(),
+                    RbacOp::DisableGroup { .. } => T::WeightInfo::disable_group(),
+                    RbacOp::AssignRoleToUser { .. } => T::WeightInfo::assign_role_to_user(),
+                    RbacOp::UnassignRoleToUser { .. } => T::WeightInfo::unassign_role_to_user(),
+                    RbacOp::AssignPermissionToRole { .. } => {
+                        T::WeightInfo::assign_permission_to_role()
+                    }
+                    RbacOp::UnassignPermissionToRole { .. } => {
+                        T::WeightInfo::unassign_permission_to_role()
+                    }
+                    RbacOp::AssignRoleToGroup { .. } => T::WeightInfo::assign_role_to_group(),
+                    RbacOp::UnassignRoleToGroup { .. } => T::WeightInfo::unassign_role_to_group(),
+                    RbacOp::AssignUserToGroup { .. } => T::WeightInfo::assign_user_to_group(),
+                    RbacOp::UnassignUserToGroup { .. } => T::WeightInfo::unassign_user_to_group(),
+                    RbacOp::AssignRoleToRole { .. } => T::WeightInfo::assign_role_to_role(),
+                    RbacOp::UnassignRoleToRole { .. } => T::WeightInfo::unassign_role_to_role(),
+                    RbacOp::AssignGroupToGroup { .. } => T::WeightInfo::assign_group_to_group(),
+                    RbacOp::UnassignGroupToGroup { .. } => T::WeightInfo::unassign_group_to_group(),
+                    RbacOp::AddUser { .. } => T::WeightInfo::add_user(),
+                    RbacOp::UpdateUser { .. } => T::WeightInfo::update_user(),
+                    RbacOp::DisableUser { .. } => T::WeightInfo::disable_user(),
+                })
+            })
+        }
+
+        /// Sums up the weights of the calls, which correspond to the items of an import.
+        pub(crate) fn import_weight(domain: &RbacDomain<T::EntityId, BlockNumberFor<T>>) -> Weight {
+            let weight_of = |count: usize, weight: Weight| weight.saturating_mul(count as u64);
+            let assignments = domain.role_users.len()
+                + domain.group_users.len()
+                + domain.group_roles.len()
+                + domain.role_permissions.len()
+                + domain.role_roles.len()
+                + domain.group_groups.len();
+            let constraint_roles: usize = domain.constraints.iter().map(|c| c.roles.len()).sum();
+
+            T::DbWeight::get()
+                .reads(2)
+                .saturating_add(weight_of(domain.roles.len(), T::WeightInfo::add_role()))
+                .saturating_add(weight_of(
+                    domain.permissions.len(),
+                    T::WeightInfo::add_permission(),
+                ))
+                .saturating_add(weight_of(domain.groups.len(), T::WeightInfo::add_group()))
+                .saturating_add(weight_of(domain.users.len(), T::WeightInfo::add_user()))
+                .saturating_add(weight_of(
+                    domain.role_users.len(),
+                    T::WeightInfo::assign_role_to_user(),
+                ))
+                .saturating_add(weight_of(
+                    domain.group_users.len(),
+                    T::WeightInfo::assign_user_to_group(),
+                ))
+                .saturating_add(weight_of(
+                    domain.group_roles.len(),
+                    T::WeightInfo::assign_role_to_group(),
+                ))
+                .saturating_add(weight_of(
+                    domain.role_permissions.len(),
+                    T::WeightInfo::assign_permission_to_role(),
+                ))
+                .saturating_add(weight_of(
+                    domain.role_roles.len(),
+                    T::WeightInfo::assign_role_to_role(),
+                ))
+                .saturating_add(weight_of(
+                    domain.group_groups.len(),
+                    T::WeightInfo::assign_group_to_group(),
+                ))
+                .saturating_add(domain.constraints.iter().fold(Weight::zero(), |weight, c| {
+                    weight.saturating_add(T::WeightInfo::add_role_constraint(c.roles.len() as u32))
+                }))
+                // Existing assignments and assigned entities are looked up upfront, as are the
+                // roles of each constraint
+                .saturating_add(
+                    T::DbWeight::get().reads(
+                        (assignments as u64)
+                            .saturating_mul(3)
+                            .saturating_add(constraint_roles as u64),
+                    ),
+                )
+        }
+
+        /// Applies a single operation of a batch to the owner's domain and deposits the same
+        /// event as the corresponding call.
+        fn apply_op(
+            who: &T::AccountId,
+            owner: &T::AccountId,
+            op: RbacOp<T::EntityId, BlockNumberFor<T>>,
+        ) -> Result<(), RbacError> {
+            if !Self::is_admin(who, owner, op.capability()) {
+                return RbacError::err(EntityAuthorizationFailed, who);
+            }
+
+            let owner = owner.clone();
+            let event = match op {
+                RbacOp::AddRole { role, name } => {
+                    Self::check_name(&name)?;
+                    Self::create_role(&owner, role, &name)?;
+                    Event::RoleAdded(owner, role, name)
+                }
+                RbacOp::UpdateRole { role, name } => {
+                    Self::check_name(&name)?;
+                    Self::update_existing_role(&owner, role, &name)?;
+                    Event::RoleUpdated(owner, role, name)
+                }
+                RbacOp::DisableRole { role } => {
+                    Self::disable_existing_role(&owner, role)?;
+                    Event::RoleRemoved(owner, role)
+                }
+                RbacOp::AddPermission { permission, name } => {
+                    Self::check_name(&name)?;
+                    Self::create_permission(&owner, permission, &name)?;
+                    Event::PermissionAdded(owner, permission, name)
+                }
+                RbacOp::UpdatePermission { permission, name } => {
+                    Self::check_name(&name)?;
+                    Self::update_existing_permission(&owner, permission, &name)?;
+                    Event::PermissionUpdated(owner, permission, name)
+                }
+                RbacOp::DisablePermission { permission } => {
+                    Self::disable_existing_permission(&owner, permission)?;
+                    Event::PermissionDisabled(owner, permission)
+                }
+                RbacOp::AddGroup { group, name } => {
+                    Self::check_name(&name)?;
+                    Self::create_group(&owner, group, &name)?;
+                    Event::GroupAdded(owner, group, name)
+                }
+                RbacOp::UpdateGroup { group, name } => {
+                    Self::check_name(&name)?;
+                    Self::update_existing_group(&owner, group, &name)?;
+                    Event::GroupUpdated(owner, group, name)
+                }
+                RbacOp::DisableGroup { group } => {
+                    Self::disable_existing_group(&owner, group)?;
+                    Event::GroupDisabled(owner, group)
+                }
+                RbacOp::AssignRoleToUser {
+                    role,
+                    user,
+                    expires_at,
+                } => {
+                    Self::create_role_to_user(&owner, role, user, expires_at)?;
+                    Event::RoleAssignedToUser(owner, role, user)
+                }
+                RbacOp::UnassignRoleToUser { role, user } => {
+                    Self::revoke_role_to_user(&owner, role, user)?;
+                    Event::RoleUnassignedToUser(owner, role, user)
+                }
+                RbacOp::AssignPermissionToRole { permission, role } => {
+                    Self::create_permission_to_role(&owner, permission, role)?;
+                    Event::PermissionAssigned(owner, permission, role)
+                }
+                RbacOp::UnassignPermissionToRole { permission, role } => {
+                    Self::revoke_permission_to_role(&owner, permission, role)?;
+                    Event::PermissionUnassignedToRole(owner, permission, role)
+                }
+                RbacOp::AssignRoleToGroup { role, group } => {
+                    Self::create_role_to_group(&owner, role, group)?;
+                    Event::RoleAssignedToGroup(owner, role, group)
+                }
+                RbacOp::UnassignRoleToGroup { role, group } => {
+                    Self::revoke_role_to_group(&owner, role, group)?;
+                    Event::RoleUnassignedToGroup(owner, role, group)
+                }
+                RbacOp::AssignUserToGroup {
+                    user,
+                    group,
+                    expires_at,
+                } => {
+                    Self::create_user_to_group(&owner, user, group, expires_at)?;
+                    Event::UserAssignedToGroup(owner, user, group)
+                }
+                RbacOp::UnassignUserToGroup { user, group } => {
+                    Self::revoke_user_to_group(&owner, user, group)?;
+                    Event::UserUnAssignedToGroup(owner, user, group)
+                }
+                RbacOp::AssignRoleToRole {
+                    role,
+                    inherited_role,
+                } => {
+                    Self::create_role_to_role(&owner, role, inherited_role)?;
+                    Event::RoleAssignedToRole(owner, role, inherited_role)
+                }
+                RbacOp::UnassignRoleToRole {
+                    role,
+                    inherited_role,
+                } => {
+                    Self::revoke_role_to_role(&owner, role, inherited_role)?;
+                    Event::RoleUnassignedToRole(owner, role, inherited_role)
+                }
+                RbacOp::AssignGroupToGroup { group, parent } => {
+                    Self::create_group_to_group(&owner, group, parent)?;
+                    Event::GroupAssignedToGroup(owner, group, parent)
+                }
+                RbacOp::UnassignGroupToGroup { group, parent } => {
+                    Self::revoke_group_to_group(&owner, group, parent)?;
+                    Event::GroupUnassignedToGroup(owner, group, parent)
+                }
+                RbacOp::AddUser { user, name } => {
+                    Self::check_name(&name)?;
+                    Self::create_user(&owner, user, &name)?;
+                    Event::UserAdded(owner, user, name)
+                }
+                RbacOp::UpdateUser { user, name } => {
+                    Self::check_name(&name)?;
+                    Self::update_existing_user(&owner, user, &name)?;
+                    Event::UserUpdated(owner, user, name)
+                }
+                RbacOp::DisableUser { user } => {
+                    Self::disable_existing_user(&owner, user)?;
+                    Event::UserDisabled(owner, user)
+                }
+            };
+
+            Self::deposit_event(event);
+            Ok(())
+        }
+
+        /// Checks that a name does not exceed 64 characters, like the single calls do.
+        fn check_name(name: &[u8]) -> Result<(), RbacError> {
+            if name.len() > 64 {
+                return RbacError::err(NameExceedMaxChar, &name.to_vec());
+            }
+            Ok(())
+        }
+
+        /// Returns up to `limit` items ordered by id, which follow the id `start_after`, being
+        /// the last id of the previous page, or the first items if no id is given. Paging
+        /// resumes after a cursor, even if its item got removed in the meantime.
+        fn page<V>(
+            mut items: Vec<V>,
+            id_of: impl Fn(&V) -> T::EntityId,
+            start_after: Option<T::EntityId>,
+            limit: u32,
+        ) -> Vec<V> {
+            // Entities are stored in insertion order
+            items.sort_by_key(|item| id_of(item));
+            let start = match start_after {
+                Some(id) => items.partition_point(|item| id_of(item) <= id),
+                None => 0,
+            };
+
+            items.into_iter().skip(start).take(limit as usize).collect()
+        }
+
+        /// Checks the entity names of a domain and that each kind of entity fits the storage
+        /// bounds, before any of it gets imported.
+        fn check_domain(
+            domain: &RbacDomain<T::EntityId, BlockNumberFor<T>>,
+        ) -> Result<(), RbacError> {
+            for entities in [
+                &domain.roles,
+                &domain.permissions,
+                &domain.groups,
+                &domain.users,
+            ] {
+                if entities.len() > T::BoundedDataLen::get() as usize {
+                    return RbacError::err(StorageExceedsMaxBounds, &(entities.len() as u32));
+                }
+                for entity in entities.iter() {
+                    Self::check_name(&entity.name)?;
+                }
+            }
+            if domain.constraints.len() > T::BoundedDataLen::get() as usize {
+                return RbacError::err(StorageExceedsMaxBounds, &(domain.constraints.len() as u32));
+            }
+
+            // Assignments are stored per entity, e.g. all roles of a user in a single list
+            Self::check_assignment_lists(domain.role_users.iter().map(|r2u| r2u.user))?;
+            Self::check_assignment_lists(domain.group_users.iter().map(|u2g| u2g.user))?;
+            Self::check_assignment_lists(domain.group_roles.iter().map(|r2g| r2g.group))?;
+            Self::check_assignment_lists(domain.role_permissions.iter().map(|p2r| p2r.role))?;
+            Self::check_assignment_lists(domain.role_roles.iter().map(|r2r| r2r.role))?;
+            Self::check_assignment_lists(domain.group_groups.iter().map(|g2g| g2g.group))?;
+
+            Ok(())
+        }
+
+        /// Checks that no entity is given more assignments than fit its stored list, the
+        /// entity of each assignment being given by `keys`.
+        fn check_assignment_lists(
+            keys: impl Iterator<Item = T::EntityId>,
+        ) -> Result<(), RbacError> {
+            let mut counts: BTreeMap<T::EntityId, u32> = BTreeMap::new();
+
+            for key in keys {
+                let count = counts.entry(key).or_default();
+                *count += 1;
+                if *count > T::BoundedDataLen::get() {
+                    return RbacError::err(StorageExceedsMaxBounds, &key);
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Handles an item of an import, which already exists in the owner's domain. Fails in
+        /// `Fail` mode, otherwise returns whether the item gets overwritten.
+        fn overwrite_existing(
+            mode: ImportMode,
+            typ: RbacErrorType,
+            id: &T::EntityId,
+        ) -> Result<bool, RbacError> {
+            match mode {
+                ImportMode::Fail => RbacError::err(typ, id),
+                ImportMode::Skip => Ok(false),
+                ImportMode::Overwrite => Ok(true),
+            }
+        }
+
+        /// Imports the entities, constraints and assignments of a domain into the owner's
+        /// domain. Entities keep their enabled flag, so assignments of disabled entities are
+        /// imported as well.
+        fn import_into(
+            owner: &T::AccountId,
+            domain: RbacDomain<T::EntityId, BlockNumberFor<T>>,
+            mode: ImportMode,
+        ) -> Result<(), RbacError> {
+            Self::check_domain(&domain)?;
+
+            import_entities!(RoleStore, owner, domain.roles, Tag::Role, mode);
+            import_entities!(
+                PermissionStore,
+                owner,
+                domain.permissions,
+                Tag::Permission,
+                mode
+            );
+            import_entities!(GroupStore, owner, domain.groups, Tag::Group, mode);
+            import_entities!(UserStore, owner, domain.users, Tag::User, mode);
+
+            // Assigned entities only need to exist, as disabled ones are imported as well
+            let exists = |id: &T::EntityId, tag: Tag| {
+                Self::check_entity_exists_get_key(owner, id, tag).map(|_| ())
+            };
+            let user_exists = |id: &T::EntityId| match exists(id, Tag::User) {
+                Err(RbacError {
+                    typ: EntityDoesNotExist,
+                    ..
+                }) if !T::RequireRegisteredUsers::get() => Ok(()),
+                res => res,
+            };
+
+            for constraint in domain.constraints.iter() {
+                if Self::role_constraint_of(owner)
+                    .iter()
+                    .any(|c| c.id == constraint.id)
+                {
+                    if !Self::overwrite_existing(mode, EntityAlreadyExist, &constraint.id)? {
+                        continue;
+                    }
+                    Self::delete_role_constraint(owner, constraint.id)?;
+                }
+                Self::create_role_constraint(
+                    owner,
+                    constraint.id,
+                    constraint.kind,
+                    &constraint.roles,
+                )?;
+            }
+
+            // Existing assignments are checked upfront, as their entities may be disabled.
+            // Overwriting only changes assignments, which carry an expiry.
+            for r2r in domain.role_roles.iter() {
+                let key = Self::generate_key(owner, &r2r.role, Tag::Role2Role);
+                if <Role2RoleStore<T>>::get(key).contains(r2r) {
+                    Self::overwrite_existing(mode, AssignmentAlreadyExist, &r2r.inherited_role)?;
+                } else {
+                    exists(&r2r.role, Tag::Role)?;
+                    exists(&r2r.inherited_role, Tag::Role)?;
+                    Self::insert_role_to_role(owner, r2r.role, r2r.inherited_role)?;
+                }
+            }
+            for g2g in domain.group_groups.iter() {
+                let key = Self::generate_key(owner, &g2g.group, Tag::Group2Group);
+                if <Group2GroupStore<T>>::get(key).contains(g2g) {
+                    Self::overwrite_existing(mode, AssignmentAlreadyExist, &g2g.parent)?;
+                } else {
+                    exists(&g2g.group, Tag::Group)?;
+                    exists(&g2g.parent, Tag::Group)?;
+                    Self::insert_group_to_group(owner, g2g.group, g2g.parent)?;
+                }
+            }
+            for p2r in domain.role_permissions.iter() {
+                let key = Self::generate_key(owner, &p2r.role, Tag::Permission2Role);
+                if <Permission2RoleStore<T>>::get(key).contains(p2r) {
+                    Self::overwrite_existing(mode, AssignmentAlreadyExist, &p2r.role)?;
+                } else {
+                    exists(&p2r.role, Tag::Role)?;
+                    exists(&p2r.permission, Tag::Permission)?;
+                    Self::insert_permission_to_role(owner, p2r.permission, p2r.role)?;
+                }
+            }
+            for r2g in domain.group_roles.iter() {
+                let key = Self::generate_key(owner, &r2g.group, Tag::Role2Group);
+                if <Role2GroupStore<T>>::get(key).contains(r2g) {
+                    Self::overwrite_existing(mode, AssignmentAlreadyExist, &r2g.group)?;
+                } else {
+                    exists(&r2g.role, Tag::Role)?;
+                    exists(&r2g.group, Tag::Group)?;
+                    Self::insert_role_to_group(owner, r2g.role, r2g.group)?;
+                }
+            }
+            for r2u in domain.role_users.iter() {
+                let key = Self::generate_key(owner, &r2u.user, Tag::Role2User);
+                if <Role2UserStore<T>>::get(key).iter().any(|x| {
+                    x.role == r2u.role && x.user == r2u.user && Self::is_active(&x.expires_at)
+                }) {
+                    if !Self::overwrite_existing(mode, AssignmentAlreadyExist, &r2u.user)? {
+                        continue;
+                    }
+                    Self::revoke_role_to_user(owner, r2u.role, r2u.user)?;
+                }
+                exists(&r2u.role, Tag::Role)?;
+                user_exists(&r2u.user)?;
+                Self::insert_role_to_user(owner, r2u.role, r2u.user, r2u.expires_at)?;
+            }
+            for u2g in domain.group_users.iter() {
+                let key = Self::generate_key(owner, &u2g.user, Tag::User2Group);
+                if <User2GroupStore<T>>::get(key).iter().any(|x| {
+                    x.user == u2g.user && x.group == u2g.group && Self::is_active(&x.expires_at)
+                }) {
+                    if !Self::overwrite_existing(mode, AssignmentAlreadyExist, &u2g.group)? {
+                        continue;
+                    }
+                    Self::revoke_user_to_group(owner, u2g.user, u2g.group)?;
+                }
+                exists(&u2g.group, Tag::Group)?;
+                user_exists(&u2g.user)?;
+                Self::insert_user_to_group(owner, u2g.user, u2g.group, u2g.expires_at)?;
+            }
+
+            Ok(())
+        }
+
+        /// Links two roles like `create_role_to_role`, but accepts disabled roles.
+        fn insert_role_to_role(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+            inherited_role_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            let role_2_role_key = Self::generate_key(owner, &role_id, Tag::Role2Role);
+
+            let mut roles: BoundedVec<Role2Role<T::EntityId>, T::BoundedDataLen> =
+                BoundedVec::new();
+
+            let new_assign = Role2Role {
+                role: role_id,
+                inherited_role: inherited_role_id,
+            };
+
+            // Check if role does already inherit that role
+            if <Role2RoleStore<T>>::contains_key(role_2_role_key) {
+                roles = <Role2RoleStore<T>>::get(role_2_role_key);
+
+                if roles.contains(&new_assign) {
+                    return RbacError::err(AssignmentAlreadyExist, &inherited_role_id);
+                }
+            }
+
+            Self::check_role_hierarchy(owner, role_id, inherited_role_id)?;
+
+            // Check static separation of duty for all roles, groups and users gaining roles
+            Self::check_role_link_constraints(owner, role_id, inherited_role_id)?;
+
+            let idx = roles.partition_point(|x| x < &new_assign);
+
+            match roles.try_insert(idx, new_assign) {
+                Err(e) => return RbacError::err(StorageExceedsMaxBounds, &e),
+                Ok(()) => <Role2RoleStore<T>>::insert(role_2_role_key, roles),
+            }
+
+            Ok(())
+        }
+
+        /// Links two groups like `create_group_to_group`, but accepts disabled groups.
+        fn insert_group_to_group(
+            owner: &T::AccountId,
+            group_id: T::EntityId,
+            parent_group_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            let group_2_group_key = Self::generate_key(owner, &group_id, Tag::Group2Group);
+
+            let mut groups: BoundedVec<Group2Group<T::EntityId>, T::BoundedDataLen> =
+                BoundedVec::new();
+
+            let new_assign = Group2Group {
+                group: group_id,
+                parent: parent_group_id,
+            };
+
+            // Check if group is already a member of that group
+            if <Group2GroupStore<T>>::contains_key(group_2_group_key) {
+                groups = <Group2GroupStore<T>>::get(group_2_group_key);
+
+                if groups.contains(&new_assign) {
+                    return RbacError::err(AssignmentAlreadyExist, &parent_group_id);
+                }
+            }
+
+            Self::check_group_hierarchy(owner, group_id, parent_group_id)?;
+
+            // Check static separation of duty for the group and its members
+            let parent_roles =
+                Self::groups_roles(owner, &Self::group_closure(owner, vec![parent_group_id]));
+            Self::check_group_constraints(owner, group_id, &parent_roles)?;
+
+            let idx = groups.partition_point(|x| x < &new_assign);
+
+            match groups.try_insert(idx, new_assign) {
+                Err(e) => return RbacError::err(StorageExceedsMaxBounds, &e),
+                Ok(()) => <Group2GroupStore<T>>::insert(group_2_group_key, groups),
+            }
+
+            Ok(())
+        }
+
+        /// Assigns a permission like `create_permission_to_role`, but accepts disabled entities.
+        fn insert_permission_to_role(
+            owner: &T::AccountId,
+            permission_id: T::EntityId,
+            role_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            let permission_2_role_key = Self::generate_key(owner, &role_id, Tag::Permission2Role);
+
+            let mut permissions: BoundedVec<Permission2Role<T::EntityId>, T::BoundedDataLen> =
+                BoundedVec::new();
+
+            let new_assign = Permission2Role {
+                permission: permission_id,
+                role: role_id,
+            };
+
+            // Check if permission has already been assigned to role
+            if <Permission2RoleStore<T>>::contains_key(permission_2_role_key) {
+                permissions = <Permission2RoleStore<T>>::get(permission_2_role_key);
+
+                if permissions.contains(&new_assign) {
+                    return RbacError::err(AssignmentAlreadyExist, &role_id);
+                }
+            }
+
+            let idx = permissions.partition_point(|x| x < &new_assign);
+
+            match permissions.try_insert(idx, new_assign.clone()) {
+                Err(e) => return RbacError::err(StorageExceedsMaxBounds, &e),
+                Ok(()) => <Permission2RoleStore<T>>::insert(permission_2_role_key, permissions),
+            }
+
+            let permission_roles_key =
+                Self::generate_key(owner, &permission_id, Tag::PermissionRoles);
+            <PermissionRolesStore<T>>::insert(permission_roles_key, role_id, ());
+
+            Ok(())
+        }
+
+        /// Assigns a role to a group like `create_role_to_group`, but accepts disabled entities.
+        fn insert_role_to_group(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+            group_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            let role_2_group_key = Self::generate_key(owner, &group_id, Tag::Role2Group);
+
+            let mut roles: BoundedVec<Role2Group<T::EntityId>, T::BoundedDataLen> =
+                BoundedVec::new();
+
+            let new_assign = Role2Group {
+                role: role_id,
+                group: group_id,
+            };
+
+            // Check if role has already been assigned to group
+            if <Role2GroupStore<T>>::contains_key(role_2_group_key) {
+                roles = <Role2GroupStore<T>>::get(role_2_group_key);
+
+                if roles.contains(&new_assign) {
+                    return RbacError::err(AssignmentAlreadyExist, &group_id);
+                }
+            }
+
+            // Check static separation of duty for the group and its members
+            Self::check_group_constraints(owner, group_id, &[role_id])?;
+
+            let idx = roles.partition_point(|x| x < &new_assign);
+
+            match roles.try_insert(idx, new_assign.clone()) {
+                Err(e) => return RbacError::err(StorageExceedsMaxBounds, &e),
+                Ok(()) => <Role2GroupStore<T>>::insert(role_2_group_key, roles),
+            }
+
+            let role_groups_key = Self::generate_key(owner, &role_id, Tag::RoleGroups);
+            <RoleGroupsStore<T>>::insert(role_groups_key, group_id, ());
+
+            Ok(())
+        }
+
+        /// Assigns a role to a user like `create_role_to_user`, but accepts disabled entities.
+        fn insert_role_to_user(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+            user_id: T::EntityId,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> Result<(), RbacError> {
+            let role_2_user_key = Self::generate_key(owner, &user_id, Tag::Role2User);
+
+            Self::check_expiry(&expires_at)?;
+
+            let mut roles: BoundedVec<
+                Role2User<T::EntityId, BlockNumberFor<T>>,
+                T::BoundedDataLen,
+            > = BoundedVec::new();
+
+            let new_assign = Role2User {
+                role: role_id,
+                user: user_id,
+                expires_at,
+            };
+
+            // Check if role has already been assigned to user
+            if <Role2UserStore<T>>::contains_key(role_2_user_key) {
+                roles = <Role2UserStore<T>>::get(role_2_user_key);
+
+                match roles.binary_search_by(|x| (x.role, x.user).cmp(&(role_id, user_id))) {
+                    Ok(i) if Self::is_active(&roles[i].expires_at) => {
+                        return RbacError::err(AssignmentAlreadyExist, &user_id);
+                    }
+                    // Replaces an expired assignment, which has not been pruned yet
+                    Ok(i) => {
+                        roles.remove(i);
+                    }
+                    Err(_) => {}
+                }
+            }
+
+            // Check static separation of duty for the user's roles
+            let mut effective_roles = Self::get_user_effective_roles(owner, user_id);
+            effective_roles.extend(Self::role_closure(owner, vec![role_id]));
+            Self::check_role_constraints(owner, &effective_roles, ConstraintKind::Static)?;
+
+            Self::schedule_expiry(
+                owner,
+                &expires_at,
+                Assignment::Role2User {
+                    role: role_id,
+                    user: user_id,
+                },
+            )?;
+
+            let idx = roles.partition_point(|x| x < &new_assign);
+
+            match roles.try_insert(idx, new_assign.clone()) {
+                Err(e) => return RbacError::err(StorageExceedsMaxBounds, &e),
+                Ok(()) => <Role2UserStore<T>>::insert(role_2_user_key, roles),
+            }
+
+            let role_users_key = Self::generate_key(owner, &role_id, Tag::RoleUsers);
+            <RoleUsersStore<T>>::insert(role_users_key, user_id, expires_at);
+
+            Ok(())
+        }
+
+        /// Assigns a user to a group like `create_user_to_group`, but accepts disabled entities.
+        fn insert_user_to_group(
+            owner: &T::AccountId,
+            user_id: T::EntityId,
+            group_id: T::EntityId,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> Result<(), RbacError> {
+            let user_2_group_key = Self::generate_key(owner, &user_id, Tag::User2Group);
+
+            Self::check_expiry(&expires_at)?;
+
+            let mut groups: BoundedVec<
+                User2Group<T::EntityId, BlockNumberFor<T>>,
+                T::BoundedDataLen,
+            > = BoundedVec::new();
+
+            let new_assign = User2Group {
+                user: user_id,
+                group: group_id,
+                expires_at,
+            };
+
+            // Check if role has already been assigned to group
+            if <User2GroupStore<T>>::contains_key(user_2_group_key) {
+                groups = <User2GroupStore<T>>::get(user_2_group_key);
+
+                match groups.binary_search_by(|x| (x.user, x.group).cmp(&(user_id, group_id))) {
+                    Ok(i) if Self::is_active(&groups[i].expires_at) => {
+                        return RbacError::err(AssignmentAlreadyExist, &group_id);
+                    }
+                    // Replaces an expired assignment, which has not been pruned yet
+                    Ok(i) => {
+                        groups.remove(i);
+                    }
+                    Err(_) => {}
+                }
+            }
+
+            // Check static separation of duty for the user's roles
+            let mut effective_roles = Self::get_user_effective_roles(owner, user_id);
+            effective_roles.extend(Self::role_closure(
+                owner,
+                Self::groups_roles(owner, &Self::group_closure(owner, vec![group_id])),
+            ));
+            Self::check_role_constraints(owner, &effective_roles, ConstraintKind::Static)?;
+
+            Self::schedule_expiry(
+                owner,
+                &expires_at,
+                Assignment::User2Group {
+                    user: user_id,
+                    group: group_id,
+                },
+            )?;
+
+            let idx = groups.partition_point(|x| x < &new_assign);
+
+            match groups.try_insert(idx, new_assign) {
+                Err(e) => return RbacError::err(StorageExceedsMaxBounds, &e),
+                Ok(()) => <User2GroupStore<T>>::insert(user_2_group_key, groups),
+            }
+
+            let group_users_key = Self::generate_key(owner, &group_id, Tag::GroupUsers);
+            <GroupUsersStore<T>>::insert(group_users_key, user_id, expires_at);
+
+            Ok(())
+        }
+
+        /// Checks whether an assignment has not expired at the current block.
+        fn is_active(expires_at: &Option<BlockNumberFor<T>>) -> bool {
+            match expires_at {
+                Some(block) => *block > <frame_system::Pallet<T>>::block_number(),
+                None => true,
+            }
+        }
+
+        /// Checks that an assignment does not expire at or before the current block.
+        fn check_expiry(expires_at: &Option<BlockNumberFor<T>>) -> Result<(), RbacError> {
+            match expires_at {
+                Some(block) if !Self::is_active(expires_at) => RbacError::err(InvalidExpiry, block),
+                _ => Ok(()),
+            }
+        }
+
+        /// Queues an expiring assignment, so that it gets pruned by `on_idle` later on.
+        fn schedule_expiry(
+            owner: &T::AccountId,
+            expires_at: &Option<BlockNumberFor<T>>,
+            assignment: Assignment<T::EntityId>,
+        ) -> Result<(), RbacError> {
+            if let Some(block) = expires_at {
+                let mut queue = <ExpiryStore<T>>::get(block, owner);
+
+                match queue.try_push(assignment) {
+                    Err(_) => return RbacError::err(StorageExceedsMaxBounds, block),
+                    Ok(()) => <ExpiryStore<T>>::insert(block, owner, queue),
+                }
+
+                // Pruning starts at the earliest queued block
+                <ExpiryCursor<T>>::mutate(|cursor| match cursor {
+                    Some(cursor) if *cursor <= *block => {}
+                    _ => *cursor = Some(*block),
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Removes a queued assignment, if it still expires at the given block. Assignments,
+        /// which have been revoked or renewed in the meantime, are left untouched.
+        fn expire_assignment(
+            owner: &T::AccountId,
+            assignment: &Assignment<T::EntityId>,
+            block: BlockNumberFor<T>,
+        ) -> bool {
+            let mut expired = false;
+
+            match assignment {
+                Assignment::Role2User { role, user } => {
+                    let key = Self::generate_key(owner, user, Tag::Role2User);
+                    <Role2UserStore<T>>::mutate_exists(key, |v| {
+                        Self::retain_or_remove(v, |r2u| {
+                            let matches = r2u.role == *role && r2u.expires_at == Some(block);
+                            expired |= matches;
+                            !matches
+                        })
+                    });
+                    if expired {
+                        let key = Self::generate_key(owner, role, Tag::RoleUsers);
+                        <RoleUsersStore<T>>::remove(key, user);
+                    }
+                }
+                Assignment::User2Group { user, group } => {
+                    let key = Self::generate_key(owner, user, Tag::User2Group);
+                    <User2GroupStore<T>>::mutate_exists(key, |v| {
+                        Self::retain_or_remove(v, |u2g| {
+                            let matches = u2g.group == *group && u2g.expires_at == Some(block);
+                            expired |= matches;
+                            !matches
+                        })
+                    });
+                    if expired {
+                        let key = Self::generate_key(owner, group, Tag::GroupUsers);
+                        <GroupUsersStore<T>>::remove(key, user);
+                    }
+                }
+            }
+
+            expired
+        }
+
+        /// Prunes the queued assignments, which expired up to the current block, queue by
+        /// queue and block by block as long as the remaining weight allows. A queue, which
+        /// does not fit, gets pruned partially. Returns the consumed weight.
+        fn prune_expired_assignments(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let db_weight = T::DbWeight::get();
+            // Reading the cursor and updating it in the end
+            let mut consumed = db_weight.reads_writes(1, 1);
+
+            if remaining_weight.any_lt(consumed) {
+                return Weight::zero();
+            }
+
+            // Nothing has been queued yet
+            let mut cursor = match <ExpiryCursor<T>>::get() {
+                Some(cursor) => cursor,
+                None => return db_weight.reads(1),
+            };
+
+            // Reading a queue and updating or removing it
+            let step = db_weight.reads_writes(1, 1);
+            // Removing an assignment and its reverse index entry
+            let pruning = db_weight.reads_writes(1, 2);
+
+            while cursor <= now {
+                if remaining_weight.any_lt(consumed.saturating_add(step)) {
+                    break;
+                }
+                consumed = consumed.saturating_add(step);
+
+                let (owner, queue) = match <ExpiryStore<T>>::iter_prefix(cursor).next() {
+                    Some(entry) => entry,
+                    None => {
+                        cursor = cursor.saturating_add(One::one());
+                        continue;
+                    }
+                };
+
+                let mut queue = queue.into_inner();
+                while !remaining_weight.any_lt(consumed.saturating_add(pruning)) {
+                    let assignment = match queue.pop() {
+                        Some(assignment) => assignment,
+                        None => break,
+                    };
+                    consumed = consumed.saturating_add(pruning);
+
+                    if Self::expire_assignment(&owner, &assignment, cursor) {
+                        Self::deposit_event(Event::AssignmentExpired(owner.clone(), assignment));
+                    }
+                }
+
+                if !queue.is_empty() {
+                    // Continues with the rest of the queue next time
+                    <ExpiryStore<T>>::insert(cursor, &owner, BoundedVec::truncate_from(queue));
+                    break;
+                }
+                <ExpiryStore<T>>::remove(cursor, &owner);
+            }
+
+            <ExpiryCursor<T>>::put(cursor);
+
+            consumed
+        }
+
+        /// Checks that an index yields at most `witness` entries, without reading more than
+        /// `witness + 1` of them.
+        fn within_witness(entries: impl Iterator<Item = T::EntityId>, witness: u32) -> bool {
+            entries.take(witness as usize + 1).count() <= witness as usize
+        }
+
+        /// Removes a role from all groups, users, inheriting roles and constraints and removes
+        /// all its permissions and inherited roles. Constraints left with a single role are
+        /// removed as well.
+        fn purge_role_assignments(owner: &T::AccountId, role_id: T::EntityId) {
+            <RoleConstraintStore<T>>::mutate_exists(owner, |v| {
+                if let Some(constraints) = v {
+                    for constraint in constraints.iter_mut() {
+                        constraint.roles.retain(|r| *r != role_id);
+                    }
+                }
+                Self::retain_or_remove(v, |c| c.roles.len() > 1)
+            });
+
+            let permission_2_role_key = Self::generate_key(owner, &role_id, Tag::Permission2Role);
+            for p2r in <Permission2RoleStore<T>>::take(permission_2_role_key).iter() {
+                let key = Self::generate_key(owner, &p2r.permission, Tag::PermissionRoles);
+                <PermissionRolesStore<T>>::remove(key, role_id);
+            }
+
+            let role_2_role_key = Self::generate_key(owner, &role_id, Tag::Role2Role);
+            <Role2RoleStore<T>>::remove(role_2_role_key);
+
+            for role in <RoleStore<T>>::get(owner).iter() {
+                let key = Self::generate_key(owner, &role.id, Tag::Role2Role);
+                <Role2RoleStore<T>>::mutate_exists(key, |v| {
+                    Self::retain_or_remove(v, |r2r| r2r.inherited_role != role_id)
+                });
+            }
+
+            let role_groups_key = Self::generate_key(owner, &role_id, Tag::RoleGroups);
+            let groups: Vec<T::EntityId> = <RoleGroupsStore<T>>::drain_prefix(role_groups_key)
+                .map(|(group, _)| group)
+                .collect();
+            for group in groups {
+                let key = Self::generate_key(owner, &group, Tag::Role2Group);
+                <Role2GroupStore<T>>::mutate_exists(key, |v| {
+                    Self::retain_or_remove(v, |r2g| r2g.role != role_id)
+                });
+            }
+
+            let role_users_key = Self::generate_key(owner, &role_id, Tag::RoleUsers);
+            let users: Vec<T::EntityId> = <RoleUsersStore<T>>::drain_prefix(role_users_key)
+                .map(|(user, _)| user)
+                .collect();
+            for user in users {
+                let key = Self::generate_key(owner, &user, Tag::Role2User);
+                <Role2UserStore<T>>::mutate_exists(key, |v| {
+                    Self::retain_or_remove(v, |r2u| r2u.role != role_id)
+                });
+            }
+        }
+
+        /// Removes a permission from all roles.
+        fn purge_permission_assignments(owner: &T::AccountId, permission_id: T::EntityId) {
+            let permission_roles_key =
+                Self::generate_key(owner, &permission_id, Tag::PermissionRoles);
+            let roles: Vec<T::EntityId> =
+                <PermissionRolesStore<T>>::drain_prefix(permission_roles_key)
+                    .map(|(role, _)| role)
+                    .collect();
+            for role in roles {
+                let key = Self::generate_key(owner, &role, Tag::Permission2Role);
+                <Permission2RoleStore<T>>::mutate_exists(key, |v| {
+                    Self::retain_or_remove(v, |p2r| p2r.permission != permission_id)
+                });
+            }
+        }
+
+        /// Removes all users and nested groups from a group, removes all its roles and
+        /// removes it from all groups containing it.
+        fn purge_group_assignments(owner: &T::AccountId, group_id: T::EntityId) {
+            let role_2_group_key = Self::generate_key(owner, &group_id, Tag::Role2Group);
+            for r2g in <Role2GroupStore<T>>::take(role_2_group_key).iter() {
+                let key = Self::generate_key(owner, &r2g.role, Tag::RoleGroups);
+                <RoleGroupsStore<T>>::remove(key, group_id);
+            }
+
+            let group_2_group_key = Self::generate_key(owner, &group_id, Tag::Group2Group);
+            <Group2GroupStore<T>>::remove(group_2_group_key);
+
+            for group in <GroupStore<T>>::get(owner).iter() {
+                let key = Self::generate_key(owner, &group.id, Tag::Group2Group);
+                <Group2GroupStore<T>>::mutate_exists(key, |v| {
+                    Self::retain_or_remove(v, |g2g| g2g.parent != group_id)
+                });
+            }
+
+            let group_users_key = Self::generate_key(owner, &group_id, Tag::GroupUsers);
+            let users: Vec<T::EntityId> = <GroupUsersStore<T>>::drain_prefix(group_users_key)
+                .map(|(user, _)| user)
+                .collect();
+            for user in users {
+                let key = Self::generate_key(owner, &user, Tag::User2Group);
+                <User2GroupStore<T>>::mutate_exists(key, |v| {
+                    Self::retain_or_remove(v, |u2g| u2g.group != group_id)
+                });
+            }
+        }
+    }
+
+    // Dispatchable functions allows users to interact with the pallet and invoke state changes.
+    // These functions materialize as "extrinsics", which are often compared to transactions.
+    // Dispatchable functions must be annotated with a weight and must return a DispatchResult.
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Deprecated, the `fetch_role` runtime API returns the same without a fee
+        /// (only dispatchable with the `legacy-fetch-calls` feature)
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::fetch_role())]
+        pub fn fetch_role(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            entity: T::EntityId,
+        ) -> DispatchResult {
+            // Check that an extrinsic was signed and get the signer
+            // This fn returns an error if the extrinsic is not signed
+            // https://docs.substrate.io/v3/runtime/origins
+            ensure_signed(origin)?;
+            Self::ensure_legacy_fetch_calls()?;
+
+            dpatch_dposit!(Self::get_role(&owner, entity), Event::RoleFetched)
+        }
+
+        /// Deprecated, the `fetch_roles` runtime API returns the same without a fee
+        /// (only dispatchable with the `legacy-fetch-calls` feature)
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::fetch_roles())]
+        pub fn fetch_roles(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::ensure_legacy_fetch_calls()?;
+
+            dpatch_dposit!(Self::get_roles(&owner), Event::AllRolesFetched)
+        }
+
+        /// create role call
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::add_role())]
+        pub fn add_role(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            role_id: T::EntityId,
+            name: Vec<u8>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageRoles)?;
+
+            // Verify that the name len is 64 max
+            ensure!(name.len() <= 64, Error::<T>::EntityNameExceedMax64);
+
+            dpatch_dposit_par!(
+                Self::create_role(&owner, role_id, &name),
+                Event::RoleAdded(owner, role_id, name)
+            )
+        }
+
+        /// update role call
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::update_role())]
+        pub fn update_role(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            role_id: T::EntityId,
+            name: Vec<u8>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageRoles)?;
+
+            // Verify that the name len is 64 max
+            ensure!(name.len() <= 64, Error::<T>::EntityNameExceedMax64);
+
+            dpatch_dposit_par!(
+                Self::update_existing_role(&owner, role_id, &name),
+                Event::RoleUpdated(owner, role_id, name)
+            )
+        }
+
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::disable_role())]
+        pub fn disable_role(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            role_id: T::EntityId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageRoles)?;
+
+            dpatch_dposit_par!(
+                Self::disable_existing_role(&owner, role_id),
+                Event::RoleRemoved(owner, role_id)
+            )
+        }
+
+        /// Deprecated, the `fetch_user_roles` runtime API returns the same without a fee
+        /// (only dispatchable with the `legacy-fetch-calls` feature)
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::fetch_user_roles())]
+        pub fn fetch_user_roles(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            user_id: T::EntityId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::ensure_legacy_fetch_calls()?;
+
+            dpatch_dposit!(
+                Self::get_user_roles(&owner, user_id),
+                Event::FetchedUserRoles
+            )
+        }
+
+        /// assign a role to user call, which optionally expires at the given block
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::assign_role_to_user())]
+        pub fn assign_role_to_user(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            role_id: T::EntityId,
+            user_id: T::EntityId,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageUsers)?;
+
+            dpatch_dposit_par!(
+                Self::create_role_to_user(&owner, role_id, user_id, expires_at),
+                Event::RoleAssignedToUser(owner, role_id, user_id)
+            )
+        }
+
+        /// unassign role to user relationship call
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::unassign_role_to_user())]
+        pub fn unassign_role_to_user(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            role_id: T::EntityId,
+            user_id: T::EntityId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageUsers)?;
+
+            dpatch_dposit_par!(
+                Self::revoke_role_to_user(&owner, role_id, user_id),
+                Event::RoleUnassignedToUser(owner, role_id, user_id)
+            )
+        }
+
+        /// Deprecated, the `fetch_permission` runtime API returns the same without a fee
+        /// (only dispatchable with the `legacy-fetch-calls` feature)
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::fetch_permission())]
+        pub fn fetch_permission(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            permission_id: T::EntityId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::ensure_legacy_fetch_calls()?;
+
+            dpatch_dposit!(
+                Self::get_permission(&owner, permission_id),
+                Event::PermissionFetched
+            )
+        }
+
+        /// Deprecated, the `fetch_permissions` runtime API returns the same without a fee
+        /// (only dispatchable with the `legacy-fetch-calls` feature)
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::fetch_permissions())]
+        pub fn fetch_permissions(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::ensure_legacy_fetch_calls()?;
+
+            dpatch_dposit!(Self::get_permissions(&owner), Event::AllPermissionsFetched)
+        }
+
+        /// create permission call
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::add_permission())]
+        pub fn add_permission(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            permission_id: T::EntityId,
+            name: Vec<u8>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManagePermissions)?;
+
+            // Verify that the name len is 64 max
+            ensure!(name.len() <= 64, Error::<T>::EntityNameExceedMax64);
+
+            dpatch_dposit_par!(
+                Self::create_permission(&owner, permission_id, &name),
+                Event::PermissionAdded(owner, permission_id, name)
+            )
+        }
+
+        /// update permission call
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::update_permission())]
+        pub fn update_permission(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            permission_id: T::EntityId,
+            name: Vec<u8>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManagePermissions)?;
+
+            // Verify that the name len is 64 max
+            ensure!(name.len() <= 64, Error::<T>::EntityNameExceedMax64);
+
+            dpatch_dposit_par!(
+                Self::update_existing_permission(&owner, permission_id, &name),
+                Event::PermissionUpdated(owner, permission_id, name)
+            )
+        }
+
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::disable_permission())]
+        pub fn disable_permission(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            permission_id: T::EntityId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManagePermissions)?;
+
+            dpatch_dposit_par!(
+                Self::disable_existing_permission(&owner, permission_id),
+                Event::PermissionDisabled(owner, permission_id)
+            )
+        }
+
+        /// Deprecated, the `fetch_role_permissions` runtime API returns the same without a fee
+        /// (only dispatchable with the `legacy-fetch-calls` feature)
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::fetch_role_permissions())]
+        pub fn fetch_role_permissions(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            role_id: T::EntityId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::ensure_legacy_fetch_calls()?;
+
+            dpatch_dposit!(
+                Self::get_role_permissions(&owner, role_id),
+                Event::FetchedRolePermissions
+            )
+        }
+
+        /// assign a permission to role call
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::assign_permission_to_role())]
+        pub fn assign_permission_to_role(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            permission_id: T::EntityId,
+            role_id: T::EntityId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManagePermissions)?;
+
             dpatch_dposit_par!(
-                Self::revoke_role_to_user(&sender, role_id, user_id),
-                Event::RoleUnassignedToUser(sender, role_id, user_id)
+                Self::create_permission_to_role(&owner, permission_id, role_id),
+                Event::PermissionAssigned(owner, permission_id, role_id)
             )
         }
 
-        #[pallet::call_index(8)]
-        #[pallet::weight(T::WeightInfo::fetch_permission())]
-        pub fn fetch_permission(
+        /// unassign permission to role relationship call
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::unassign_permission_to_role())]
+        pub fn unassign_permission_to_role(
             origin: OriginFor<T>,
             owner: T::AccountId,
             permission_id: T::EntityId,
+            role_id: T::EntityId,
         ) -> DispatchResult {
-            ensure_signed(origin)?;
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManagePermissions)?;
 
-            dpatch_dposit!(
-                Self::get_permission(&owner, permission_id),
-                Event::PermissionFetched
+            dpatch_dposit_par!(
+                Self::revoke_permission_to_role(&owner, permission_id, role_id),
+                Event::PermissionUnassignedToRole(owner, permission_id, role_id)
             )
         }
 
-        #[pallet::call_index(9)]
-        #[pallet::weight(T::WeightInfo::fetch_permissions())]
-        pub fn fetch_permissions(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResult {
+        /// Deprecated, the `fetch_group` runtime API returns the same without a fee
+        /// (only dispatchable with the `legacy-fetch-calls` feature)
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::fetch_group())]
+        pub fn fetch_group(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            group_id: T::EntityId,
+        ) -> DispatchResult {
             ensure_signed(origin)?;
+            Self::ensure_legacy_fetch_calls()?;
 
-            dpatch_dposit!(Self::get_permissions(&owner), Event::AllPermissionsFetched)
+            dpatch_dposit!(Self::get_group(&owner, group_id), Event::GroupFetched)
         }
 
-        /// create permission call
-        #[pallet::call_index(10)]
-        #[pallet::weight(T::WeightInfo::add_permission())]
-        pub fn add_permission(
+        /// Deprecated, the `fetch_groups` runtime API returns the same without a fee
+        /// (only dispatchable with the `legacy-fetch-calls` feature)
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::fetch_groups())]
+        pub fn fetch_groups(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::ensure_legacy_fetch_calls()?;
+
+            dpatch_dposit!(Self::get_groups(&owner), Event::AllGroupsFetched)
+        }
+
+        /// create group call
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::add_group())]
+        pub fn add_group(
             origin: OriginFor<T>,
-            permission_id: T::EntityId,
+            owner: T::AccountId,
+            group_id: T::EntityId,
+            name: Vec<u8>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageUsers)?;
+
+            // Verify that the name len is 64 max
+            ensure!(name.len() <= 64, Error::<T>::EntityNameExceedMax64);
+
+            dpatch_dposit_par!(
+                Self::create_group(&owner, group_id, &name),
+                Event::GroupAdded(owner, group_id, name)
+            )
+        }
+
+        /// update group call
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::update_group())]
+        pub fn update_group(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            group_id: T::EntityId,
             name: Vec<u8>,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageUsers)?;
 
             // Verify that the name len is 64 max
             ensure!(name.len() <= 64, Error::<T>::EntityNameExceedMax64);
 
             dpatch_dposit_par!(
-                Self::create_permission(&sender, permission_id, &name),
-                Event::PermissionAdded(sender, permission_id, name)
+                Self::update_existing_group(&owner, group_id, &name),
+                Event::GroupUpdated(owner, group_id, name)
+            )
+        }
+
+        /// disable group call
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::disable_group())]
+        pub fn disable_group(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            group_id: T::EntityId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageUsers)?;
+
+            dpatch_dposit_par!(
+                Self::disable_existing_group(&owner, group_id),
+                Event::GroupDisabled(owner, group_id)
+            )
+        }
+
+        /// assign a role to group call
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::assign_role_to_group())]
+        pub fn assign_role_to_group(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            role_id: T::EntityId,
+            group_id: T::EntityId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageRoles)?;
+
+            dpatch_dposit_par!(
+                Self::create_role_to_group(&owner, role_id, group_id),
+                Event::RoleAssignedToGroup(owner, role_id, group_id)
+            )
+        }
+
+        /// unassign role to group relationship call
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::unassign_role_to_group())]
+        pub fn unassign_role_to_group(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            role_id: T::EntityId,
+            group_id: T::EntityId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageRoles)?;
+
+            dpatch_dposit_par!(
+                Self::revoke_role_to_group(&owner, role_id, group_id),
+                Event::RoleUnassignedToGroup(owner, role_id, group_id)
+            )
+        }
+
+        /// Deprecated, the `fetch_group_roles` runtime API returns the same without a fee
+        /// (only dispatchable with the `legacy-fetch-calls` feature)
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::fetch_group_roles())]
+        pub fn fetch_group_roles(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            group_id: T::EntityId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::ensure_legacy_fetch_calls()?;
+
+            dpatch_dposit!(
+                Self::get_group_roles(&owner, group_id),
+                Event::FetchedGroupRoles
+            )
+        }
+
+        /// assign a user to group call, which optionally expires at the given block
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::assign_user_to_group())]
+        pub fn assign_user_to_group(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            user_id: T::EntityId,
+            group_id: T::EntityId,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageUsers)?;
+
+            dpatch_dposit_par!(
+                Self::create_user_to_group(&owner, user_id, group_id, expires_at),
+                Event::UserAssignedToGroup(owner, user_id, group_id)
+            )
+        }
+
+        /// unassign a user to group call
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::unassign_user_to_group())]
+        pub fn unassign_user_to_group(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            user_id: T::EntityId,
+            group_id: T::EntityId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageUsers)?;
+
+            dpatch_dposit_par!(
+                Self::revoke_user_to_group(&owner, user_id, group_id),
+                Event::UserUnAssignedToGroup(owner, user_id, group_id)
+            )
+        }
+
+        /// Deprecated, the `fetch_user_groups` runtime API returns the same without a fee
+        /// (only dispatchable with the `legacy-fetch-calls` feature)
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::fetch_user_groups())]
+        pub fn fetch_user_groups(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            user_id: T::EntityId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::ensure_legacy_fetch_calls()?;
+
+            dpatch_dposit!(
+                Self::get_user_groups(&owner, user_id),
+                Event::FetchedUserGroups
+            )
+        }
+
+        /// Deprecated, the `fetch_user_permissions` runtime API returns the same without a fee
+        /// (only dispatchable with the `legacy-fetch-calls` feature)
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::fetch_user_permissions())]
+        pub fn fetch_user_permissions(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            user_id: T::EntityId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::ensure_legacy_fetch_calls()?;
+
+            dpatch_dposit!(
+                Self::get_user_permissions(&owner, user_id),
+                Event::FetchedUserPermissions
+            )
+        }
+
+        /// Deprecated, the `fetch_group_permissions` runtime API returns the same without a fee
+        /// (only dispatchable with the `legacy-fetch-calls` feature)
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::fetch_group_permissions())]
+        pub fn fetch_group_permissions(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            group_id: T::EntityId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::ensure_legacy_fetch_calls()?;
+
+            dpatch_dposit!(
+                Self::get_group_permissions(&owner, group_id),
+                Event::FetchedGroupPermissions
+            )
+        }
+
+        /// delete role and all its assignments call, `users` is an upper bound of the users
+        /// holding the role and defines the weight
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::delete_role(*users))]
+        pub fn delete_role(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            role_id: T::EntityId,
+            users: u32,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageRoles)?;
+
+            let role_users_key = Self::generate_key(&owner, &role_id, Tag::RoleUsers);
+            ensure!(
+                Self::within_witness(<RoleUsersStore<T>>::iter_key_prefix(role_users_key), users),
+                Error::<T>::InvalidAssignmentWitness
+            );
+
+            dpatch_dposit_par!(
+                Self::delete_existing_role(&owner, role_id),
+                Event::RoleDeleted(owner, role_id)
+            )
+        }
+
+        /// delete permission and all its assignments call
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::delete_permission())]
+        pub fn delete_permission(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            permission_id: T::EntityId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManagePermissions)?;
+
+            dpatch_dposit_par!(
+                Self::delete_existing_permission(&owner, permission_id),
+                Event::PermissionDeleted(owner, permission_id)
+            )
+        }
+
+        /// delete group and all its assignments call, `users` is an upper bound of the members
+        /// of the group and defines the weight
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::delete_group(*users))]
+        pub fn delete_group(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            group_id: T::EntityId,
+            users: u32,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageUsers)?;
+
+            let group_users_key = Self::generate_key(&owner, &group_id, Tag::GroupUsers);
+            ensure!(
+                Self::within_witness(
+                    <GroupUsersStore<T>>::iter_key_prefix(group_users_key),
+                    users
+                ),
+                Error::<T>::InvalidAssignmentWitness
+            );
+
+            dpatch_dposit_par!(
+                Self::delete_existing_group(&owner, group_id),
+                Event::GroupDeleted(owner, group_id)
+            )
+        }
+
+        /// enable disabled role call
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::enable_role())]
+        pub fn enable_role(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            role_id: T::EntityId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageRoles)?;
+
+            dpatch_dposit_par!(
+                Self::enable_existing_role(&owner, role_id),
+                Event::RoleEnabled(owner, role_id)
             )
         }
 
-        /// update permission call
-        #[pallet::call_index(11)]
-        #[pallet::weight(T::WeightInfo::update_permission())]
-        pub fn update_permission(
+        /// enable disabled permission call
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::enable_permission())]
+        pub fn enable_permission(
             origin: OriginFor<T>,
+            owner: T::AccountId,
             permission_id: T::EntityId,
-            name: Vec<u8>,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
-
-            // Verify that the name len is 64 max
-            ensure!(name.len() <= 64, Error::<T>::EntityNameExceedMax64);
+            Self::ensure_admin(&sender, &owner, Capability::ManagePermissions)?;
 
             dpatch_dposit_par!(
-                Self::update_existing_permission(&sender, permission_id, &name),
-                Event::PermissionUpdated(sender, permission_id, name)
+                Self::enable_existing_permission(&owner, permission_id),
+                Event::PermissionEnabled(owner, permission_id)
             )
         }
 
-        #[pallet::call_index(12)]
-        #[pallet::weight(T::WeightInfo::disable_permission())]
-        pub fn disable_permission(
+        /// enable disabled group call
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::enable_group())]
+        pub fn enable_group(
             origin: OriginFor<T>,
-            permission_id: T::EntityId,
+            owner: T::AccountId,
+            group_id: T::EntityId,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageUsers)?;
 
             dpatch_dposit_par!(
-                Self::disable_existing_permission(&sender, permission_id),
-                Event::PermissionDisabled(sender, permission_id)
+                Self::enable_existing_group(&owner, group_id),
+                Event::GroupEnabled(owner, group_id)
             )
         }
 
-        #[pallet::call_index(13)]
-        #[pallet::weight(T::WeightInfo::fetch_role_permissions())]
-        pub fn fetch_role_permissions(
+        /// assign a role to role call, the role inherits all permissions of the other role
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::WeightInfo::assign_role_to_role())]
+        pub fn assign_role_to_role(
             origin: OriginFor<T>,
             owner: T::AccountId,
             role_id: T::EntityId,
+            inherited_role_id: T::EntityId,
         ) -> DispatchResult {
-            ensure_signed(origin)?;
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageRoles)?;
 
-            dpatch_dposit!(
-                Self::get_role_permissions(&owner, role_id),
-                Event::FetchedRolePermissions
+            dpatch_dposit_par!(
+                Self::create_role_to_role(&owner, role_id, inherited_role_id),
+                Event::RoleAssignedToRole(owner, role_id, inherited_role_id)
             )
         }
 
-        /// assign a permission to role call
-        #[pallet::call_index(14)]
-        #[pallet::weight(T::WeightInfo::assign_permission_to_role())]
-        pub fn assign_permission_to_role(
+        /// unassign role to role relationship call
+        #[pallet::call_index(36)]
+        #[pallet::weight(T::WeightInfo::unassign_role_to_role())]
+        pub fn unassign_role_to_role(
             origin: OriginFor<T>,
-            permission_id: T::EntityId,
+            owner: T::AccountId,
             role_id: T::EntityId,
+            inherited_role_id: T::EntityId,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageRoles)?;
 
             dpatch_dposit_par!(
-                Self::create_permission_to_role(&sender, permission_id, role_id),
-                Event::PermissionAssigned(sender, permission_id, role_id)
+                Self::revoke_role_to_role(&owner, role_id, inherited_role_id),
+                Event::RoleUnassignedToRole(owner, role_id, inherited_role_id)
             )
         }
 
-        /// unassign permission to role relationship call
-        #[pallet::call_index(15)]
-        #[pallet::weight(T::WeightInfo::unassign_permission_to_role())]
-        pub fn unassign_permission_to_role(
+        /// assign a group to group call, the group becomes a member of the parent group
+        #[pallet::call_index(37)]
+        #[pallet::weight(T::WeightInfo::assign_group_to_group())]
+        pub fn assign_group_to_group(
             origin: OriginFor<T>,
-            permission_id: T::EntityId,
-            role_id: T::EntityId,
+            owner: T::AccountId,
+            group_id: T::EntityId,
+            parent_group_id: T::EntityId,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageUsers)?;
 
             dpatch_dposit_par!(
-                Self::revoke_permission_to_role(&sender, permission_id, role_id),
-                Event::PermissionUnassignedToRole(sender, permission_id, role_id)
+                Self::create_group_to_group(&owner, group_id, parent_group_id),
+                Event::GroupAssignedToGroup(owner, group_id, parent_group_id)
             )
         }
 
-        #[pallet::call_index(16)]
-        #[pallet::weight(T::WeightInfo::fetch_group())]
-        pub fn fetch_group(
+        /// unassign group to group relationship call
+        #[pallet::call_index(38)]
+        #[pallet::weight(T::WeightInfo::unassign_group_to_group())]
+        pub fn unassign_group_to_group(
             origin: OriginFor<T>,
             owner: T::AccountId,
             group_id: T::EntityId,
+            parent_group_id: T::EntityId,
         ) -> DispatchResult {
-            ensure_signed(origin)?;
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageUsers)?;
 
-            dpatch_dposit!(Self::get_group(&owner, group_id), Event::GroupFetched)
+            dpatch_dposit_par!(
+                Self::revoke_group_to_group(&owner, group_id, parent_group_id),
+                Event::GroupUnassignedToGroup(owner, group_id, parent_group_id)
+            )
         }
 
-        #[pallet::call_index(17)]
-        #[pallet::weight(T::WeightInfo::fetch_groups())]
-        pub fn fetch_groups(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResult {
-            ensure_signed(origin)?;
+        /// add separation of duty constraint call, users may hold at most one of the roles
+        #[pallet::call_index(39)]
+        #[pallet::weight(T::WeightInfo::add_role_constraint(roles.len() as u32))]
+        pub fn add_role_constraint(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            constraint_id: T::EntityId,
+            kind: ConstraintKind,
+            roles: Vec<T::EntityId>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageRoles)?;
 
-            dpatch_dposit!(Self::get_groups(&owner), Event::AllGroupsFetched)
+            dpatch_dposit_par!(
+                Self::create_role_constraint(&owner, constraint_id, kind, &roles),
+                Event::RoleConstraintAdded(owner, constraint_id)
+            )
         }
 
-        /// create group call
-        #[pallet::call_index(18)]
-        #[pallet::weight(T::WeightInfo::add_group())]
-        pub fn add_group(
+        /// remove separation of duty constraint call
+        #[pallet::call_index(40)]
+        #[pallet::weight(T::WeightInfo::remove_role_constraint())]
+        pub fn remove_role_constraint(
             origin: OriginFor<T>,
-            group_id: T::EntityId,
-            name: Vec<u8>,
+            owner: T::AccountId,
+            constraint_id: T::EntityId,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
-
-            // Verify that the name len is 64 max
-            ensure!(name.len() <= 64, Error::<T>::EntityNameExceedMax64);
+            Self::ensure_admin(&sender, &owner, Capability::ManageRoles)?;
 
             dpatch_dposit_par!(
-                Self::create_group(&sender, group_id, &name),
-                Event::GroupAdded(sender, group_id, name)
+                Self::delete_role_constraint(&owner, constraint_id),
+                Event::RoleConstraintRemoved(owner, constraint_id)
             )
         }
 
-        /// update group call
-        #[pallet::call_index(19)]
-        #[pallet::weight(T::WeightInfo::update_group())]
-        pub fn update_group(
+        /// add a delegate or update its capabilities call, only the owner can delegate
+        #[pallet::call_index(41)]
+        #[pallet::weight(T::WeightInfo::add_delegate())]
+        pub fn add_delegate(
             origin: OriginFor<T>,
-            group_id: T::EntityId,
-            name: Vec<u8>,
+            admin: T::AccountId,
+            capabilities: Capabilities,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
+            ensure!(
+                !Self::is_transferring(&sender),
+                Error::<T>::OwnershipTransferInProgress
+            );
 
-            // Verify that the name len is 64 max
-            ensure!(name.len() <= 64, Error::<T>::EntityNameExceedMax64);
+            <Admin2OwnerStore<T>>::insert(&sender, &admin, capabilities);
 
-            dpatch_dposit_par!(
-                Self::update_existing_group(&sender, group_id, &name),
-                Event::GroupUpdated(sender, group_id, name)
-            )
+            Self::deposit_event(Event::DelegateAdded(sender, admin, capabilities));
+            Ok(())
         }
 
-        /// disable group call
-        #[pallet::call_index(20)]
-        #[pallet::weight(T::WeightInfo::disable_group())]
-        pub fn disable_group(origin: OriginFor<T>, group_id: T::EntityId) -> DispatchResult {
+        /// remove a delegate call
+        #[pallet::call_index(42)]
+        #[pallet::weight(T::WeightInfo::remove_delegate())]
+        pub fn remove_delegate(origin: OriginFor<T>, admin: T::AccountId) -> DispatchResult {
             let sender = ensure_signed(origin)?;
+            ensure!(
+                !Self::is_transferring(&sender),
+                Error::<T>::OwnershipTransferInProgress
+            );
+
+            ensure!(
+                <Admin2OwnerStore<T>>::contains_key(&sender, &admin),
+                Error::<T>::AssignmentDoesNotExist
+            );
+            <Admin2OwnerStore<T>>::remove(&sender, &admin);
+
+            Self::deposit_event(Event::DelegateRemoved(sender, admin));
+            Ok(())
+        }
 
-            dpatch_dposit_par!(
-                Self::disable_existing_group(&sender, group_id),
-                Event::GroupDisabled(sender, group_id)
-            )
+        /// propose to transfer all roles, permissions, groups and assignments to a new owner
+        /// call, replaces any previous proposal
+        #[pallet::call_index(43)]
+        #[pallet::weight(T::WeightInfo::propose_ownership_transfer())]
+        pub fn propose_ownership_transfer(
+            origin: OriginFor<T>,
+            new_owner: T::AccountId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(sender != new_owner, Error::<T>::InvalidOwnershipTransfer);
+            ensure!(
+                !Self::is_transferring(&sender),
+                Error::<T>::OwnershipTransferInProgress
+            );
+
+            let transfer = OwnershipTransfer {
+                new_owner: new_owner.clone(),
+                stage: TransferStage::Proposed,
+            };
+            <OwnershipTransferStore<T>>::insert(&sender, transfer);
+
+            Self::deposit_event(Event::OwnershipTransferProposed(sender, new_owner));
+            Ok(())
         }
 
-        /// assign a role to group call
-        #[pallet::call_index(21)]
-        #[pallet::weight(T::WeightInfo::assign_role_to_group())]
-        pub fn assign_role_to_group(
+        /// accept an ownership transfer call, both domains are locked until the transfer
+        /// has been completed by `continue_ownership_transfer`
+        #[pallet::call_index(44)]
+        #[pallet::weight(T::WeightInfo::accept_ownership_transfer())]
+        pub fn accept_ownership_transfer(
             origin: OriginFor<T>,
-            role_id: T::EntityId,
-            group_id: T::EntityId,
+            owner: T::AccountId,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
 
-            dpatch_dposit_par!(
-                Self::create_role_to_group(&sender, role_id, group_id),
-                Event::RoleAssignedToGroup(sender, role_id, group_id)
-            )
+            let mut transfer = match <OwnershipTransferStore<T>>::get(&owner) {
+                Some(transfer)
+                    if transfer.new_owner == sender
+                        && transfer.stage == TransferStage::Proposed =>
+                {
+                    transfer
+                }
+                _ => return Err(Error::<T>::OwnershipTransferDoesNotExist.into()),
+            };
+            ensure!(
+                !Self::is_transferring(&owner) && !Self::is_transferring(&sender),
+                Error::<T>::OwnershipTransferInProgress
+            );
+            ensure!(
+                Self::is_domain_empty(&sender),
+                Error::<T>::InvalidOwnershipTransfer
+            );
+
+            transfer.stage = TransferStage::Roles;
+            <OwnershipTransferStore<T>>::insert(&owner, transfer);
+            <IncomingOwnershipStore<T>>::insert(&sender, &owner);
+
+            Self::deposit_event(Event::OwnershipTransferAccepted(owner, sender));
+            Ok(())
         }
 
-        /// unassign role to group relationship call
-        #[pallet::call_index(22)]
-        #[pallet::weight(T::WeightInfo::unassign_role_to_group())]
-        pub fn unassign_role_to_group(
+        /// move up to the given number of entities and assignments of an accepted ownership
+        /// transfer call, each of them being moved on its own
+        #[pallet::call_index(45)]
+        #[pallet::weight(T::WeightInfo::continue_ownership_transfer(*entries))]
+        pub fn continue_ownership_transfer(
             origin: OriginFor<T>,
-            role_id: T::EntityId,
-            group_id: T::EntityId,
+            owner: T::AccountId,
+            entries: u32,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let mut transfer = match <OwnershipTransferStore<T>>::get(&owner) {
+                Some(transfer) if transfer.stage != TransferStage::Proposed => transfer,
+                _ => return Err(Error::<T>::OwnershipTransferDoesNotExist.into()),
+            };
+
+            for _ in 0..entries {
+                if !Self::transfer_ownership_step(&owner, &mut transfer) {
+                    <OwnershipTransferStore<T>>::remove(&owner);
+                    <IncomingOwnershipStore<T>>::remove(&transfer.new_owner);
+
+                    Self::deposit_event(Event::OwnershipTransferCompleted(
+                        owner,
+                        transfer.new_owner,
+                    ));
+                    return Ok(());
+                }
+            }
+
+            <OwnershipTransferStore<T>>::insert(&owner, transfer);
+            Ok(())
+        }
+
+        /// apply a batch of create, update, disable, assign and unassign operations call,
+        /// which either applies all operations or none of them
+        #[pallet::call_index(46)]
+        #[pallet::weight(Pallet::<T>::batch_weight(ops))]
+        pub fn batch_apply(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            ops: BoundedVec<RbacOp<T::EntityId, BlockNumberFor<T>>, T::MaxOps>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(
+                !Self::is_transferring(&owner),
+                Error::<T>::OwnershipTransferInProgress
+            );
+
+            let count = ops.len() as u32;
+            with_storage_layer(|| -> DispatchResult {
+                for (index, op) in ops.into_iter().enumerate() {
+                    if let Err(err) = Self::apply_op(&sender, &owner, op) {
+                        return Err(Error::<T>::BatchOpFailed {
+                            index: index as u16,
+                            error: err.typ,
+                        }
+                        .into());
+                    }
+                }
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::BatchApplied(owner, count));
+            Ok(())
+        }
+
+        /// create user call
+        #[pallet::call_index(47)]
+        #[pallet::weight(T::WeightInfo::add_user())]
+        pub fn add_user(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            user_id: T::EntityId,
+            name: Vec<u8>,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageUsers)?;
+
+            // Verify that the name len is 64 max
+            ensure!(name.len() <= 64, Error::<T>::EntityNameExceedMax64);
 
             dpatch_dposit_par!(
-                Self::revoke_role_to_group(&sender, role_id, group_id),
-                Event::RoleUnassignedToGroup(sender, role_id, group_id)
+                Self::create_user(&owner, user_id, &name),
+                Event::UserAdded(owner, user_id, name)
             )
         }
 
-        #[pallet::call_index(23)]
-        #[pallet::weight(T::WeightInfo::fetch_group_roles())]
-        pub fn fetch_group_roles(
+        /// update user call
+        #[pallet::call_index(48)]
+        #[pallet::weight(T::WeightInfo::update_user())]
+        pub fn update_user(
             origin: OriginFor<T>,
             owner: T::AccountId,
-            group_id: T::EntityId,
+            user_id: T::EntityId,
+            name: Vec<u8>,
         ) -> DispatchResult {
-            ensure_signed(origin)?;
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageUsers)?;
 
-            dpatch_dposit!(
-                Self::get_group_roles(&owner, group_id),
-                Event::FetchedGroupRoles
+            // Verify that the name len is 64 max
+            ensure!(name.len() <= 64, Error::<T>::EntityNameExceedMax64);
+
+            dpatch_dposit_par!(
+                Self::update_existing_user(&owner, user_id, &name),
+                Event::UserUpdated(owner, user_id, name)
             )
         }
 
-        /// assign a user to group call
-        #[pallet::call_index(24)]
-        #[pallet::weight(T::WeightInfo::assign_user_to_group())]
-        pub fn assign_user_to_group(
+        /// disable user call, which revokes all permissions of the user
+        #[pallet::call_index(49)]
+        #[pallet::weight(T::WeightInfo::disable_user())]
+        pub fn disable_user(
             origin: OriginFor<T>,
+            owner: T::AccountId,
             user_id: T::EntityId,
-            group_id: T::EntityId,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageUsers)?;
 
             dpatch_dposit_par!(
-                Self::create_user_to_group(&sender, user_id, group_id),
-                Event::UserAssignedToGroup(sender, user_id, group_id)
+                Self::disable_existing_user(&owner, user_id),
+                Event::UserDisabled(owner, user_id)
             )
         }
 
-        /// unassign a user to group call
-        #[pallet::call_index(25)]
-        #[pallet::weight(T::WeightInfo::unassign_user_to_group())]
-        pub fn unassign_user_to_group(
+        /// claim a user of the owner's domain call, the signing account gets bound to the
+        /// user once the owner approves the claim
+        #[pallet::call_index(50)]
+        #[pallet::weight(T::WeightInfo::claim_user())]
+        pub fn claim_user(
             origin: OriginFor<T>,
+            owner: T::AccountId,
             user_id: T::EntityId,
-            group_id: T::EntityId,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
+            ensure!(
+                !Self::is_transferring(&owner),
+                Error::<T>::OwnershipTransferInProgress
+            );
 
             dpatch_dposit_par!(
-                Self::revoke_user_to_group(&sender, user_id, group_id),
-                Event::UserUnAssignedToGroup(sender, user_id, group_id)
+                Self::create_user_claim(&owner, user_id, &sender),
+                Event::UserClaimed(owner, user_id, sender)
             )
         }
 
-        #[pallet::call_index(26)]
-        #[pallet::weight(T::WeightInfo::fetch_user_groups())]
-        pub fn fetch_user_groups(
+        /// approve the claim of an account and bind it to the user call
+        #[pallet::call_index(51)]
+        #[pallet::weight(T::WeightInfo::approve_user_claim())]
+        pub fn approve_user_claim(
             origin: OriginFor<T>,
             owner: T::AccountId,
             user_id: T::EntityId,
+            account: T::AccountId,
         ) -> DispatchResult {
-            ensure_signed(origin)?;
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageUsers)?;
 
-            dpatch_dposit!(
-                Self::get_user_groups(&owner, user_id),
-                Event::FetchedUserGroups
+            dpatch_dposit_par!(
+                Self::create_user_binding(&owner, user_id, &account),
+                Event::UserBound(owner, user_id, account)
             )
         }
 
-        #[pallet::call_index(27)]
-        #[pallet::weight(T::WeightInfo::fetch_user_permissions())]
-        pub fn fetch_user_permissions(
+        /// unbind a user from its account call, either by the owner or the bound account
+        #[pallet::call_index(52)]
+        #[pallet::weight(T::WeightInfo::unbind_user())]
+        pub fn unbind_user(
             origin: OriginFor<T>,
             owner: T::AccountId,
             user_id: T::EntityId,
         ) -> DispatchResult {
-            ensure_signed(origin)?;
+            let sender = ensure_signed(origin)?;
+            ensure!(
+                !Self::is_transferring(&owner),
+                Error::<T>::OwnershipTransferInProgress
+            );
+
+            let account =
+                Self::account_of_user(&owner, user_id).ok_or(Error::<T>::AssignmentDoesNotExist)?;
+            ensure!(
+                sender == account || Self::is_admin(&sender, &owner, Capability::ManageUsers),
+                Error::<T>::EntityAuthorizationFailed
+            );
 
-            dpatch_dposit!(
-                Self::get_user_permissions(&owner, user_id),
-                Event::FetchedUserPermissions
+            dpatch_dposit_par!(
+                Self::revoke_user_binding(&owner, user_id),
+                Event::UserUnbound(owner, user_id, account)
             )
         }
 
-        #[pallet::call_index(28)]
-        #[pallet::weight(T::WeightInfo::fetch_group_permissions())]
-        pub fn fetch_group_permissions(
+        /// import the entities, constraints and assignments of a domain export call, which
+        /// either imports all of them or none, see `ImportMode` for existing ones
+        #[pallet::call_index(53)]
+        #[pallet::weight(Pallet::<T>::import_weight(domain))]
+        pub fn import_domain(
             origin: OriginFor<T>,
             owner: T::AccountId,
-            group_id: T::EntityId,
+            domain: RbacDomain<T::EntityId, BlockNumberFor<T>>,
+            mode: ImportMode,
         ) -> DispatchResult {
-            ensure_signed(origin)?;
+            let sender = ensure_signed(origin)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageRoles)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManagePermissions)?;
+            Self::ensure_admin(&sender, &owner, Capability::ManageUsers)?;
 
-            dpatch_dposit!(
-                Self::get_group_permissions(&owner, group_id),
-                Event::FetchedGroupPermissions
-            )
+            let count = domain.item_count();
+            with_storage_layer(|| -> DispatchResult {
+                Self::import_into(&owner, domain, mode).or_else(Error::<T>::dispatch_error)
+            })?;
+
+            Self::deposit_event(Event::DomainImported(owner, count));
+            Ok(())
         }
     }
 
     // implement the Rbac trait to satify the methods
-    impl<T: Config> Rbac<T::AccountId, T::EntityId> for Pallet<T> {
+    impl<T: Config> Rbac<T::AccountId, T::EntityId, BlockNumberFor<T>> for Pallet<T> {
         fn get_entity(
             owner: &T::AccountId,
             entity_id: &T::EntityId,
@@ -757,29 +3810,63 @@ pub mod pallet {
         fn get_user_roles(
             owner: &T::AccountId,
             user_id: T::EntityId,
-        ) -> Result<Vec<Role2User<T::EntityId>>, RbacError> {
+        ) -> Result<Vec<Role2User<T::EntityId, BlockNumberFor<T>>>, RbacError> {
             // Generate key for integrity check
             let key = Self::generate_key(owner, &user_id, Tag::Role2User);
 
             if <Role2UserStore<T>>::contains_key(key) {
-                Ok(Self::role_to_user_of(key).into())
+                Ok(Self::role_to_user_of(key)
+                    .into_iter()
+                    .filter(|r2u| Self::is_active(&r2u.expires_at))
+                    .collect())
             } else {
                 RbacError::err(AssignmentDoesNotExist, &user_id)
             }
         }
 
+        fn get_user_roles_page(
+            owner: &T::AccountId,
+            user_id: T::EntityId,
+            start_after: Option<T::EntityId>,
+            limit: u32,
+        ) -> Result<Vec<Role2User<T::EntityId, BlockNumberFor<T>>>, RbacError> {
+            let user_roles = Self::get_user_roles(owner, user_id)?;
+            Ok(Self::page(user_roles, |r2u| r2u.role, start_after, limit))
+        }
+
         fn get_user_groups(
             owner: &T::AccountId,
             user_id: T::EntityId,
-        ) -> Result<Vec<User2Group<T::EntityId>>, RbacError> {
+        ) -> Result<Vec<User2Group<T::EntityId, BlockNumberFor<T>>>, RbacError> {
             // Generate key for integrity check
             let key = Self::generate_key(owner, &user_id, Tag::User2Group);
 
-            if <User2GroupStore<T>>::contains_key(key) {
-                Ok(Self::user_to_group_of(key).into())
-            } else {
-                RbacError::err(AssignmentDoesNotExist, &user_id)
+            if !<User2GroupStore<T>>::contains_key(key) {
+                return RbacError::err(AssignmentDoesNotExist, &user_id);
+            }
+
+            let mut groups: Vec<User2Group<T::EntityId, BlockNumberFor<T>>> = vec![];
+            // use to avoid duplicate transversal
+            let mut processed_groups: BTreeSet<T::EntityId> = BTreeSet::new();
+
+            // Includes all groups, which contain the user's groups directly or indirectly.
+            // These share the expiry of the membership they have been reached through first.
+            // Disabled groups are handled as in permission resolution.
+            for u2g in Self::user_to_group_of(key).iter() {
+                if !Self::is_active(&u2g.expires_at) {
+                    continue;
+                }
+
+                for group in Self::expand_group(owner, u2g.group, &mut processed_groups)? {
+                    groups.push(User2Group {
+                        user: user_id,
+                        group,
+                        expires_at: u2g.expires_at,
+                    });
+                }
             }
+
+            Ok(groups)
         }
 
         fn get_group_roles(
@@ -796,18 +3883,121 @@ pub mod pallet {
             }
         }
 
-        fn get_role_permissions(
+        fn get_group_roles_page(
+            owner: &T::AccountId,
+            group_id: T::EntityId,
+            start_after: Option<T::EntityId>,
+            limit: u32,
+        ) -> Result<Vec<Role2Group<T::EntityId>>, RbacError> {
+            let group_roles = Self::get_group_roles(owner, group_id)?;
+            Ok(Self::page(group_roles, |r2g| r2g.role, start_after, limit))
+        }
+
+        fn get_role_permissions(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+        ) -> Result<Vec<Permission2Role<T::EntityId>>, RbacError> {
+            // Generate key for integrity check
+            let key = Self::generate_key(owner, &role_id, Tag::Permission2Role);
+
+            if <Permission2RoleStore<T>>::contains_key(key) {
+                Ok(Self::permission_to_role_of(key).into())
+            } else {
+                RbacError::err(AssignmentDoesNotExist, &role_id)
+            }
+        }
+
+        fn get_role_users(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+        ) -> Result<Vec<Role2User<T::EntityId, BlockNumberFor<T>>>, RbacError> {
+            let key = Self::generate_key(owner, &role_id, Tag::RoleUsers);
+
+            let mut users: Vec<Role2User<T::EntityId, BlockNumberFor<T>>> =
+                <RoleUsersStore<T>>::iter_prefix(key)
+                    .map(|(user, expires_at)| Role2User {
+                        role: role_id,
+                        user,
+                        expires_at,
+                    })
+                    .collect();
+
+            if users.is_empty() {
+                return RbacError::err(AssignmentDoesNotExist, &role_id);
+            }
+
+            users.retain(|r2u| Self::is_active(&r2u.expires_at));
+            // The index is ordered by hashes, so it gets sorted like the other assignments
+            users.sort();
+            Ok(users)
+        }
+
+        fn get_group_members(
+            owner: &T::AccountId,
+            group_id: T::EntityId,
+        ) -> Result<Vec<User2Group<T::EntityId, BlockNumberFor<T>>>, RbacError> {
+            let key = Self::generate_key(owner, &group_id, Tag::GroupUsers);
+
+            let mut users: Vec<User2Group<T::EntityId, BlockNumberFor<T>>> =
+                <GroupUsersStore<T>>::iter_prefix(key)
+                    .map(|(user, expires_at)| User2Group {
+                        user,
+                        group: group_id,
+                        expires_at,
+                    })
+                    .collect();
+
+            if users.is_empty() {
+                return RbacError::err(AssignmentDoesNotExist, &group_id);
+            }
+
+            users.retain(|u2g| Self::is_active(&u2g.expires_at));
+            users.sort();
+            Ok(users)
+        }
+
+        fn get_role_groups(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+        ) -> Result<Vec<Role2Group<T::EntityId>>, RbacError> {
+            let key = Self::generate_key(owner, &role_id, Tag::RoleGroups);
+
+            let mut groups: Vec<Role2Group<T::EntityId>> =
+                <RoleGroupsStore<T>>::iter_key_prefix(key)
+                    .map(|group| Role2Group {
+                        role: role_id,
+                        group,
+                    })
+                    .collect();
+
+            if groups.is_empty() {
+                return RbacError::err(AssignmentDoesNotExist, &role_id);
+            }
+
+            groups.sort();
+            Ok(groups)
+        }
+
+        fn get_permission_roles(
             owner: &T::AccountId,
-            role_id: T::EntityId,
+            permission_id: T::EntityId,
         ) -> Result<Vec<Permission2Role<T::EntityId>>, RbacError> {
-            // Generate key for integrity check
-            let key = Self::generate_key(owner, &role_id, Tag::Permission2Role);
-
-            if <Permission2RoleStore<T>>::contains_key(key) {
-                Ok(Self::permission_to_role_of(key).into())
-            } else {
-                RbacError::err(AssignmentDoesNotExist, &role_id)
+            let key = Self::generate_key(owner, &permission_id, Tag::PermissionRoles);
+
+            let mut roles: Vec<Permission2Role<T::EntityId>> =
+                <PermissionRolesStore<T>>::iter_key_prefix(key)
+                    .map(|role| Permission2Role {
+                        permission: permission_id,
+                        role,
+                    })
+                    .collect();
+
+            if roles.is_empty() {
+                return RbacError::err(AssignmentDoesNotExist, &permission_id);
             }
+
+            roles.sort();
+            Ok(roles)
         }
 
         fn get_user_permissions(
@@ -818,24 +4008,26 @@ pub mod pallet {
             let role_2_user_key = Self::generate_key(owner, &user_id, Tag::Role2User);
             let user_2_group_key = Self::generate_key(owner, &user_id, Tag::User2Group);
 
-            let mut permissions: Vec<Entity<T::EntityId>> = vec![];
-            // use to avoid duplicate transverval
-            let mut processed_roles: Vec<T::EntityId> = vec![];
+            // Disabled users are left without any permissions
+            if !Self::resolve_user(owner, &user_id)? {
+                return Ok(vec![]);
+            }
+
+            // Check dynamic separation of duty for the user's roles
+            Self::check_dynamic_constraints(owner, user_id)?;
+
+            let mut permissions: BTreeMap<T::EntityId, Entity<T::EntityId>> = BTreeMap::new();
+            // use to avoid duplicate transversal
+            let mut processed_roles: BTreeSet<T::EntityId> = BTreeSet::new();
 
             if <Role2UserStore<T>>::contains_key(role_2_user_key) {
                 let val = <Role2UserStore<T>>::get(role_2_user_key);
 
                 let itr = val.iter();
 
-                for r2u in itr {
-                    // use to avoid duplicate transversal
-                    processed_roles.push(r2u.role);
-
-                    let p2r_option = Self::get_role_permissions(owner, r2u.role)?;
-
-                    for p2r in p2r_option.iter() {
-                        let perm_option = Self::get_permission(owner, p2r.permission)?;
-                        permissions.push(perm_option);
+                for r2u in itr.filter(|r2u| Self::is_active(&r2u.expires_at)) {
+                    for role in Self::expand_role(owner, r2u.role, &mut processed_roles)? {
+                        Self::collect_role_permissions(owner, role, &mut permissions)?;
                     }
                 }
             }
@@ -844,31 +4036,24 @@ pub mod pallet {
                 let val = <User2GroupStore<T>>::get(user_2_group_key);
 
                 let itr = val.iter();
+                // use to avoid duplicate transversal
+                let mut processed_groups: BTreeSet<T::EntityId> = BTreeSet::new();
 
-                for u2g in itr {
-                    let key = Self::generate_key(owner, &u2g.group, Tag::Role2Group);
-
-                    if <Role2GroupStore<T>>::contains_key(key) {
-                        let val = <Role2GroupStore<T>>::get(key);
-
-                        let r2g_itr = val.iter();
-
-                        for r2g in r2g_itr {
-                            // use to avoid duplicate transversal
-                            if !processed_roles.contains(&r2g.role) {
-                                let p2r_option = Self::get_role_permissions(owner, r2g.role)?;
+                for u2g in itr.filter(|u2g| Self::is_active(&u2g.expires_at)) {
+                    for group in Self::expand_group(owner, u2g.group, &mut processed_groups)? {
+                        let key = Self::generate_key(owner, &group, Tag::Role2Group);
 
-                                for p2r in p2r_option.iter() {
-                                    let perm_option = Self::get_permission(owner, p2r.permission)?;
-                                    permissions.push(perm_option);
-                                }
+                        for r2g in <Role2GroupStore<T>>::get(key).iter() {
+                            for role in Self::expand_role(owner, r2g.role, &mut processed_roles)? {
+                                Self::collect_role_permissions(owner, role, &mut permissions)?;
                             }
                         }
                     }
                 }
             }
 
-            Ok(permissions)
+            // Sorted by id and free of duplicates
+            Ok(permissions.into_values().collect())
         }
 
         fn get_group_permissions(
@@ -877,7 +4062,9 @@ pub mod pallet {
         ) -> Result<Vec<Entity<T::EntityId>>, RbacError> {
             // Generate key for integrity check
 
-            let mut permissions: Vec<Entity<T::EntityId>> = vec![];
+            let mut permissions: BTreeMap<T::EntityId, Entity<T::EntityId>> = BTreeMap::new();
+            // use to avoid duplicate transversal
+            let mut processed_roles: BTreeSet<T::EntityId> = BTreeSet::new();
 
             let key = Self::generate_key(owner, &group_id, Tag::Role2Group);
 
@@ -887,169 +4074,402 @@ pub mod pallet {
                 let r2g_itr = val.iter();
 
                 for r2g in r2g_itr {
-                    let p2r_option = Self::get_role_permissions(owner, r2g.role)?;
+                    for role in Self::expand_role(owner, r2g.role, &mut processed_roles)? {
+                        Self::collect_role_permissions(owner, role, &mut permissions)?;
+                    }
+                }
+            }
+
+            // Roles of all groups, which contain the group directly or indirectly
+            let mut processed_groups: BTreeSet<T::EntityId> = BTreeSet::new();
+            processed_groups.insert(group_id);
 
-                    for p2r in p2r_option.iter() {
-                        let perm_option = Self::get_permission(owner, p2r.permission)?;
-                        permissions.push(perm_option);
+            for parent in Self::parent_groups(owner, &group_id) {
+                for group in Self::expand_group(owner, parent, &mut processed_groups)? {
+                    let key = Self::generate_key(owner, &group, Tag::Role2Group);
+
+                    for r2g in <Role2GroupStore<T>>::get(key).iter() {
+                        for role in Self::expand_role(owner, r2g.role, &mut processed_roles)? {
+                            Self::collect_role_permissions(owner, role, &mut permissions)?;
+                        }
                     }
                 }
             }
 
-            Ok(permissions)
+            // Sorted by id and free of duplicates
+            Ok(permissions.into_values().collect())
         }
 
-        fn create_role_to_user(
+        fn has_permission(
             owner: &T::AccountId,
-            role_id: T::EntityId,
             user_id: T::EntityId,
-        ) -> Result<(), RbacError> {
-            // Generate key for integrity check
-            let role_key = Self::generate_key(owner, &role_id, Tag::Role);
-            let role_2_user_key = Self::generate_key(owner, &user_id, Tag::Role2User);
+            permission_id: T::EntityId,
+        ) -> bool {
+            Self::check_permission(owner, user_id, permission_id).unwrap_or(false)
+        }
 
-            // Check if role exists
-            if !<KeysLookUpStore<T>>::contains_key(role_key) {
-                return RbacError::err(EntityDoesNotExist, &role_id);
+        fn check_permission(
+            owner: &T::AccountId,
+            user_id: T::EntityId,
+            permission_id: T::EntityId,
+        ) -> Result<bool, RbacError> {
+            // Unlike has_permission, unknown or disabled permissions, rejected disabled
+            // entities and violated constraints are reported as errors
+            Self::find_permission_grant(owner, user_id, permission_id).map(|grant| grant.is_some())
+        }
+
+        fn check_permissions(
+            owner: &T::AccountId,
+            checks: &[(T::EntityId, T::EntityId)],
+        ) -> Vec<Result<bool, RbacError>> {
+            checks
+                .iter()
+                .map(|(user_id, permission_id)| {
+                    Self::check_permission(owner, *user_id, *permission_id)
+                })
+                .collect()
+        }
+
+        fn get_permission_grant(
+            owner: &T::AccountId,
+            user_id: T::EntityId,
+            permission_id: T::EntityId,
+        ) -> Option<PermissionGrant<T::EntityId>> {
+            // Direct roles are searched first, so this is the shortest path via a direct role
+            // if there is one, like the first grant explained by explain_user_permission
+            Self::find_permission_grant(owner, user_id, permission_id)
+                .ok()
+                .flatten()
+        }
+
+        fn explain_user_permission(
+            owner: &T::AccountId,
+            user_id: T::EntityId,
+            permission_id: T::EntityId,
+        ) -> Result<Vec<PermissionGrant<T::EntityId>>, RbacError> {
+            // Disabled or non-existing entities never grant a permission
+            Self::get_permission(owner, permission_id)?;
+            if !Self::resolve_user(owner, &user_id)? {
+                return Ok(vec![]);
             }
+            Self::check_dynamic_constraints(owner, user_id)?;
 
-            let mut roles: BoundedVec<Role2User<T::EntityId>, T::BoundedDataLen> =
-                BoundedVec::new();
+            let mut grants: Vec<PermissionGrant<T::EntityId>> = vec![];
 
-            let new_assign = Role2User {
-                role: role_id,
-                user: user_id,
-            };
+            let role_2_user_key = Self::generate_key(owner, &user_id, Tag::Role2User);
 
-            // Check if role has already been assigned to user
-            if <Role2UserStore<T>>::contains_key(role_2_user_key) {
-                roles = <Role2UserStore<T>>::get(role_2_user_key);
+            let role_2_user = <Role2UserStore<T>>::get(role_2_user_key);
+
+            for r2u in role_2_user
+                .iter()
+                .filter(|r2u| Self::is_active(&r2u.expires_at))
+            {
+                for roles in Self::find_permission_paths(owner, r2u.role, permission_id)? {
+                    grants.push(PermissionGrant {
+                        groups: vec![],
+                        roles,
+                    });
+                }
+            }
 
-                if roles.contains(&new_assign) {
-                    return RbacError::err(AssignmentAlreadyExist, &user_id);
+            let user_2_group_key = Self::generate_key(owner, &user_id, Tag::User2Group);
+
+            let user_2_group = <User2GroupStore<T>>::get(user_2_group_key);
+
+            for u2g in user_2_group
+                .iter()
+                .filter(|u2g| Self::is_active(&u2g.expires_at))
+            {
+                let group_paths = Self::hierarchy_paths(
+                    owner,
+                    u2g.group,
+                    Tag::Group,
+                    T::MaxGroupDepth::get(),
+                    Self::parent_groups,
+                )?;
+
+                for groups in group_paths {
+                    let group = match groups.last() {
+                        Some(group) => *group,
+                        None => continue,
+                    };
+                    let key = Self::generate_key(owner, &group, Tag::Role2Group);
+
+                    for r2g in <Role2GroupStore<T>>::get(key).iter() {
+                        for roles in Self::find_permission_paths(owner, r2g.role, permission_id)? {
+                            grants.push(PermissionGrant {
+                                groups: groups.clone(),
+                                roles,
+                            });
+                        }
+                    }
                 }
             }
 
-            let idx = roles.partition_point(|x| x < &new_assign);
+            Ok(grants)
+        }
 
-            match roles.try_insert(idx, new_assign.clone()) {
-                Err(e) => return RbacError::err(StorageExceedsMaxBounds, &e),
-                Ok(()) => <Role2UserStore<T>>::insert(role_2_user_key, roles),
+        fn get_role_roles(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+        ) -> Result<Vec<Role2Role<T::EntityId>>, RbacError> {
+            // Generate key for integrity check
+            let key = Self::generate_key(owner, &role_id, Tag::Role2Role);
+
+            if <Role2RoleStore<T>>::contains_key(key) {
+                Ok(Self::role_to_role_of(key).into())
+            } else {
+                RbacError::err(AssignmentDoesNotExist, &role_id)
             }
+        }
 
-            Ok(())
+        fn create_role_to_role(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+            inherited_role_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            // Check if both roles exist and are enabled
+            Self::check_entity_get_key(owner, &role_id, Tag::Role)?;
+            Self::check_entity_get_key(owner, &inherited_role_id, Tag::Role)?;
+
+            Self::insert_role_to_role(owner, role_id, inherited_role_id)
         }
 
-        fn revoke_role_to_user(
+        fn revoke_role_to_role(
             owner: &T::AccountId,
             role_id: T::EntityId,
-            user_id: T::EntityId,
+            inherited_role_id: T::EntityId,
         ) -> Result<(), RbacError> {
             // Generate key for integrity check
-            let role_2_user_key = Self::generate_key(owner, &user_id, Tag::Role2User);
+            let role_2_role_key = Self::generate_key(owner, &role_id, Tag::Role2Role);
 
-            // Check if role exists
-            if !<Role2UserStore<T>>::contains_key(role_2_user_key) {
-                return RbacError::err(AssignmentDoesNotExist, &user_id);
+            // Check if role inherits any roles
+            if !<Role2RoleStore<T>>::contains_key(role_2_role_key) {
+                return RbacError::err(AssignmentDoesNotExist, &role_id);
             }
 
-            let new_assign = Role2User {
+            let new_assign = Role2Role {
                 role: role_id,
-                user: user_id,
+                inherited_role: inherited_role_id,
             };
 
-            let mut val = <Role2UserStore<T>>::get(role_2_user_key);
-
-            if !val.contains(&new_assign) {
-                return RbacError::err(AssignmentDoesNotExist, &user_id);
-            }
+            let mut val = <Role2RoleStore<T>>::get(role_2_role_key);
 
             match val.binary_search(&new_assign) {
                 Ok(i) => val.remove(i),
-                Err(_) => return RbacError::err(AssignmentDoesNotExist, &user_id),
+                Err(_) => return RbacError::err(AssignmentDoesNotExist, &role_id),
             };
 
             if val.is_empty() {
-                <Role2UserStore<T>>::remove(role_2_user_key);
+                <Role2RoleStore<T>>::remove(role_2_role_key);
+            } else {
+                <Role2RoleStore<T>>::mutate(role_2_role_key, |a| *a = val);
             }
 
-            if !val.is_empty() {
-                <Role2UserStore<T>>::mutate(role_2_user_key, |a| *a = val);
+            Ok(())
+        }
+
+        fn get_user_effective_roles(
+            owner: &T::AccountId,
+            user_id: T::EntityId,
+        ) -> Vec<T::EntityId> {
+            let role_2_user_key = Self::generate_key(owner, &user_id, Tag::Role2User);
+            let user_2_group_key = Self::generate_key(owner, &user_id, Tag::User2Group);
+
+            let mut roles: Vec<T::EntityId> = <Role2UserStore<T>>::get(role_2_user_key)
+                .iter()
+                .filter(|r2u| Self::is_active(&r2u.expires_at))
+                .map(|r2u| r2u.role)
+                .collect();
+
+            let groups: Vec<T::EntityId> = <User2GroupStore<T>>::get(user_2_group_key)
+                .iter()
+                .filter(|u2g| Self::is_active(&u2g.expires_at))
+                .map(|u2g| u2g.group)
+                .collect();
+
+            for role in Self::groups_roles(owner, &Self::group_closure(owner, groups)) {
+                if !roles.contains(&role) {
+                    roles.push(role);
+                }
             }
 
-            Ok(())
+            // Roles inherited by any of them are held as well
+            Self::role_closure(owner, roles)
         }
 
-        fn create_role_to_group(
+        fn get_role_constraints(
             owner: &T::AccountId,
-            role_id: T::EntityId,
-            group_id: T::EntityId,
+        ) -> Result<Vec<RoleConstraint<T::EntityId>>, RbacError> {
+            Ok(Self::role_constraint_of(owner).into())
+        }
+
+        fn export_domain(
+            owner: &T::AccountId,
+        ) -> Result<RbacDomain<T::EntityId, BlockNumberFor<T>>, RbacError> {
+            let mut domain = RbacDomain {
+                roles: Self::role_of(owner).into(),
+                permissions: Self::permission_of(owner).into(),
+                groups: Self::group_of(owner).into(),
+                users: Self::user_of(owner).into(),
+                constraints: Self::role_constraint_of(owner).into(),
+                ..Default::default()
+            };
+
+            // Entities without assignments are no error here
+            for role in domain.roles.iter() {
+                let role_users = Self::get_role_users(owner, role.id).unwrap_or_default();
+                let role_permissions =
+                    Self::get_role_permissions(owner, role.id).unwrap_or_default();
+                let role_roles = Self::get_role_roles(owner, role.id).unwrap_or_default();
+
+                domain.role_users.extend(role_users);
+                domain.role_permissions.extend(role_permissions);
+                domain.role_roles.extend(role_roles);
+            }
+            for group in domain.groups.iter() {
+                let group_users = Self::get_group_members(owner, group.id).unwrap_or_default();
+                let group_roles = Self::get_group_roles(owner, group.id).unwrap_or_default();
+                let group_groups = Self::get_group_groups(owner, group.id).unwrap_or_default();
+
+                domain.group_users.extend(group_users);
+                domain.group_roles.extend(group_roles);
+                domain.group_groups.extend(group_groups);
+            }
+
+            domain.roles.sort();
+            domain.permissions.sort();
+            domain.groups.sort();
+            domain.users.sort();
+            domain.role_users.sort();
+            domain.group_users.sort();
+            domain.group_roles.sort();
+            domain.role_permissions.sort();
+            domain.role_roles.sort();
+            domain.group_groups.sort();
+            domain.constraints.sort();
+
+            Ok(domain)
+        }
+
+        fn create_role_constraint(
+            owner: &T::AccountId,
+            constraint_id: T::EntityId,
+            kind: ConstraintKind,
+            roles: &[T::EntityId],
         ) -> Result<(), RbacError> {
-            // Generate key for integrity check
-            let group_key = Self::generate_key(owner, &group_id, Tag::Group);
-            let role_key = Self::generate_key(owner, &role_id, Tag::Role);
-            let role_2_group_key = Self::generate_key(owner, &group_id, Tag::Role2Group);
+            let mut constraints = <RoleConstraintStore<T>>::get(owner);
 
-            // Check if role exists
-            if !<KeysLookUpStore<T>>::contains_key(role_key) {
-                return RbacError::err(EntityDoesNotExist, &role_id);
+            // Check if constraint already exists
+            if constraints.iter().any(|c| c.id == constraint_id) {
+                return RbacError::err(EntityAlreadyExist, &constraint_id);
             }
 
-            // Check if group exists
-            if !<KeysLookUpStore<T>>::contains_key(group_key) {
-                return RbacError::err(EntityDoesNotExist, &group_id);
+            // Roles of a constraint are bounded like the roles of an owner
+            if roles.len() > T::BoundedDataLen::get() as usize {
+                return RbacError::err(StorageExceedsMaxBounds, &(roles.len() as u32));
             }
 
-            let mut roles: BoundedVec<Role2Group<T::EntityId>, T::BoundedDataLen> =
-                BoundedVec::new();
+            let mut constraint_roles: Vec<T::EntityId> = vec![];
+            // use to avoid duplicate roles
+            let mut processed_roles: BTreeSet<T::EntityId> = BTreeSet::new();
+            for role in roles.iter() {
+                // Check if role exists, disabled roles may be enabled again later
+                Self::check_entity_exists_get_key(owner, role, Tag::Role)?;
 
-            let new_assign = Role2Group {
-                role: role_id,
-                group: group_id,
+                if processed_roles.insert(*role) {
+                    constraint_roles.push(*role);
+                }
+            }
+
+            if constraint_roles.len() < 2 {
+                return RbacError::err(InvalidRoleConstraint, &constraint_id);
+            }
+
+            let new_constraint = RoleConstraint {
+                id: constraint_id,
+                kind,
+                roles: constraint_roles,
             };
 
-            // Check if role has already been assigned to group
-            if <Role2GroupStore<T>>::contains_key(role_2_group_key) {
-                roles = <Role2GroupStore<T>>::get(role_2_group_key);
+            // Static constraints have to hold for all current role holders already
+            if kind == ConstraintKind::Static {
+                Self::check_constraint_holders(owner, &new_constraint)?;
+            }
 
-                if roles.contains(&new_assign) {
-                    return RbacError::err(AssignmentAlreadyExist, &group_id);
-                }
+            match constraints.try_push(new_constraint) {
+                Err(e) => return RbacError::err(StorageExceedsMaxBounds, &e),
+                Ok(()) => <RoleConstraintStore<T>>::insert(owner, constraints),
             }
 
-            let idx = roles.partition_point(|x| x < &new_assign);
+            Ok(())
+        }
 
-            match roles.try_insert(idx, new_assign.clone()) {
-                Err(e) => return RbacError::err(StorageExceedsMaxBounds, &e),
-                Ok(()) => <Role2GroupStore<T>>::insert(role_2_group_key, roles),
+        fn delete_role_constraint(
+            owner: &T::AccountId,
+            constraint_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            let mut constraints = <RoleConstraintStore<T>>::get(owner);
+
+            match constraints.iter().position(|c| c.id == constraint_id) {
+                Some(i) => constraints.remove(i),
+                None => return RbacError::err(EntityDoesNotExist, &constraint_id),
+            };
+
+            if constraints.is_empty() {
+                <RoleConstraintStore<T>>::remove(owner);
+            } else {
+                <RoleConstraintStore<T>>::insert(owner, constraints);
             }
 
             Ok(())
         }
 
-        fn revoke_role_to_group(
+        fn get_group_groups(
+            owner: &T::AccountId,
+            group_id: T::EntityId,
+        ) -> Result<Vec<Group2Group<T::EntityId>>, RbacError> {
+            // Generate key for integrity check
+            let key = Self::generate_key(owner, &group_id, Tag::Group2Group);
+
+            if <Group2GroupStore<T>>::contains_key(key) {
+                Ok(Self::group_to_group_of(key).into())
+            } else {
+                RbacError::err(AssignmentDoesNotExist, &group_id)
+            }
+        }
+
+        fn create_group_to_group(
+            owner: &T::AccountId,
+            group_id: T::EntityId,
+            parent_group_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            // Check if both groups exist and are enabled
+            Self::check_entity_get_key(owner, &group_id, Tag::Group)?;
+            Self::check_entity_get_key(owner, &parent_group_id, Tag::Group)?;
+
+            Self::insert_group_to_group(owner, group_id, parent_group_id)
+        }
+
+        fn revoke_group_to_group(
             owner: &T::AccountId,
-            role_id: T::EntityId,
             group_id: T::EntityId,
+            parent_group_id: T::EntityId,
         ) -> Result<(), RbacError> {
             // Generate key for integrity check
-            let role_2_group_key = Self::generate_key(owner, &group_id, Tag::Role2Group);
+            let group_2_group_key = Self::generate_key(owner, &group_id, Tag::Group2Group);
 
-            // Check if role exists
-            if !<Role2GroupStore<T>>::contains_key(role_2_group_key) {
+            // Check if group is a member of any groups
+            if !<Group2GroupStore<T>>::contains_key(group_2_group_key) {
                 return RbacError::err(AssignmentDoesNotExist, &group_id);
             }
 
-            let new_assign = Role2Group {
-                role: role_id,
+            let new_assign = Group2Group {
                 group: group_id,
+                parent: parent_group_id,
             };
 
-            let mut val = <Role2GroupStore<T>>::get(role_2_group_key);
-
-            if !val.contains(&new_assign) {
-                return RbacError::err(AssignmentDoesNotExist, &group_id);
-            }
+            let mut val = <Group2GroupStore<T>>::get(group_2_group_key);
 
             match val.binary_search(&new_assign) {
                 Ok(i) => val.remove(i),
@@ -1057,55 +4477,129 @@ pub mod pallet {
             };
 
             if val.is_empty() {
-                <Role2GroupStore<T>>::remove(role_2_group_key);
+                <Group2GroupStore<T>>::remove(group_2_group_key);
             } else {
-                <Role2GroupStore<T>>::mutate(role_2_group_key, |a| *a = val);
+                <Group2GroupStore<T>>::mutate(group_2_group_key, |a| *a = val);
             }
 
             Ok(())
         }
 
-        fn create_user_to_group(
+        fn create_role_to_user(
             owner: &T::AccountId,
+            role_id: T::EntityId,
+            user_id: T::EntityId,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> Result<(), RbacError> {
+            // Check if role exists and it's enabled
+            Self::check_entity_get_key(owner, &role_id, Tag::Role)?;
+            Self::check_user(owner, &user_id)?;
+
+            Self::insert_role_to_user(owner, role_id, user_id, expires_at)
+        }
+
+        fn revoke_role_to_user(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
             user_id: T::EntityId,
-            group_id: T::EntityId,
         ) -> Result<(), RbacError> {
             // Generate key for integrity check
-            let group_key = Self::generate_key(owner, &group_id, Tag::Group);
-            let user_2_group_key = Self::generate_key(owner, &user_id, Tag::User2Group);
+            let role_2_user_key = Self::generate_key(owner, &user_id, Tag::Role2User);
+
+            // Check if role exists
+            if !<Role2UserStore<T>>::contains_key(role_2_user_key) {
+                return RbacError::err(AssignmentDoesNotExist, &user_id);
+            }
+
+            let mut val = <Role2UserStore<T>>::get(role_2_user_key);
+
+            match val.binary_search_by(|x| (x.role, x.user).cmp(&(role_id, user_id))) {
+                Ok(i) => val.remove(i),
+                Err(_) => return RbacError::err(AssignmentDoesNotExist, &user_id),
+            };
 
-            // Check if group exists
-            if !<KeysLookUpStore<T>>::contains_key(group_key) {
-                return RbacError::err(EntityDoesNotExist, &group_id);
+            if val.is_empty() {
+                <Role2UserStore<T>>::remove(role_2_user_key);
             }
 
-            let mut groups: BoundedVec<User2Group<T::EntityId>, T::BoundedDataLen> =
-                BoundedVec::new();
+            if !val.is_empty() {
+                <Role2UserStore<T>>::mutate(role_2_user_key, |a| *a = val);
+            }
 
-            let new_assign = User2Group {
-                user: user_id,
+            let role_users_key = Self::generate_key(owner, &role_id, Tag::RoleUsers);
+            <RoleUsersStore<T>>::remove(role_users_key, user_id);
+
+            Ok(())
+        }
+
+        fn create_role_to_group(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+            group_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            // Check if role exists and it's enabled
+            Self::check_entity_get_key(owner, &role_id, Tag::Role)?;
+
+            // Check if group exists and it's enabled
+            Self::check_entity_get_key(owner, &group_id, Tag::Group)?;
+
+            Self::insert_role_to_group(owner, role_id, group_id)
+        }
+
+        fn revoke_role_to_group(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+            group_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            // Generate key for integrity check
+            let role_2_group_key = Self::generate_key(owner, &group_id, Tag::Role2Group);
+
+            // Check if role exists
+            if !<Role2GroupStore<T>>::contains_key(role_2_group_key) {
+                return RbacError::err(AssignmentDoesNotExist, &group_id);
+            }
+
+            let new_assign = Role2Group {
+                role: role_id,
                 group: group_id,
             };
 
-            // Check if role has already been assigned to group
-            if <User2GroupStore<T>>::contains_key(user_2_group_key) {
-                groups = <User2GroupStore<T>>::get(user_2_group_key);
+            let mut val = <Role2GroupStore<T>>::get(role_2_group_key);
 
-                if groups.contains(&new_assign) {
-                    return RbacError::err(AssignmentAlreadyExist, &group_id);
-                }
+            if !val.contains(&new_assign) {
+                return RbacError::err(AssignmentDoesNotExist, &group_id);
             }
 
-            let idx = groups.partition_point(|x| x < &new_assign);
+            match val.binary_search(&new_assign) {
+                Ok(i) => val.remove(i),
+                Err(_) => return RbacError::err(AssignmentDoesNotExist, &group_id),
+            };
 
-            match groups.try_insert(idx, new_assign) {
-                Err(e) => return RbacError::err(StorageExceedsMaxBounds, &e),
-                Ok(()) => <User2GroupStore<T>>::insert(user_2_group_key, groups),
+            if val.is_empty() {
+                <Role2GroupStore<T>>::remove(role_2_group_key);
+            } else {
+                <Role2GroupStore<T>>::mutate(role_2_group_key, |a| *a = val);
             }
 
+            let role_groups_key = Self::generate_key(owner, &role_id, Tag::RoleGroups);
+            <RoleGroupsStore<T>>::remove(role_groups_key, group_id);
+
             Ok(())
         }
 
+        fn create_user_to_group(
+            owner: &T::AccountId,
+            user_id: T::EntityId,
+            group_id: T::EntityId,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> Result<(), RbacError> {
+            // Check if group exists and it's enabled
+            Self::check_entity_get_key(owner, &group_id, Tag::Group)?;
+            Self::check_user(owner, &user_id)?;
+
+            Self::insert_user_to_group(owner, user_id, group_id, expires_at)
+        }
+
         fn revoke_user_to_group(
             owner: &T::AccountId,
             user_id: T::EntityId,
@@ -1119,18 +4613,9 @@ pub mod pallet {
                 return RbacError::err(AssignmentDoesNotExist, &group_id);
             }
 
-            let new_assign = User2Group {
-                user: user_id,
-                group: group_id,
-            };
-
             let mut val = <User2GroupStore<T>>::get(user_2_group_key);
 
-            if !val.contains(&new_assign) {
-                return RbacError::err(AssignmentDoesNotExist, &group_id);
-            }
-
-            match val.binary_search(&new_assign) {
+            match val.binary_search_by(|x| (x.user, x.group).cmp(&(user_id, group_id))) {
                 Ok(i) => val.remove(i),
                 Err(_) => return RbacError::err(AssignmentDoesNotExist, &group_id),
             };
@@ -1143,6 +4628,9 @@ pub mod pallet {
                 <User2GroupStore<T>>::mutate(user_2_group_key, |a| *a = val);
             }
 
+            let group_users_key = Self::generate_key(owner, &group_id, Tag::GroupUsers);
+            <GroupUsersStore<T>>::remove(group_users_key, user_id);
+
             Ok(())
         }
 
@@ -1151,46 +4639,13 @@ pub mod pallet {
             permission_id: T::EntityId,
             role_id: T::EntityId,
         ) -> Result<(), RbacError> {
-            // Generate key for integrity check
-            let role_key = Self::generate_key(owner, &role_id, Tag::Role);
-            let permission_key = Self::generate_key(owner, &permission_id, Tag::Permission);
-            let permission_2_role_key = Self::generate_key(owner, &role_id, Tag::Permission2Role);
-
-            // Check if role exists
-            if !<KeysLookUpStore<T>>::contains_key(role_key) {
-                return RbacError::err(EntityDoesNotExist, &role_id);
-            }
-
-            // Check if permission exists
-            if !<KeysLookUpStore<T>>::contains_key(permission_key) {
-                return RbacError::err(EntityDoesNotExist, &permission_id);
-            }
-
-            let mut permissions: BoundedVec<Permission2Role<T::EntityId>, T::BoundedDataLen> =
-                BoundedVec::new();
-
-            let new_assign = Permission2Role {
-                permission: permission_id,
-                role: role_id,
-            };
-
-            // Check if permission has already been assigned to role
-            if <Permission2RoleStore<T>>::contains_key(permission_2_role_key) {
-                permissions = <Permission2RoleStore<T>>::get(permission_2_role_key);
-
-                if permissions.contains(&new_assign) {
-                    return RbacError::err(AssignmentAlreadyExist, &role_id);
-                }
-            }
-
-            let idx = permissions.partition_point(|x| x < &new_assign);
+            // Check if role exists and it's enabled
+            Self::check_entity_get_key(owner, &role_id, Tag::Role)?;
 
-            match permissions.try_insert(idx, new_assign.clone()) {
-                Err(e) => return RbacError::err(StorageExceedsMaxBounds, &e),
-                Ok(()) => <Permission2RoleStore<T>>::insert(permission_2_role_key, permissions),
-            }
+            // Check if permission exists and it's enabled
+            Self::check_entity_get_key(owner, &permission_id, Tag::Permission)?;
 
-            Ok(())
+            Self::insert_permission_to_role(owner, permission_id, role_id)
         }
 
         fn revoke_permission_to_role(
@@ -1230,6 +4685,10 @@ pub mod pallet {
                 <Permission2RoleStore<T>>::mutate(permission_2_role_key, |a| *a = val);
             }
 
+            let permission_roles_key =
+                Self::generate_key(owner, &permission_id, Tag::PermissionRoles);
+            <PermissionRolesStore<T>>::remove(permission_roles_key, role_id);
+
             Ok(())
         }
 
@@ -1256,6 +4715,19 @@ pub mod pallet {
             Ok(<RoleStore<T>>::get(owner).into())
         }
 
+        fn get_roles_page(
+            owner: &T::AccountId,
+            start_after: Option<T::EntityId>,
+            limit: u32,
+        ) -> Result<Vec<Entity<T::EntityId>>, RbacError> {
+            Ok(Self::page(
+                <RoleStore<T>>::get(owner).into_inner(),
+                |e| e.id,
+                start_after,
+                limit,
+            ))
+        }
+
         fn create_role(
             owner: &T::AccountId,
             role_id: T::EntityId,
@@ -1345,6 +4817,46 @@ pub mod pallet {
             }
             Ok(())
         }
+
+        fn enable_existing_role(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            // Check if role exists and it's disabled and get key for integrity check
+            let key = Self::check_entity_disabled_get_key(owner, &role_id, Tag::Role)?;
+
+            let mut val = <RoleStore<T>>::get(owner);
+
+            for entity in val.iter_mut() {
+                if entity.id == role_id {
+                    entity.enabled = true;
+                    <KeysLookUpStore<T>>::mutate(key, |e| *e = entity.clone());
+                    break;
+                }
+            }
+
+            if !val.is_empty() {
+                <RoleStore<T>>::mutate(owner, |v| *v = val);
+            }
+            Ok(())
+        }
+
+        fn delete_existing_role(
+            owner: &T::AccountId,
+            role_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            // Check if role exists, it may be disabled, and get key for integrity check
+            let key = Self::check_entity_exists_get_key(owner, &role_id, Tag::Role)?;
+
+            Self::purge_role_assignments(owner, role_id);
+
+            <RoleStore<T>>::mutate_exists(owner, |v| {
+                Self::retain_or_remove(v, |role| role.id != role_id)
+            });
+            <KeysLookUpStore<T>>::remove(key);
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Permission<T::AccountId, T::EntityId> for Pallet<T> {
@@ -1359,6 +4871,19 @@ pub mod pallet {
             Ok(<PermissionStore<T>>::get(owner).into())
         }
 
+        fn get_permissions_page(
+            owner: &T::AccountId,
+            start_after: Option<T::EntityId>,
+            limit: u32,
+        ) -> Result<Vec<Entity<T::EntityId>>, RbacError> {
+            Ok(Self::page(
+                <PermissionStore<T>>::get(owner).into_inner(),
+                |e| e.id,
+                start_after,
+                limit,
+            ))
+        }
+
         fn create_permission(
             owner: &T::AccountId,
             permission_id: T::EntityId,
@@ -1449,6 +4974,47 @@ pub mod pallet {
 
             Ok(())
         }
+
+        fn enable_existing_permission(
+            owner: &T::AccountId,
+            permission_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            // Check if permission exists and it's disabled and get key for integrity check
+            let key = Self::check_entity_disabled_get_key(owner, &permission_id, Tag::Permission)?;
+
+            let mut val = <PermissionStore<T>>::get(owner);
+
+            for entity in val.iter_mut() {
+                if entity.id == permission_id {
+                    entity.enabled = true;
+                    <KeysLookUpStore<T>>::mutate(key, |e| *e = entity.clone());
+                    break;
+                }
+            }
+
+            if !val.is_empty() {
+                <PermissionStore<T>>::mutate(owner, |v| *v = val);
+            }
+
+            Ok(())
+        }
+
+        fn delete_existing_permission(
+            owner: &T::AccountId,
+            permission_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            // Check if permission exists, it may be disabled, and get key for integrity check
+            let key = Self::check_entity_exists_get_key(owner, &permission_id, Tag::Permission)?;
+
+            Self::purge_permission_assignments(owner, permission_id);
+
+            <PermissionStore<T>>::mutate_exists(owner, |v| {
+                Self::retain_or_remove(v, |permission| permission.id != permission_id)
+            });
+            <KeysLookUpStore<T>>::remove(key);
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Group<T::AccountId, T::EntityId> for Pallet<T> {
@@ -1463,6 +5029,19 @@ pub mod pallet {
             Ok(<GroupStore<T>>::get(owner).into())
         }
 
+        fn get_groups_page(
+            owner: &T::AccountId,
+            start_after: Option<T::EntityId>,
+            limit: u32,
+        ) -> Result<Vec<Entity<T::EntityId>>, RbacError> {
+            Ok(Self::page(
+                <GroupStore<T>>::get(owner).into_inner(),
+                |e| e.id,
+                start_after,
+                limit,
+            ))
+        }
+
         fn create_group(
             owner: &T::AccountId,
             group_id: T::EntityId,
@@ -1545,5 +5124,192 @@ pub mod pallet {
             }
             Ok(())
         }
+
+        fn enable_existing_group(
+            owner: &T::AccountId,
+            group_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            // Check if group exists and it's disabled and get key for integrity check
+            let key = Self::check_entity_disabled_get_key(owner, &group_id, Tag::Group)?;
+            let mut val = <GroupStore<T>>::get(owner);
+
+            for entity in val.iter_mut() {
+                if entity.id == group_id {
+                    entity.enabled = true;
+                    <KeysLookUpStore<T>>::mutate(key, |e| *e = entity.clone());
+                    break;
+                }
+            }
+
+            if !val.is_empty() {
+                <GroupStore<T>>::mutate(owner, |v| *v = val);
+            }
+            Ok(())
+        }
+
+        fn delete_existing_group(
+            owner: &T::AccountId,
+            group_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            // Check if group exists, it may be disabled, and get key for integrity check
+            let key = Self::check_entity_exists_get_key(owner, &group_id, Tag::Group)?;
+
+            Self::purge_group_assignments(owner, group_id);
+
+            <GroupStore<T>>::mutate_exists(owner, |v| {
+                Self::retain_or_remove(v, |group| group.id != group_id)
+            });
+            <KeysLookUpStore<T>>::remove(key);
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> User<T::AccountId, T::EntityId> for Pallet<T> {
+        fn get_user(
+            owner: &T::AccountId,
+            user_id: T::EntityId,
+        ) -> Result<Entity<T::EntityId>, RbacError> {
+            Self::get_entity(owner, &user_id, Tag::User)
+        }
+
+        fn get_users(owner: &T::AccountId) -> Result<Vec<Entity<T::EntityId>>, RbacError> {
+            Ok(<UserStore<T>>::get(owner).into())
+        }
+
+        fn create_user(
+            owner: &T::AccountId,
+            user_id: T::EntityId,
+            name: &[u8],
+        ) -> Result<(), RbacError> {
+            // Generate key for integrity check
+            let key = Self::generate_key(owner, &user_id, Tag::User);
+
+            // Check if user already exists
+            if <KeysLookUpStore<T>>::contains_key(key) {
+                return RbacError::err(EntityAlreadyExist, &user_id);
+            }
+
+            let new_user = Entity {
+                id: user_id,
+                name: name.to_vec(),
+                enabled: true,
+            };
+
+            let mut users = <UserStore<T>>::get(owner);
+
+            match users.try_push(new_user.clone()) {
+                Err(e) => return RbacError::err(StorageExceedsMaxBounds, &e),
+                Ok(()) => {
+                    <UserStore<T>>::insert(owner, users);
+                    <KeysLookUpStore<T>>::insert(key, new_user);
+                }
+            }
+
+            Ok(())
+        }
+
+        fn update_existing_user(
+            owner: &T::AccountId,
+            user_id: T::EntityId,
+            name: &[u8],
+        ) -> Result<(), RbacError> {
+            // Check if user exists and it's enabled and get key for integrity check
+            let key = Self::check_entity_get_key(owner, &user_id, Tag::User)?;
+
+            <UserStore<T>>::mutate(owner, |users| {
+                if let Some(entity) = users.iter_mut().find(|user| user.id == user_id) {
+                    entity.name = name.to_vec();
+                    <KeysLookUpStore<T>>::insert(key, entity.clone());
+                }
+            });
+
+            Ok(())
+        }
+
+        fn disable_existing_user(
+            owner: &T::AccountId,
+            user_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            // Check if user exists and it's enabled and get key for integrity check
+            let key = Self::check_entity_get_key(owner, &user_id, Tag::User)?;
+
+            <UserStore<T>>::mutate(owner, |users| {
+                if let Some(entity) = users.iter_mut().find(|user| user.id == user_id) {
+                    entity.enabled = false;
+                    <KeysLookUpStore<T>>::insert(key, entity.clone());
+                }
+            });
+
+            Ok(())
+        }
+
+        fn user_of_account(owner: &T::AccountId, account: &T::AccountId) -> Option<T::EntityId> {
+            <Account2UserStore<T>>::get(owner, account)
+        }
+
+        fn account_of_user(owner: &T::AccountId, user_id: T::EntityId) -> Option<T::AccountId> {
+            <User2AccountStore<T>>::get(owner, user_id)
+        }
+
+        fn create_user_claim(
+            owner: &T::AccountId,
+            user_id: T::EntityId,
+            account: &T::AccountId,
+        ) -> Result<(), RbacError> {
+            // An account can only be bound to a single user of a domain and vice versa
+            if <Account2UserStore<T>>::contains_key(owner, account) {
+                return RbacError::err(AssignmentAlreadyExist, account);
+            }
+            if <User2AccountStore<T>>::contains_key(owner, user_id) {
+                return RbacError::err(AssignmentAlreadyExist, &user_id);
+            }
+
+            // Only registered users can be claimed, which bounds the claims of a domain
+            Self::check_entity_get_key(owner, &user_id, Tag::User)?;
+
+            // A new claim of the same user replaces its previous one
+            <UserClaimStore<T>>::insert(owner, user_id, account);
+
+            Ok(())
+        }
+
+        fn create_user_binding(
+            owner: &T::AccountId,
+            user_id: T::EntityId,
+            account: &T::AccountId,
+        ) -> Result<(), RbacError> {
+            if <UserClaimStore<T>>::get(owner, user_id).as_ref() != Some(account) {
+                return RbacError::err(AssignmentDoesNotExist, &user_id);
+            }
+
+            // Another claim for the user or of the account may have been approved in the meantime
+            if <User2AccountStore<T>>::contains_key(owner, user_id) {
+                return RbacError::err(AssignmentAlreadyExist, &user_id);
+            }
+            if <Account2UserStore<T>>::contains_key(owner, account) {
+                return RbacError::err(AssignmentAlreadyExist, account);
+            }
+
+            Self::check_user(owner, &user_id)?;
+
+            <UserClaimStore<T>>::remove(owner, user_id);
+            <User2AccountStore<T>>::insert(owner, user_id, account);
+            <Account2UserStore<T>>::insert(owner, account, user_id);
+
+            Ok(())
+        }
+
+        fn revoke_user_binding(
+            owner: &T::AccountId,
+            user_id: T::EntityId,
+        ) -> Result<(), RbacError> {
+            match <User2AccountStore<T>>::take(owner, user_id) {
+                Some(account) => <Account2UserStore<T>>::remove(owner, account),
+                None => return RbacError::err(AssignmentDoesNotExist, &user_id),
+            }
+
+            Ok(())
+        }
     }
 }
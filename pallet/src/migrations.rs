@@ -4,11 +4,43 @@ use frame_support::{
     dispatch::GetStorageVersion, pallet_prelude::StorageVersion, traits::Get, weights::Weight,
     BoundedVec,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
+use rbac::{Rbac, Tag};
 use structs::*;
 
 pub(crate) fn on_runtime_upgrade<T: Config>() -> Weight {
     MigrateToV1x::<T>::on_runtime_upgrade()
+        .saturating_add(MigrateToV2::<T>::on_runtime_upgrade())
+        .saturating_add(MigrateToV3::<T>::on_runtime_upgrade())
+}
+
+// Storage layouts of previous storage versions
+mod v1 {
+    use super::*;
+    use frame_support::{storage_alias, Blake2_128Concat};
+    use rbac::RbacKeyType;
+
+    pub use structs::{LegacyRole2User as Role2User, LegacyUser2Group as User2Group};
+
+    #[storage_alias]
+    pub type Role2UserStore<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        RbacKeyType,
+        BoundedVec<Role2User<<T as Config>::EntityId>, <T as Config>::BoundedDataLen>,
+        ValueQuery,
+    >;
+
+    #[storage_alias]
+    pub type User2GroupStore<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        RbacKeyType,
+        BoundedVec<User2Group<<T as Config>::EntityId>, <T as Config>::BoundedDataLen>,
+        ValueQuery,
+    >;
 }
 
 // This migration sort all values in some StorageMaps allowing them to be binary searchable
@@ -17,24 +49,24 @@ pub struct MigrateToV1x<T>(sp_std::marker::PhantomData<T>);
 
 impl<T: Config> MigrateToV1x<T> {
     pub fn on_runtime_upgrade() -> Weight {
-        let target_storage_version: StorageVersion = Pallet::<T>::current_storage_version();
+        let target_storage_version = StorageVersion::new(1);
         let on_chain_storage_version: StorageVersion = Pallet::<T>::on_chain_storage_version();
 
         let mut weight: u64 = 0;
         if on_chain_storage_version < target_storage_version {
             log::info!(
-                "Pallet RBAC: Migration from onchain version {:?} to current version {:?}",
+                "Pallet RBAC: Migration from onchain version {:?} to version {:?}",
                 on_chain_storage_version,
                 target_storage_version,
             );
 
             // TODO repetitive translation logic with different types, reduce this with a macro
             // translate all vec to sorted vec
-            Role2UserStore::<T>::translate_values::<
-                BoundedVec<Role2User<T::EntityId>, T::BoundedDataLen>,
+            v1::Role2UserStore::<T>::translate_values::<
+                BoundedVec<v1::Role2User<T::EntityId>, T::BoundedDataLen>,
                 _,
             >(
-                |val: BoundedVec<Role2User<T::EntityId>, T::BoundedDataLen>| {
+                |val: BoundedVec<v1::Role2User<T::EntityId>, T::BoundedDataLen>| {
                     let mut sorted_val = val.clone();
                     sorted_val.sort();
                     weight += 1;
@@ -56,11 +88,11 @@ impl<T: Config> MigrateToV1x<T> {
             );
 
             // translate all vec to sorted vec
-            User2GroupStore::<T>::translate_values::<
-                BoundedVec<User2Group<T::EntityId>, T::BoundedDataLen>,
+            v1::User2GroupStore::<T>::translate_values::<
+                BoundedVec<v1::User2Group<T::EntityId>, T::BoundedDataLen>,
                 _,
             >(
-                |val: BoundedVec<User2Group<T::EntityId>, T::BoundedDataLen>| {
+                |val: BoundedVec<v1::User2Group<T::EntityId>, T::BoundedDataLen>| {
                     let mut sorted_val = val.clone();
                     sorted_val.sort();
                     weight += 1;
@@ -92,3 +124,192 @@ impl<T: Config> MigrateToV1x<T> {
         T::DbWeight::get().reads_writes(weight, weight)
     }
 }
+
+// This migration adds an optional expiry to all role to user and user to group assignments,
+// existing assignments never expire
+pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> MigrateToV2<T> {
+    pub fn on_runtime_upgrade() -> Weight {
+        let target_storage_version = StorageVersion::new(2);
+        let on_chain_storage_version: StorageVersion = Pallet::<T>::on_chain_storage_version();
+
+        let mut weight: u64 = 0;
+        if on_chain_storage_version < target_storage_version {
+            log::info!(
+                "Pallet RBAC: Migration from onchain version {:?} to version {:?}",
+                on_chain_storage_version,
+                target_storage_version,
+            );
+
+            // add expiry to all values, this keeps the values sorted
+            Role2UserStore::<T>::translate_values::<
+                BoundedVec<v1::Role2User<T::EntityId>, T::BoundedDataLen>,
+                _,
+            >(
+                |val: BoundedVec<v1::Role2User<T::EntityId>, T::BoundedDataLen>| {
+                    weight += 1;
+                    let val: Vec<Role2User<T::EntityId, BlockNumberFor<T>>> =
+                        val.into_iter().map(Into::into).collect();
+                    val.try_into().ok()
+                },
+            );
+
+            // add expiry to all values, this keeps the values sorted
+            User2GroupStore::<T>::translate_values::<
+                BoundedVec<v1::User2Group<T::EntityId>, T::BoundedDataLen>,
+                _,
+            >(
+                |val: BoundedVec<v1::User2Group<T::EntityId>, T::BoundedDataLen>| {
+                    weight += 1;
+                    let val: Vec<User2Group<T::EntityId, BlockNumberFor<T>>> =
+                        val.into_iter().map(Into::into).collect();
+                    val.try_into().ok()
+                },
+            );
+
+            // upgrade current_storage_version
+            log::info!(
+                "Pallet RBAC: Setting storage version to {:?}",
+                target_storage_version
+            );
+            target_storage_version.put::<Pallet<T>>();
+        }
+        log::info!("Weight calculated: {:?}", weight);
+        T::DbWeight::get().reads_writes(weight, weight)
+    }
+}
+
+// This migration backfills the reverse indexes of the role to user, user to group, role to
+// group and permission to role assignments
+pub struct MigrateToV3<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> MigrateToV3<T> {
+    pub fn on_runtime_upgrade() -> Weight {
+        let target_storage_version = StorageVersion::new(3);
+        let on_chain_storage_version: StorageVersion = Pallet::<T>::on_chain_storage_version();
+
+        let mut reads: u64 = 0;
+        let mut writes: u64 = 0;
+        if on_chain_storage_version < target_storage_version {
+            log::info!(
+                "Pallet RBAC: Migration from onchain version {:?} to version {:?}",
+                on_chain_storage_version,
+                target_storage_version,
+            );
+
+            let key = |owner: &T::AccountId, entity: &T::EntityId, tag: Tag| {
+                <Pallet<T> as Rbac<T::AccountId, T::EntityId, BlockNumberFor<T>>>::generate_key(
+                    owner, entity, tag,
+                )
+            };
+
+            // Roles and groups are known per owner, so their assignments can be looked up
+            let role_owners: Vec<(T::AccountId, Vec<T::EntityId>)> = RoleStore::<T>::iter()
+                .map(|(owner, roles)| (owner, roles.iter().map(|role| role.id).collect()))
+                .collect();
+            let group_owners: Vec<(T::AccountId, Vec<T::EntityId>)> = GroupStore::<T>::iter()
+                .map(|(owner, groups)| (owner, groups.iter().map(|group| group.id).collect()))
+                .collect();
+            reads += (role_owners.len() + group_owners.len()) as u64;
+
+            // Owners of each role and group id, ids are usually unique across owners
+            let owners_by_id = |owners: &[(T::AccountId, Vec<T::EntityId>)]| {
+                let mut by_id: BTreeMap<T::EntityId, Vec<T::AccountId>> = BTreeMap::new();
+                for (owner, ids) in owners.iter() {
+                    for id in ids.iter() {
+                        by_id.entry(*id).or_default().push(owner.clone());
+                    }
+                }
+                by_id
+            };
+            let role_owners_by_id = owners_by_id(&role_owners);
+            let group_owners_by_id = owners_by_id(&group_owners);
+
+            for (owner, roles) in role_owners.iter() {
+                for role in roles.iter() {
+                    reads += 1;
+                    let p2rs =
+                        Permission2RoleStore::<T>::get(key(owner, role, Tag::Permission2Role));
+                    for p2r in p2rs.iter() {
+                        writes += 1;
+                        let index_key = key(owner, &p2r.permission, Tag::PermissionRoles);
+                        PermissionRolesStore::<T>::insert(index_key, p2r.role, ());
+                    }
+                }
+            }
+
+            for (owner, groups) in group_owners.iter() {
+                for group in groups.iter() {
+                    reads += 1;
+                    let r2gs = Role2GroupStore::<T>::get(key(owner, group, Tag::Role2Group));
+                    for r2g in r2gs.iter() {
+                        writes += 1;
+                        let index_key = key(owner, &r2g.role, Tag::RoleGroups);
+                        RoleGroupsStore::<T>::insert(index_key, r2g.group, ());
+                    }
+                }
+            }
+
+            // Users are not known per owner, so the owner of an assignment is found by
+            // comparing its storage key with the keys of the owners of its role or group
+            let mut dropped: u64 = 0;
+            for (storage_key, r2us) in Role2UserStore::<T>::iter() {
+                reads += 1;
+                let owner = r2us.iter().find_map(|r2u| {
+                    role_owners_by_id
+                        .get(&r2u.role)?
+                        .iter()
+                        .find(|owner| key(owner, &r2u.user, Tag::Role2User) == storage_key)
+                });
+                match owner {
+                    Some(owner) => {
+                        for r2u in r2us.iter() {
+                            writes += 1;
+                            let index_key = key(owner, &r2u.role, Tag::RoleUsers);
+                            RoleUsersStore::<T>::insert(index_key, r2u.user, r2u.expires_at);
+                        }
+                    }
+                    None => dropped += r2us.len() as u64,
+                }
+            }
+
+            for (storage_key, u2gs) in User2GroupStore::<T>::iter() {
+                reads += 1;
+                let owner = u2gs.iter().find_map(|u2g| {
+                    group_owners_by_id
+                        .get(&u2g.group)?
+                        .iter()
+                        .find(|owner| key(owner, &u2g.user, Tag::User2Group) == storage_key)
+                });
+                match owner {
+                    Some(owner) => {
+                        for u2g in u2gs.iter() {
+                            writes += 1;
+                            let index_key = key(owner, &u2g.group, Tag::GroupUsers);
+                            GroupUsersStore::<T>::insert(index_key, u2g.user, u2g.expires_at);
+                        }
+                    }
+                    None => dropped += u2gs.len() as u64,
+                }
+            }
+
+            // Assignments, whose roles or groups do not exist anymore, cannot be attributed
+            if dropped > 0 {
+                log::warn!(
+                    "Pallet RBAC: {:?} assignments of unknown roles or groups were not indexed",
+                    dropped
+                );
+            }
+
+            // upgrade current_storage_version
+            log::info!(
+                "Pallet RBAC: Setting storage version to {:?}",
+                target_storage_version
+            );
+            target_storage_version.put::<Pallet<T>>();
+        }
+        log::info!("Weight calculated: {:?}", (reads, writes));
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
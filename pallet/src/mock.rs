@@ -1,11 +1,12 @@
 use crate as peaq_rbac;
-use frame_support::parameter_types;
+use crate::rbac::DisabledEntityPolicy;
+use frame_support::{parameter_types, weights::constants::RocksDbWeight};
 use frame_system as system;
 
 use sp_core::{sr25519, Pair, H256};
 use sp_runtime::{
     testing::Header,
-    traits::{BlakeTwo256, IdentityLookup},
+    traits::{BlakeTwo256, Convert, IdentityLookup},
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -33,7 +34,7 @@ impl system::Config for Test {
     type BaseCallFilter = frame_support::traits::Everything;
     type BlockWeights = ();
     type BlockLength = ();
-    type DbWeight = ();
+    type DbWeight = RocksDbWeight;
     type RuntimeOrigin = RuntimeOrigin;
     type RuntimeCall = RuntimeCall;
     type Index = u64;
@@ -59,6 +60,11 @@ impl system::Config for Test {
 parameter_types! {
     pub const MinimumPeriod: u64 = 5;
     pub const BoundedDataLen: u32 = 256;
+    pub const MaxRoleDepth: u32 = 3;
+    pub const MaxGroupDepth: u32 = 3;
+    pub const MaxOps: u32 = 32;
+    pub storage DisabledPolicy: DisabledEntityPolicy = DisabledEntityPolicy::Skip;
+    pub storage RequireRegisteredUsers: bool = false;
 }
 
 impl pallet_timestamp::Config for Test {
@@ -68,10 +74,24 @@ impl pallet_timestamp::Config for Test {
     type WeightInfo = ();
 }
 
+pub struct AccountToUser;
+
+impl Convert<sr25519::Public, [u8; 32]> for AccountToUser {
+    fn convert(account: sr25519::Public) -> [u8; 32] {
+        account.0
+    }
+}
+
 impl peaq_rbac::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type EntityId = [u8; 32];
     type BoundedDataLen = BoundedDataLen;
+    type MaxRoleDepth = MaxRoleDepth;
+    type MaxGroupDepth = MaxGroupDepth;
+    type MaxOps = MaxOps;
+    type AccountToUser = AccountToUser;
+    type DisabledPolicy = DisabledPolicy;
+    type RequireRegisteredUsers = RequireRegisteredUsers;
     type WeightInfo = peaq_rbac::weights::WeightInfo<Test>;
 }
 
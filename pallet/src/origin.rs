@@ -0,0 +1,78 @@
+//! Origin checks backed by RBAC permissions, to be used by other pallets of the runtime.
+
+use frame_support::traits::{EnsureOrigin, Get};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Convert;
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "runtime-benchmarks")]
+use crate::rbac::{Permission, Role};
+use crate::{
+    rbac::{Rbac, User},
+    Config, Pallet,
+};
+
+/// Ensures that the origin is signed by an account, whose RBAC user holds `PermissionId`
+/// within the RBAC domain of `Owner`. The signing account gets resolved to the RBAC user
+/// it is bound to within the domain, or else by `Config::AccountToUser`, as long as that
+/// user is not bound to another account. Returns the signing account on success. The check
+/// is not charged by itself, its cost grows with the user's roles, groups and role depth,
+/// see `WeightInfo::has_permission`.
+pub struct EnsureRbacPermission<T, Owner, PermissionId>(PhantomData<(T, Owner, PermissionId)>);
+
+impl<T, Owner, PermissionId> EnsureOrigin<T::RuntimeOrigin>
+    for EnsureRbacPermission<T, Owner, PermissionId>
+where
+    T: Config,
+    Owner: Get<T::AccountId>,
+    PermissionId: Get<T::EntityId>,
+{
+    type Success = T::AccountId;
+
+    fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
+        let o: Result<RawOrigin<T::AccountId>, T::RuntimeOrigin> = o.into();
+
+        o.and_then(|o| match o {
+            RawOrigin::Signed(who) => {
+                let owner = Owner::get();
+                let user_id = match Pallet::<T>::user_of_account(&owner, &who) {
+                    Some(user_id) => Some(user_id),
+                    // Bound users can only be acted for by their bound account
+                    None => Some(T::AccountToUser::convert(who.clone()))
+                        .filter(|user_id| Pallet::<T>::account_of_user(&owner, *user_id).is_none()),
+                };
+
+                if user_id.map_or(false, |user_id| {
+                    Pallet::<T>::has_permission(&owner, user_id, PermissionId::get())
+                }) {
+                    Ok(who)
+                } else {
+                    Err(RawOrigin::Signed(who).into())
+                }
+            }
+            r => Err(r.into()),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
+        let owner = Owner::get();
+        let permission_id = PermissionId::get();
+        let role_id = T::EntityId::default();
+        let who: T::AccountId = frame_benchmarking::account("rbac_origin", 0, 0);
+        let user_id = T::AccountToUser::convert(who.clone());
+
+        // Entities and assignments, which exist already, are reused
+        let _ = Pallet::<T>::create_user(&owner, user_id, b"origin");
+        let _ = Pallet::<T>::create_role(&owner, role_id, b"origin");
+        let _ = Pallet::<T>::create_permission(&owner, permission_id, b"origin");
+        let _ = Pallet::<T>::create_permission_to_role(&owner, permission_id, role_id);
+        let _ = Pallet::<T>::create_role_to_user(&owner, role_id, user_id, None);
+
+        if Pallet::<T>::has_permission(&owner, user_id, permission_id) {
+            Ok(RawOrigin::Signed(who).into())
+        } else {
+            Err(())
+        }
+    }
+}
@@ -1,4 +1,7 @@
 use crate::structs::*;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::RuntimeDebug;
 use sp_std::vec::Vec;
 
 use crate::error::RbacError;
@@ -6,7 +9,7 @@ pub use crate::error::Result;
 
 pub type RbacKeyType = [u8; 32];
 
-pub trait Rbac<AccountId, EntityId> {
+pub trait Rbac<AccountId, EntityId, BlockNumber> {
     fn generate_key(owner: &AccountId, entity: &EntityId, tag: Tag) -> RbacKeyType;
 
     fn get_entity(
@@ -24,23 +27,57 @@ pub trait Rbac<AccountId, EntityId> {
     fn get_user_roles(
         owner: &AccountId,
         user_id: EntityId,
-    ) -> Result<Vec<Role2User<EntityId>>, RbacError>;
+    ) -> Result<Vec<Role2User<EntityId, BlockNumber>>, RbacError>;
+
+    fn get_user_roles_page(
+        owner: &AccountId,
+        user_id: EntityId,
+        start_after: Option<EntityId>,
+        limit: u32,
+    ) -> Result<Vec<Role2User<EntityId, BlockNumber>>, RbacError>;
 
     fn get_user_groups(
         owner: &AccountId,
         user_id: EntityId,
-    ) -> Result<Vec<User2Group<EntityId>>, RbacError>;
+    ) -> Result<Vec<User2Group<EntityId, BlockNumber>>, RbacError>;
 
     fn get_group_roles(
         owner: &AccountId,
         group_id: EntityId,
     ) -> Result<Vec<Role2Group<EntityId>>, RbacError>;
 
+    fn get_group_roles_page(
+        owner: &AccountId,
+        group_id: EntityId,
+        start_after: Option<EntityId>,
+        limit: u32,
+    ) -> Result<Vec<Role2Group<EntityId>>, RbacError>;
+
     fn get_role_permissions(
         owner: &AccountId,
         role_id: EntityId,
     ) -> Result<Vec<Permission2Role<EntityId>>, RbacError>;
 
+    fn get_role_users(
+        owner: &AccountId,
+        role_id: EntityId,
+    ) -> Result<Vec<Role2User<EntityId, BlockNumber>>, RbacError>;
+
+    fn get_group_members(
+        owner: &AccountId,
+        group_id: EntityId,
+    ) -> Result<Vec<User2Group<EntityId, BlockNumber>>, RbacError>;
+
+    fn get_role_groups(
+        owner: &AccountId,
+        role_id: EntityId,
+    ) -> Result<Vec<Role2Group<EntityId>>, RbacError>;
+
+    fn get_permission_roles(
+        owner: &AccountId,
+        permission_id: EntityId,
+    ) -> Result<Vec<Permission2Role<EntityId>>, RbacError>;
+
     fn get_user_permissions(
         owner: &AccountId,
         user_id: EntityId,
@@ -51,10 +88,61 @@ pub trait Rbac<AccountId, EntityId> {
         group_id: EntityId,
     ) -> Result<Vec<Entity<EntityId>>, RbacError>;
 
+    fn has_permission(owner: &AccountId, user_id: EntityId, permission_id: EntityId) -> bool;
+
+    fn check_permission(
+        owner: &AccountId,
+        user_id: EntityId,
+        permission_id: EntityId,
+    ) -> Result<bool, RbacError>;
+
+    fn check_permissions(
+        owner: &AccountId,
+        checks: &[(EntityId, EntityId)],
+    ) -> Vec<Result<bool, RbacError>>;
+
+    fn get_permission_grant(
+        owner: &AccountId,
+        user_id: EntityId,
+        permission_id: EntityId,
+    ) -> Option<PermissionGrant<EntityId>>;
+
+    fn explain_user_permission(
+        owner: &AccountId,
+        user_id: EntityId,
+        permission_id: EntityId,
+    ) -> Result<Vec<PermissionGrant<EntityId>>, RbacError>;
+
+    fn get_role_roles(
+        owner: &AccountId,
+        role_id: EntityId,
+    ) -> Result<Vec<Role2Role<EntityId>>, RbacError>;
+
+    fn get_group_groups(
+        owner: &AccountId,
+        group_id: EntityId,
+    ) -> Result<Vec<Group2Group<EntityId>>, RbacError>;
+
+    fn get_user_effective_roles(owner: &AccountId, user_id: EntityId) -> Vec<EntityId>;
+
+    fn get_role_constraints(owner: &AccountId) -> Result<Vec<RoleConstraint<EntityId>>, RbacError>;
+
+    fn export_domain(owner: &AccountId) -> Result<RbacDomain<EntityId, BlockNumber>, RbacError>;
+
+    fn create_role_constraint(
+        owner: &AccountId,
+        constraint_id: EntityId,
+        kind: ConstraintKind,
+        roles: &[EntityId],
+    ) -> Result<(), RbacError>;
+
+    fn delete_role_constraint(owner: &AccountId, constraint_id: EntityId) -> Result<(), RbacError>;
+
     fn create_role_to_user(
         owner: &AccountId,
         role_id: EntityId,
         user_id: EntityId,
+        expires_at: Option<BlockNumber>,
     ) -> Result<(), RbacError>;
 
     fn revoke_role_to_user(
@@ -63,6 +151,18 @@ pub trait Rbac<AccountId, EntityId> {
         user_id: EntityId,
     ) -> Result<(), RbacError>;
 
+    fn create_role_to_role(
+        owner: &AccountId,
+        role_id: EntityId,
+        inherited_role_id: EntityId,
+    ) -> Result<(), RbacError>;
+
+    fn revoke_role_to_role(
+        owner: &AccountId,
+        role_id: EntityId,
+        inherited_role_id: EntityId,
+    ) -> Result<(), RbacError>;
+
     fn create_role_to_group(
         owner: &AccountId,
         role_id: EntityId,
@@ -79,6 +179,7 @@ pub trait Rbac<AccountId, EntityId> {
         owner: &AccountId,
         user_id: EntityId,
         group_id: EntityId,
+        expires_at: Option<BlockNumber>,
     ) -> Result<(), RbacError>;
 
     fn revoke_user_to_group(
@@ -87,6 +188,18 @@ pub trait Rbac<AccountId, EntityId> {
         group_id: EntityId,
     ) -> Result<(), RbacError>;
 
+    fn create_group_to_group(
+        owner: &AccountId,
+        group_id: EntityId,
+        parent_group_id: EntityId,
+    ) -> Result<(), RbacError>;
+
+    fn revoke_group_to_group(
+        owner: &AccountId,
+        group_id: EntityId,
+        parent_group_id: EntityId,
+    ) -> Result<(), RbacError>;
+
     fn create_permission_to_role(
         owner: &AccountId,
         permission_id: EntityId,
@@ -105,6 +218,12 @@ pub trait Role<AccountId, EntityId, BoundedDataLen> {
 
     fn get_roles(owner: &AccountId) -> Result<Vec<Entity<EntityId>>, RbacError>;
 
+    fn get_roles_page(
+        owner: &AccountId,
+        start_after: Option<EntityId>,
+        limit: u32,
+    ) -> Result<Vec<Entity<EntityId>>, RbacError>;
+
     fn create_role(owner: &AccountId, role_id: EntityId, name: &[u8]) -> Result<(), RbacError>;
 
     fn update_existing_role(
@@ -114,6 +233,10 @@ pub trait Role<AccountId, EntityId, BoundedDataLen> {
     ) -> Result<(), RbacError>;
 
     fn disable_existing_role(owner: &AccountId, role_id: EntityId) -> Result<(), RbacError>;
+
+    fn enable_existing_role(owner: &AccountId, role_id: EntityId) -> Result<(), RbacError>;
+
+    fn delete_existing_role(owner: &AccountId, role_id: EntityId) -> Result<(), RbacError>;
 }
 
 pub trait Permission<AccountId, EntityId> {
@@ -124,6 +247,12 @@ pub trait Permission<AccountId, EntityId> {
 
     fn get_permissions(owner: &AccountId) -> Result<Vec<Entity<EntityId>>, RbacError>;
 
+    fn get_permissions_page(
+        owner: &AccountId,
+        start_after: Option<EntityId>,
+        limit: u32,
+    ) -> Result<Vec<Entity<EntityId>>, RbacError>;
+
     fn create_permission(
         owner: &AccountId,
         permission_id: EntityId,
@@ -140,6 +269,16 @@ pub trait Permission<AccountId, EntityId> {
         owner: &AccountId,
         permission_id: EntityId,
     ) -> Result<(), RbacError>;
+
+    fn enable_existing_permission(
+        owner: &AccountId,
+        permission_id: EntityId,
+    ) -> Result<(), RbacError>;
+
+    fn delete_existing_permission(
+        owner: &AccountId,
+        permission_id: EntityId,
+    ) -> Result<(), RbacError>;
 }
 
 pub trait Group<AccountId, EntityId> {
@@ -147,6 +286,12 @@ pub trait Group<AccountId, EntityId> {
 
     fn get_groups(owner: &AccountId) -> Result<Vec<Entity<EntityId>>, RbacError>;
 
+    fn get_groups_page(
+        owner: &AccountId,
+        start_after: Option<EntityId>,
+        limit: u32,
+    ) -> Result<Vec<Entity<EntityId>>, RbacError>;
+
     fn create_group(owner: &AccountId, group_id: EntityId, name: &[u8]) -> Result<(), RbacError>;
 
     fn update_existing_group(
@@ -156,8 +301,56 @@ pub trait Group<AccountId, EntityId> {
     ) -> Result<(), RbacError>;
 
     fn disable_existing_group(owner: &AccountId, group_id: EntityId) -> Result<(), RbacError>;
+
+    fn enable_existing_group(owner: &AccountId, group_id: EntityId) -> Result<(), RbacError>;
+
+    fn delete_existing_group(owner: &AccountId, group_id: EntityId) -> Result<(), RbacError>;
+}
+
+pub trait User<AccountId, EntityId> {
+    fn get_user(owner: &AccountId, user_id: EntityId) -> Result<Entity<EntityId>, RbacError>;
+
+    fn get_users(owner: &AccountId) -> Result<Vec<Entity<EntityId>>, RbacError>;
+
+    fn create_user(owner: &AccountId, user_id: EntityId, name: &[u8]) -> Result<(), RbacError>;
+
+    fn update_existing_user(
+        owner: &AccountId,
+        user_id: EntityId,
+        name: &[u8],
+    ) -> Result<(), RbacError>;
+
+    fn disable_existing_user(owner: &AccountId, user_id: EntityId) -> Result<(), RbacError>;
+
+    fn user_of_account(owner: &AccountId, account: &AccountId) -> Option<EntityId>;
+
+    fn account_of_user(owner: &AccountId, user_id: EntityId) -> Option<AccountId>;
+
+    fn create_user_claim(
+        owner: &AccountId,
+        user_id: EntityId,
+        account: &AccountId,
+    ) -> Result<(), RbacError>;
+
+    fn create_user_binding(
+        owner: &AccountId,
+        user_id: EntityId,
+        account: &AccountId,
+    ) -> Result<(), RbacError>;
+
+    fn revoke_user_binding(owner: &AccountId, user_id: EntityId) -> Result<(), RbacError>;
+}
+
+/// Defines how disabled entities are handled when resolving permissions
+#[derive(Clone, Copy, PartialEq, Eq, TypeInfo, Decode, Encode, RuntimeDebug)]
+pub enum DisabledEntityPolicy {
+    /// Disabled roles, groups and permissions are left out
+    Skip,
+    /// Resolution fails with `EntityDisabled`
+    Reject,
 }
 
+#[derive(Clone, Copy)]
 pub enum Tag {
     Role,
     Group,
@@ -166,6 +359,13 @@ pub enum Tag {
     User2Group,
     Permission,
     Permission2Role,
+    Role2Role,
+    Group2Group,
+    RoleUsers,
+    GroupUsers,
+    RoleGroups,
+    PermissionRoles,
+    User,
 }
 
 impl Tag {
@@ -178,6 +378,13 @@ impl Tag {
             Self::User2Group => "U2G",
             Self::Permission => "Permission",
             Self::Permission2Role => "P2R",
+            Self::Role2Role => "R2R",
+            Self::Group2Group => "G2G",
+            Self::RoleUsers => "RU",
+            Self::GroupUsers => "GU",
+            Self::RoleGroups => "RG",
+            Self::PermissionRoles => "PR",
+            Self::User => "User",
         }
     }
 }
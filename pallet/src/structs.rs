@@ -19,9 +19,31 @@ pub struct Entity<EntityId> {
 #[derive(
     Clone, PartialEq, Eq, PartialOrd, Ord, Default, TypeInfo, Decode, Encode, RuntimeDebug,
 )]
-pub struct Role2User<EntityId> {
+pub struct Role2User<EntityId, BlockNumber> {
     pub role: EntityId,
     pub user: EntityId,
+    pub expires_at: Option<BlockNumber>,
+}
+
+/// Assignment of a role to a user before expiring assignments, kept for version 1 of the
+/// runtime API
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Clone, PartialEq, Eq, PartialOrd, Ord, Default, TypeInfo, Decode, Encode, RuntimeDebug,
+)]
+pub struct LegacyRole2User<EntityId> {
+    pub role: EntityId,
+    pub user: EntityId,
+}
+
+impl<EntityId, BlockNumber> From<LegacyRole2User<EntityId>> for Role2User<EntityId, BlockNumber> {
+    fn from(v: LegacyRole2User<EntityId>) -> Self {
+        Role2User {
+            role: v.role,
+            user: v.user,
+            expires_at: None,
+        }
+    }
 }
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -37,11 +59,33 @@ pub struct Role2Group<EntityId> {
 #[derive(
     Clone, PartialEq, Eq, PartialOrd, Ord, Default, TypeInfo, Decode, Encode, RuntimeDebug,
 )]
-pub struct User2Group<EntityId> {
+pub struct User2Group<EntityId, BlockNumber> {
+    pub user: EntityId,
+    pub group: EntityId,
+    pub expires_at: Option<BlockNumber>,
+}
+
+/// Assignment of a user to a group before expiring assignments, kept for version 1 of the
+/// runtime API
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Clone, PartialEq, Eq, PartialOrd, Ord, Default, TypeInfo, Decode, Encode, RuntimeDebug,
+)]
+pub struct LegacyUser2Group<EntityId> {
     pub user: EntityId,
     pub group: EntityId,
 }
 
+impl<EntityId, BlockNumber> From<LegacyUser2Group<EntityId>> for User2Group<EntityId, BlockNumber> {
+    fn from(v: LegacyUser2Group<EntityId>) -> Self {
+        User2Group {
+            user: v.user,
+            group: v.group,
+            expires_at: None,
+        }
+    }
+}
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(
     Clone, PartialEq, Eq, PartialOrd, Ord, Default, TypeInfo, Decode, Encode, RuntimeDebug,
@@ -50,3 +94,291 @@ pub struct Permission2Role<EntityId> {
     pub permission: EntityId,
     pub role: EntityId,
 }
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Clone, PartialEq, Eq, PartialOrd, Ord, Default, TypeInfo, Decode, Encode, RuntimeDebug,
+)]
+pub struct Role2Role<EntityId> {
+    pub role: EntityId,
+    pub inherited_role: EntityId,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Clone, PartialEq, Eq, PartialOrd, Ord, Default, TypeInfo, Decode, Encode, RuntimeDebug,
+)]
+pub struct Group2Group<EntityId> {
+    pub group: EntityId,
+    pub parent: EntityId,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, TypeInfo, Decode, Encode, RuntimeDebug,
+)]
+pub enum ConstraintKind {
+    #[default]
+    Static,
+    Dynamic,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Clone, PartialEq, Eq, PartialOrd, Ord, Default, TypeInfo, Decode, Encode, RuntimeDebug,
+)]
+pub struct RoleConstraint<EntityId> {
+    pub id: EntityId,
+    pub kind: ConstraintKind,
+    pub roles: Vec<EntityId>,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, TypeInfo, Decode, Encode, RuntimeDebug)]
+pub enum Assignment<EntityId> {
+    Role2User { role: EntityId, user: EntityId },
+    User2Group { user: EntityId, group: EntityId },
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Clone, PartialEq, Eq, PartialOrd, Ord, Default, TypeInfo, Decode, Encode, RuntimeDebug,
+)]
+pub struct PermissionGrant<EntityId> {
+    pub groups: Vec<EntityId>,
+    pub roles: Vec<EntityId>,
+}
+
+/// Snapshot of the entities and assignments of an owner's domain, each list sorted.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Clone, PartialEq, Eq, PartialOrd, Ord, Default, TypeInfo, Decode, Encode, RuntimeDebug,
+)]
+pub struct RbacDomain<EntityId, BlockNumber> {
+    pub roles: Vec<Entity<EntityId>>,
+    pub permissions: Vec<Entity<EntityId>>,
+    pub groups: Vec<Entity<EntityId>>,
+    pub users: Vec<Entity<EntityId>>,
+    pub role_users: Vec<Role2User<EntityId, BlockNumber>>,
+    pub group_users: Vec<User2Group<EntityId, BlockNumber>>,
+    pub group_roles: Vec<Role2Group<EntityId>>,
+    pub role_permissions: Vec<Permission2Role<EntityId>>,
+    pub role_roles: Vec<Role2Role<EntityId>>,
+    pub group_groups: Vec<Group2Group<EntityId>>,
+    pub constraints: Vec<RoleConstraint<EntityId>>,
+}
+
+impl<EntityId, BlockNumber> RbacDomain<EntityId, BlockNumber> {
+    /// Number of entities, assignments and constraints of the domain.
+    pub fn item_count(&self) -> u32 {
+        (self.roles.len()
+            + self.permissions.len()
+            + self.groups.len()
+            + self.users.len()
+            + self.role_users.len()
+            + self.group_users.len()
+            + self.group_roles.len()
+            + self.role_permissions.len()
+            + self.role_roles.len()
+            + self.group_groups.len()
+            + self.constraints.len()) as u32
+    }
+}
+
+/// Handling of entities, assignments and constraints of an imported domain, which already
+/// exist within the owner's domain.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, TypeInfo, Decode, Encode, RuntimeDebug,
+)]
+pub enum ImportMode {
+    /// The import fails
+    #[default]
+    Fail,
+    /// Existing ones are kept as they are
+    Skip,
+    /// Existing ones are replaced by the imported ones
+    Overwrite,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, TypeInfo, Decode, Encode, RuntimeDebug,
+)]
+pub struct Capabilities {
+    pub manage_users: bool,
+    pub manage_roles: bool,
+    pub manage_permissions: bool,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq, TypeInfo, Decode, Encode, RuntimeDebug)]
+pub enum Capability {
+    ManageUsers,
+    ManageRoles,
+    ManagePermissions,
+}
+
+impl Capabilities {
+    pub fn allows(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::ManageUsers => self.manage_users,
+            Capability::ManageRoles => self.manage_roles,
+            Capability::ManagePermissions => self.manage_permissions,
+        }
+    }
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, TypeInfo, Decode, Encode, RuntimeDebug,
+)]
+pub enum TransferStage {
+    #[default]
+    Proposed,
+    Roles,
+    Permissions,
+    Groups,
+    Users,
+    Delegates,
+    Bindings,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Clone, PartialEq, Eq, PartialOrd, Ord, Default, TypeInfo, Decode, Encode, RuntimeDebug,
+)]
+pub struct OwnershipTransfer<AccountId> {
+    pub new_owner: AccountId,
+    pub stage: TransferStage,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, TypeInfo, Decode, Encode, RuntimeDebug)]
+pub enum RbacOp<EntityId, BlockNumber> {
+    AddRole {
+        role: EntityId,
+        name: Vec<u8>,
+    },
+    UpdateRole {
+        role: EntityId,
+        name: Vec<u8>,
+    },
+    DisableRole {
+        role: EntityId,
+    },
+    AddPermission {
+        permission: EntityId,
+        name: Vec<u8>,
+    },
+    UpdatePermission {
+        permission: EntityId,
+        name: Vec<u8>,
+    },
+    DisablePermission {
+        permission: EntityId,
+    },
+    AddGroup {
+        group: EntityId,
+        name: Vec<u8>,
+    },
+    UpdateGroup {
+        group: EntityId,
+        name: Vec<u8>,
+    },
+    DisableGroup {
+        group: EntityId,
+    },
+    AssignRoleToUser {
+        role: EntityId,
+        user: EntityId,
+        expires_at: Option<BlockNumber>,
+    },
+    UnassignRoleToUser {
+        role: EntityId,
+        user: EntityId,
+    },
+    AssignPermissionToRole {
+        permission: EntityId,
+        role: EntityId,
+    },
+    UnassignPermissionToRole {
+        permission: EntityId,
+        role: EntityId,
+    },
+    AssignRoleToGroup {
+        role: EntityId,
+        group: EntityId,
+    },
+    UnassignRoleToGroup {
+        role: EntityId,
+        group: EntityId,
+    },
+    AssignUserToGroup {
+        user: EntityId,
+        group: EntityId,
+        expires_at: Option<BlockNumber>,
+    },
+    UnassignUserToGroup {
+        user: EntityId,
+        group: EntityId,
+    },
+    AssignRoleToRole {
+        role: EntityId,
+        inherited_role: EntityId,
+    },
+    UnassignRoleToRole {
+        role: EntityId,
+        inherited_role: EntityId,
+    },
+    AssignGroupToGroup {
+        group: EntityId,
+        parent: EntityId,
+    },
+    UnassignGroupToGroup {
+        group: EntityId,
+        parent: EntityId,
+    },
+    AddUser {
+        user: EntityId,
+        name: Vec<u8>,
+    },
+    UpdateUser {
+        user: EntityId,
+        name: Vec<u8>,
+    },
+    DisableUser {
+        user: EntityId,
+    },
+}
+
+impl<EntityId, BlockNumber> RbacOp<EntityId, BlockNumber> {
+    pub fn capability(&self) -> Capability {
+        match self {
+            RbacOp::AddRole { .. }
+            | RbacOp::UpdateRole { .. }
+            | RbacOp::DisableRole { .. }
+            | RbacOp::AssignRoleToGroup { .. }
+            | RbacOp::UnassignRoleToGroup { .. }
+            | RbacOp::AssignRoleToRole { .. }
+            | RbacOp::UnassignRoleToRole { .. } => Capability::ManageRoles,
+            RbacOp::AddPermission { .. }
+            | RbacOp::UpdatePermission { .. }
+            | RbacOp::DisablePermission { .. }
+            | RbacOp::AssignPermissionToRole { .. }
+            | RbacOp::UnassignPermissionToRole { .. } => Capability::ManagePermissions,
+            RbacOp::AddGroup { .. }
+            | RbacOp::UpdateGroup { .. }
+            | RbacOp::DisableGroup { .. }
+            | RbacOp::AssignRoleToUser { .. }
+            | RbacOp::UnassignRoleToUser { .. }
+            | RbacOp::AssignUserToGroup { .. }
+            | RbacOp::UnassignUserToGroup { .. }
+            | RbacOp::AssignGroupToGroup { .. }
+            | RbacOp::UnassignGroupToGroup { .. }
+            | RbacOp::AddUser { .. }
+            | RbacOp::UpdateUser { .. }
+            | RbacOp::DisableUser { .. } => Capability::ManageUsers,
+        }
+    }
+}
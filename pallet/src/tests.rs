@@ -1,5 +1,46 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+    error::RbacErrorType,
+    migrations::MigrateToV3,
+    mock::*,
+    rbac::{DisabledEntityPolicy, Group, Permission, Rbac, Role, Tag, User},
+    structs::{
+        Assignment, Capabilities, ConstraintKind, Entity, Group2Group, ImportMode, Permission2Role,
+        PermissionGrant, RbacDomain, RbacOp, Role2Group, Role2Role, Role2User, User2Group,
+    },
+    EnsureRbacPermission, Error, Event, Role2UserStore, RoleUsersStore,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+    assert_noop, assert_ok, parameter_types,
+    traits::{EnsureOrigin, Get, GetStorageVersion, Hooks, StorageVersion},
+    weights::Weight,
+    BoundedVec,
+};
+use sp_core::sr25519;
+
+/// Like `assert_ok!` for deprecated fetch calls, which are rejected without the
+/// `legacy-fetch-calls` feature.
+macro_rules! assert_fetch_ok {
+    ($call:expr) => {
+        if cfg!(feature = "legacy-fetch-calls") {
+            assert_ok!($call);
+        } else {
+            assert_noop!($call, Error::<Test>::LegacyFetchCallsDisabled);
+        }
+    };
+}
+
+/// Like `assert_noop!` for deprecated fetch calls, which are rejected without the
+/// `legacy-fetch-calls` feature.
+macro_rules! assert_fetch_noop {
+    ($call:expr, $err:expr) => {
+        if cfg!(feature = "legacy-fetch-calls") {
+            assert_noop!($call, $err);
+        } else {
+            assert_noop!($call, Error::<Test>::LegacyFetchCallsDisabled);
+        }
+    };
+}
 
 #[test]
 fn add_role_test() {
@@ -10,21 +51,21 @@ fn add_role_test() {
         let name = b"ADMIN";
 
         assert_ok!(PeaqRBAC::add_role(
-            RuntimeOrigin::signed(origin),
+            RuntimeOrigin::signed(origin), origin,
             role_id,
             name.to_vec(),
         ));
 
         // Test for duplicate entry
         assert_noop!(
-            PeaqRBAC::add_role(RuntimeOrigin::signed(origin), role_id, name.to_vec(),),
+            PeaqRBAC::add_role(RuntimeOrigin::signed(origin), origin, role_id, name.to_vec(),),
             Error::<Test>::EntityAlreadyExist
         );
 
         // Test name more than 64 chars
         let name = b"ADMINADMINADMINADMINADMINADMINADMINADMINADMINADMINADMINADMINADMINADMINADMINADMINADMINADMINADMINADMINADMINADMIN";
         assert_noop!(
-            PeaqRBAC::add_role(RuntimeOrigin::signed(origin), role_id, name.to_vec(),),
+            PeaqRBAC::add_role(RuntimeOrigin::signed(origin), origin, role_id, name.to_vec(),),
             Error::<Test>::EntityNameExceedMax64
         );
     });
@@ -42,6 +83,7 @@ fn update_role_test() {
 
         assert_ok!(PeaqRBAC::add_role(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             name.to_vec(),
         ));
@@ -49,12 +91,18 @@ fn update_role_test() {
         // Test for updating role not owned by origin
         let name = b"CAN_UPDATE";
         assert_noop!(
-            PeaqRBAC::update_role(RuntimeOrigin::signed(origin2), role_id, name.to_vec()),
+            PeaqRBAC::update_role(
+                RuntimeOrigin::signed(origin2),
+                origin2,
+                role_id,
+                name.to_vec()
+            ),
             Error::<Test>::EntityDoesNotExist
         );
 
         assert_ok!(PeaqRBAC::update_role(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             name.to_vec()
         ));
@@ -62,7 +110,12 @@ fn update_role_test() {
         // Test for removal of non-existing role
         let role_id = *b"22676474666576474646673646376638";
         assert_noop!(
-            PeaqRBAC::update_role(RuntimeOrigin::signed(origin), role_id, name.to_vec()),
+            PeaqRBAC::update_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                name.to_vec()
+            ),
             Error::<Test>::EntityDoesNotExist
         );
     });
@@ -80,24 +133,26 @@ fn disable_role_test() {
 
         assert_ok!(PeaqRBAC::add_role(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             name.to_vec(),
         ));
 
         // Test for removal of role not owned by origin
         assert_noop!(
-            PeaqRBAC::disable_role(RuntimeOrigin::signed(origin2), role_id),
+            PeaqRBAC::disable_role(RuntimeOrigin::signed(origin2), origin2, role_id),
             Error::<Test>::EntityDoesNotExist
         );
 
         assert_ok!(PeaqRBAC::disable_role(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
         ));
 
         // Test for removal of non-existing role
         assert_noop!(
-            PeaqRBAC::disable_role(RuntimeOrigin::signed(origin), role_id),
+            PeaqRBAC::disable_role(RuntimeOrigin::signed(origin), origin, role_id),
             Error::<Test>::EntityDisabled
         );
     });
@@ -113,11 +168,12 @@ fn fetch_role_test() {
 
         assert_ok!(PeaqRBAC::add_role(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             name.to_vec(),
         ));
 
-        assert_ok!(PeaqRBAC::fetch_role(
+        assert_fetch_ok!(PeaqRBAC::fetch_role(
             RuntimeOrigin::signed(origin),
             origin,
             role_id,
@@ -125,13 +181,33 @@ fn fetch_role_test() {
 
         // Test for fetching non-existing role
         let role_id = *b"23676474666576474646673646376638";
-        assert_noop!(
+        assert_fetch_noop!(
             PeaqRBAC::fetch_role(RuntimeOrigin::signed(origin), origin, role_id),
             Error::<Test>::EntityDoesNotExist
         );
     });
 }
 
+#[test]
+#[cfg(not(feature = "legacy-fetch-calls"))]
+fn fetch_calls_disabled_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let role_id = *b"23676474666576474646673646376637";
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            b"ADMIN".to_vec(),
+        ));
+        assert_noop!(
+            PeaqRBAC::fetch_role(RuntimeOrigin::signed(origin), origin, role_id),
+            Error::<Test>::LegacyFetchCallsDisabled
+        );
+    });
+}
+
 #[test]
 fn fetch_roles_test() {
     new_test_ext().execute_with(|| {
@@ -143,16 +219,18 @@ fn fetch_roles_test() {
 
         assert_ok!(PeaqRBAC::add_role(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             name.to_vec(),
         ));
         assert_ok!(PeaqRBAC::add_role(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id2,
             name.to_vec(),
         ));
 
-        assert_ok!(PeaqRBAC::fetch_roles(RuntimeOrigin::signed(origin), origin));
+        assert_fetch_ok!(PeaqRBAC::fetch_roles(RuntimeOrigin::signed(origin), origin));
     });
 }
 
@@ -169,32 +247,53 @@ fn assign_role_to_user_test() {
 
         assert_ok!(PeaqRBAC::add_role(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             name.to_vec(),
         ));
 
         // Test for assigning role not owned by origin
         assert_noop!(
-            PeaqRBAC::assign_role_to_user(RuntimeOrigin::signed(origin2), role_id, user_id),
+            PeaqRBAC::assign_role_to_user(
+                RuntimeOrigin::signed(origin2),
+                origin2,
+                role_id,
+                user_id,
+                None
+            ),
             Error::<Test>::EntityDoesNotExist
         );
 
         assert_ok!(PeaqRBAC::assign_role_to_user(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
-            user_id
+            user_id,
+            None
         ));
 
         // Test for duplicate entry
         assert_noop!(
-            PeaqRBAC::assign_role_to_user(RuntimeOrigin::signed(origin), role_id, user_id),
+            PeaqRBAC::assign_role_to_user(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                user_id,
+                None
+            ),
             Error::<Test>::AssignmentAlreadyExist
         );
 
         // Test for assigning non-existing role
         let role_id = *b"24676474666576474646673646376638";
         assert_noop!(
-            PeaqRBAC::assign_role_to_user(RuntimeOrigin::signed(origin), role_id, user_id),
+            PeaqRBAC::assign_role_to_user(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                user_id,
+                None
+            ),
             Error::<Test>::EntityDoesNotExist
         );
     });
@@ -213,31 +312,45 @@ fn unassign_role_to_user_test() {
 
         assert_ok!(PeaqRBAC::add_role(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::assign_role_to_user(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
-            user_id
+            user_id,
+            None
         ));
 
         // Test for removing role not owned by origin
         assert_noop!(
-            PeaqRBAC::unassign_role_to_user(RuntimeOrigin::signed(origin2), role_id, user_id),
+            PeaqRBAC::unassign_role_to_user(
+                RuntimeOrigin::signed(origin2),
+                origin2,
+                role_id,
+                user_id
+            ),
             Error::<Test>::AssignmentDoesNotExist
         );
 
         assert_ok!(PeaqRBAC::unassign_role_to_user(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             user_id
         ));
 
         // Test for removing non-existing role
         assert_noop!(
-            PeaqRBAC::unassign_role_to_user(RuntimeOrigin::signed(origin), role_id, user_id),
+            PeaqRBAC::unassign_role_to_user(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                user_id
+            ),
             Error::<Test>::AssignmentDoesNotExist
         );
     });
@@ -257,57 +370,86 @@ fn assign_role_to_group_test() {
 
         assert_ok!(PeaqRBAC::add_role(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::add_group(
             RuntimeOrigin::signed(origin),
+            origin,
             group_id,
             name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::add_group(
             RuntimeOrigin::signed(origin2),
+            origin2,
             group_id2,
             name.to_vec(),
         ));
 
         // Test for assigning role not owned by origin
         assert_noop!(
-            PeaqRBAC::assign_role_to_group(RuntimeOrigin::signed(origin2), role_id, group_id),
+            PeaqRBAC::assign_role_to_group(
+                RuntimeOrigin::signed(origin2),
+                origin2,
+                role_id,
+                group_id
+            ),
             Error::<Test>::EntityDoesNotExist
         );
 
         // Test for assigning group not owned by origin
         assert_noop!(
-            PeaqRBAC::assign_role_to_group(RuntimeOrigin::signed(origin), role_id, group_id2),
+            PeaqRBAC::assign_role_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                group_id2
+            ),
             Error::<Test>::EntityDoesNotExist
         );
 
         assert_ok!(PeaqRBAC::assign_role_to_group(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             group_id
         ));
 
         // Test for duplicate entry
         assert_noop!(
-            PeaqRBAC::assign_role_to_group(RuntimeOrigin::signed(origin), role_id, group_id),
+            PeaqRBAC::assign_role_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                group_id
+            ),
             Error::<Test>::AssignmentAlreadyExist
         );
 
         // Test for assigning non-existing group
         let group_id = *b"73646647466673646376637126765765";
         assert_noop!(
-            PeaqRBAC::assign_role_to_group(RuntimeOrigin::signed(origin), role_id, group_id),
+            PeaqRBAC::assign_role_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                group_id
+            ),
             Error::<Test>::EntityDoesNotExist
         );
 
         // Test for assigning non-existing role
         let role_id = *b"57764746665764746462673646376638";
         assert_noop!(
-            PeaqRBAC::assign_role_to_group(RuntimeOrigin::signed(origin), role_id, group_id),
+            PeaqRBAC::assign_role_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                group_id
+            ),
             Error::<Test>::EntityDoesNotExist
         );
     });
@@ -326,37 +468,51 @@ fn unassign_role_to_group_test() {
 
         assert_ok!(PeaqRBAC::add_role(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::add_group(
             RuntimeOrigin::signed(origin),
+            origin,
             group_id,
             name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::assign_role_to_group(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             group_id
         ));
 
         // Test for removing role not owned by origin
         assert_noop!(
-            PeaqRBAC::unassign_role_to_group(RuntimeOrigin::signed(origin2), role_id, group_id),
+            PeaqRBAC::unassign_role_to_group(
+                RuntimeOrigin::signed(origin2),
+                origin2,
+                role_id,
+                group_id
+            ),
             Error::<Test>::AssignmentDoesNotExist
         );
 
         assert_ok!(PeaqRBAC::unassign_role_to_group(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             group_id
         ));
 
         // Test for removing non-existing role
         assert_noop!(
-            PeaqRBAC::unassign_role_to_group(RuntimeOrigin::signed(origin), role_id, group_id),
+            PeaqRBAC::unassign_role_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                group_id
+            ),
             Error::<Test>::AssignmentDoesNotExist
         );
     });
@@ -373,17 +529,20 @@ fn fetch_user_roles_test() {
 
         assert_ok!(PeaqRBAC::add_role(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::assign_role_to_user(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
-            user_id
+            user_id,
+            None
         ));
 
-        assert_ok!(PeaqRBAC::fetch_user_roles(
+        assert_fetch_ok!(PeaqRBAC::fetch_user_roles(
             RuntimeOrigin::signed(origin),
             origin,
             user_id
@@ -391,7 +550,7 @@ fn fetch_user_roles_test() {
 
         // Test for non-existing role to user relationship
         let user_id = *b"15676474666576474646673646376637";
-        assert_noop!(
+        assert_fetch_noop!(
             PeaqRBAC::fetch_user_roles(RuntimeOrigin::signed(origin), origin, user_id),
             Error::<Test>::AssignmentDoesNotExist
         );
@@ -407,21 +566,21 @@ fn add_permission_test() {
         let name = b"CAN_DELETE";
 
         assert_ok!(PeaqRBAC::add_permission(
-            RuntimeOrigin::signed(origin),
+            RuntimeOrigin::signed(origin), origin,
             permission_id,
             name.to_vec(),
         ));
 
         // Test for duplicate entry
         assert_noop!(
-            PeaqRBAC::add_permission(RuntimeOrigin::signed(origin), permission_id, name.to_vec(),),
+            PeaqRBAC::add_permission(RuntimeOrigin::signed(origin), origin, permission_id, name.to_vec(),),
             Error::<Test>::EntityAlreadyExist
         );
 
         // Test name more than 64 chars
         let name = b"CAN_DELETECAN_DELETECAN_DELETECAN_DELETECAN_DELETECAN_DELETECAN_DELETECAN_DELETECAN_DELETECAN_DELETE";
         assert_noop!(
-            PeaqRBAC::add_permission(RuntimeOrigin::signed(origin), permission_id, name.to_vec(),),
+            PeaqRBAC::add_permission(RuntimeOrigin::signed(origin), origin, permission_id, name.to_vec(),),
             Error::<Test>::EntityNameExceedMax64
         );
     });
@@ -439,6 +598,7 @@ fn update_permission_test() {
 
         assert_ok!(PeaqRBAC::add_permission(
             RuntimeOrigin::signed(origin),
+            origin,
             permission_id,
             name.to_vec(),
         ));
@@ -448,6 +608,7 @@ fn update_permission_test() {
         assert_noop!(
             PeaqRBAC::update_permission(
                 RuntimeOrigin::signed(origin2),
+                origin2,
                 permission_id,
                 name.to_vec()
             ),
@@ -456,6 +617,7 @@ fn update_permission_test() {
 
         assert_ok!(PeaqRBAC::update_permission(
             RuntimeOrigin::signed(origin),
+            origin,
             permission_id,
             name.to_vec()
         ));
@@ -465,6 +627,7 @@ fn update_permission_test() {
         assert_noop!(
             PeaqRBAC::update_permission(
                 RuntimeOrigin::signed(origin),
+                origin,
                 permission_id,
                 name.to_vec()
             ),
@@ -485,24 +648,26 @@ fn disable_permission_test() {
 
         assert_ok!(PeaqRBAC::add_permission(
             RuntimeOrigin::signed(origin),
+            origin,
             permission_id,
             name.to_vec(),
         ));
 
         // Test for removal of permission not owned by origin
         assert_noop!(
-            PeaqRBAC::disable_permission(RuntimeOrigin::signed(origin2), permission_id),
+            PeaqRBAC::disable_permission(RuntimeOrigin::signed(origin2), origin2, permission_id),
             Error::<Test>::EntityDoesNotExist
         );
 
         assert_ok!(PeaqRBAC::disable_permission(
             RuntimeOrigin::signed(origin),
+            origin,
             permission_id,
         ));
 
         // Test for removal of non-existing permission
         assert_noop!(
-            PeaqRBAC::disable_permission(RuntimeOrigin::signed(origin), permission_id),
+            PeaqRBAC::disable_permission(RuntimeOrigin::signed(origin), origin, permission_id),
             Error::<Test>::EntityDisabled
         );
     });
@@ -518,11 +683,12 @@ fn fetch_permission_test() {
 
         assert_ok!(PeaqRBAC::add_permission(
             RuntimeOrigin::signed(origin),
+            origin,
             permission_id,
             name.to_vec(),
         ));
 
-        assert_ok!(PeaqRBAC::fetch_permission(
+        assert_fetch_ok!(PeaqRBAC::fetch_permission(
             RuntimeOrigin::signed(origin),
             origin,
             permission_id,
@@ -530,7 +696,7 @@ fn fetch_permission_test() {
 
         // Test for fetching non-existing permission
         let permission_id = *b"44464667364637663721676474666577";
-        assert_noop!(
+        assert_fetch_noop!(
             PeaqRBAC::fetch_permission(RuntimeOrigin::signed(origin), origin, permission_id),
             Error::<Test>::EntityDoesNotExist
         );
@@ -548,16 +714,18 @@ fn fetch_permissions_test() {
 
         assert_ok!(PeaqRBAC::add_permission(
             RuntimeOrigin::signed(origin),
+            origin,
             permission_id,
             name.to_vec(),
         ));
         assert_ok!(PeaqRBAC::add_permission(
             RuntimeOrigin::signed(origin),
+            origin,
             permission_id2,
             name.to_vec(),
         ));
 
-        assert_ok!(PeaqRBAC::fetch_permissions(
+        assert_fetch_ok!(PeaqRBAC::fetch_permissions(
             RuntimeOrigin::signed(origin),
             origin
         ));
@@ -578,12 +746,14 @@ fn assign_permission_to_role_test() {
 
         assert_ok!(PeaqRBAC::add_role(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             role_name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::add_permission(
             RuntimeOrigin::signed(origin),
+            origin,
             permission_id,
             name.to_vec(),
         ));
@@ -592,6 +762,7 @@ fn assign_permission_to_role_test() {
         assert_noop!(
             PeaqRBAC::assign_permission_to_role(
                 RuntimeOrigin::signed(origin2),
+                origin2,
                 permission_id,
                 role_id
             ),
@@ -600,6 +771,7 @@ fn assign_permission_to_role_test() {
 
         assert_ok!(PeaqRBAC::assign_permission_to_role(
             RuntimeOrigin::signed(origin),
+            origin,
             permission_id,
             role_id
         ));
@@ -608,6 +780,7 @@ fn assign_permission_to_role_test() {
         assert_noop!(
             PeaqRBAC::assign_permission_to_role(
                 RuntimeOrigin::signed(origin),
+                origin,
                 permission_id,
                 role_id
             ),
@@ -619,6 +792,7 @@ fn assign_permission_to_role_test() {
         assert_noop!(
             PeaqRBAC::assign_permission_to_role(
                 RuntimeOrigin::signed(origin),
+                origin,
                 permission_id,
                 role_id
             ),
@@ -641,18 +815,21 @@ fn unassign_permission_to_role_test() {
 
         assert_ok!(PeaqRBAC::add_role(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             role_name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::add_permission(
             RuntimeOrigin::signed(origin),
+            origin,
             permission_id,
             name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::assign_permission_to_role(
             RuntimeOrigin::signed(origin),
+            origin,
             permission_id,
             role_id
         ));
@@ -661,6 +838,7 @@ fn unassign_permission_to_role_test() {
         assert_noop!(
             PeaqRBAC::unassign_permission_to_role(
                 RuntimeOrigin::signed(origin2),
+                origin2,
                 permission_id,
                 role_id
             ),
@@ -669,6 +847,7 @@ fn unassign_permission_to_role_test() {
 
         assert_ok!(PeaqRBAC::unassign_permission_to_role(
             RuntimeOrigin::signed(origin),
+            origin,
             permission_id,
             role_id,
         ));
@@ -677,6 +856,7 @@ fn unassign_permission_to_role_test() {
         assert_noop!(
             PeaqRBAC::unassign_permission_to_role(
                 RuntimeOrigin::signed(origin),
+                origin,
                 permission_id,
                 role_id,
             ),
@@ -697,23 +877,26 @@ fn fetch_role_permissions_test() {
 
         assert_ok!(PeaqRBAC::add_role(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             role_name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::add_permission(
             RuntimeOrigin::signed(origin),
+            origin,
             permission_id,
             name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::assign_permission_to_role(
             RuntimeOrigin::signed(origin),
+            origin,
             permission_id,
             role_id
         ));
 
-        assert_ok!(PeaqRBAC::fetch_role_permissions(
+        assert_fetch_ok!(PeaqRBAC::fetch_role_permissions(
             RuntimeOrigin::signed(origin),
             origin,
             role_id
@@ -721,7 +904,7 @@ fn fetch_role_permissions_test() {
 
         // Test for non-existing permission to role relationship
         let role_id = *b"15464667364637663721676474666577";
-        assert_noop!(
+        assert_fetch_noop!(
             PeaqRBAC::fetch_role_permissions(RuntimeOrigin::signed(origin), origin, role_id),
             Error::<Test>::AssignmentDoesNotExist
         );
@@ -737,21 +920,21 @@ fn add_group_test() {
         let name = b"Users";
 
         assert_ok!(PeaqRBAC::add_group(
-            RuntimeOrigin::signed(origin),
+            RuntimeOrigin::signed(origin), origin,
             group_id,
             name.to_vec(),
         ));
 
         // Test for duplicate entry
         assert_noop!(
-            PeaqRBAC::add_group(RuntimeOrigin::signed(origin), group_id, name.to_vec(),),
+            PeaqRBAC::add_group(RuntimeOrigin::signed(origin), origin, group_id, name.to_vec(),),
             Error::<Test>::EntityAlreadyExist
         );
 
         // Test name more than 64 chars
         let name = b"UsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsersUsers";
         assert_noop!(
-            PeaqRBAC::add_group(RuntimeOrigin::signed(origin), group_id, name.to_vec(),),
+            PeaqRBAC::add_group(RuntimeOrigin::signed(origin), origin, group_id, name.to_vec(),),
             Error::<Test>::EntityNameExceedMax64
         );
     });
@@ -769,6 +952,7 @@ fn update_group_test() {
 
         assert_ok!(PeaqRBAC::add_group(
             RuntimeOrigin::signed(origin),
+            origin,
             group_id,
             name.to_vec(),
         ));
@@ -776,12 +960,18 @@ fn update_group_test() {
         // Test for updating group not owned by origin
         let name = b"Admins";
         assert_noop!(
-            PeaqRBAC::update_group(RuntimeOrigin::signed(origin2), group_id, name.to_vec()),
+            PeaqRBAC::update_group(
+                RuntimeOrigin::signed(origin2),
+                origin2,
+                group_id,
+                name.to_vec()
+            ),
             Error::<Test>::EntityDoesNotExist
         );
 
         assert_ok!(PeaqRBAC::update_group(
             RuntimeOrigin::signed(origin),
+            origin,
             group_id,
             name.to_vec()
         ));
@@ -789,7 +979,12 @@ fn update_group_test() {
         // Test for removal of non-existing group
         let group_id = *b"12663776474646673646665421676477";
         assert_noop!(
-            PeaqRBAC::update_group(RuntimeOrigin::signed(origin), group_id, name.to_vec()),
+            PeaqRBAC::update_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                group_id,
+                name.to_vec()
+            ),
             Error::<Test>::EntityDoesNotExist
         );
     });
@@ -807,24 +1002,26 @@ fn disable_group_test() {
 
         assert_ok!(PeaqRBAC::add_group(
             RuntimeOrigin::signed(origin),
+            origin,
             group_id,
             name.to_vec(),
         ));
 
         // Test for removal of group not owned by origin
         assert_noop!(
-            PeaqRBAC::disable_group(RuntimeOrigin::signed(origin2), group_id),
+            PeaqRBAC::disable_group(RuntimeOrigin::signed(origin2), origin2, group_id),
             Error::<Test>::EntityDoesNotExist
         );
 
         assert_ok!(PeaqRBAC::disable_group(
             RuntimeOrigin::signed(origin),
+            origin,
             group_id,
         ));
 
         // Test for removal of non-existing group
         assert_noop!(
-            PeaqRBAC::disable_group(RuntimeOrigin::signed(origin), group_id),
+            PeaqRBAC::disable_group(RuntimeOrigin::signed(origin), origin, group_id),
             Error::<Test>::EntityDisabled
         );
     });
@@ -840,11 +1037,12 @@ fn fetch_group_test() {
 
         assert_ok!(PeaqRBAC::add_group(
             RuntimeOrigin::signed(origin),
+            origin,
             group_id,
             name.to_vec(),
         ));
 
-        assert_ok!(PeaqRBAC::fetch_group(
+        assert_fetch_ok!(PeaqRBAC::fetch_group(
             RuntimeOrigin::signed(origin),
             origin,
             group_id,
@@ -852,7 +1050,7 @@ fn fetch_group_test() {
 
         // Test for fetching non-existing group
         let group_id = *b"14663776474646673646665421676477";
-        assert_noop!(
+        assert_fetch_noop!(
             PeaqRBAC::fetch_group(RuntimeOrigin::signed(origin), origin, group_id),
             Error::<Test>::EntityDoesNotExist
         );
@@ -870,17 +1068,19 @@ fn fetch_groups_test() {
 
         assert_ok!(PeaqRBAC::add_group(
             RuntimeOrigin::signed(origin),
+            origin,
             group_id,
             name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::add_group(
             RuntimeOrigin::signed(origin),
+            origin,
             group_id2,
             name.to_vec(),
         ));
 
-        assert_ok!(PeaqRBAC::fetch_groups(
+        assert_fetch_ok!(PeaqRBAC::fetch_groups(
             RuntimeOrigin::signed(origin),
             origin,
         ));
@@ -900,32 +1100,53 @@ fn assign_user_to_group_test() {
 
         assert_ok!(PeaqRBAC::add_group(
             RuntimeOrigin::signed(origin),
+            origin,
             group_id,
             name.to_vec(),
         ));
 
         // Test for assigning group not owned by origin
         assert_noop!(
-            PeaqRBAC::assign_user_to_group(RuntimeOrigin::signed(origin2), user_id, group_id),
+            PeaqRBAC::assign_user_to_group(
+                RuntimeOrigin::signed(origin2),
+                origin2,
+                user_id,
+                group_id,
+                None
+            ),
             Error::<Test>::EntityDoesNotExist
         );
 
         assert_ok!(PeaqRBAC::assign_user_to_group(
             RuntimeOrigin::signed(origin),
+            origin,
             user_id,
-            group_id
+            group_id,
+            None
         ));
 
         // Test for duplicate entry
         assert_noop!(
-            PeaqRBAC::assign_user_to_group(RuntimeOrigin::signed(origin), user_id, group_id),
+            PeaqRBAC::assign_user_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                user_id,
+                group_id,
+                None
+            ),
             Error::<Test>::AssignmentAlreadyExist
         );
 
         // Test for assigning non-existing group relationship
         let group_id = *b"17663776474646673646665421676477";
         assert_noop!(
-            PeaqRBAC::assign_user_to_group(RuntimeOrigin::signed(origin), user_id, group_id),
+            PeaqRBAC::assign_user_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                user_id,
+                group_id,
+                None
+            ),
             Error::<Test>::EntityDoesNotExist
         );
     });
@@ -944,31 +1165,45 @@ fn unassign_user_to_group_test() {
 
         assert_ok!(PeaqRBAC::add_group(
             RuntimeOrigin::signed(origin),
+            origin,
             group_id,
             name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::assign_user_to_group(
             RuntimeOrigin::signed(origin),
+            origin,
             user_id,
-            group_id
+            group_id,
+            None
         ));
 
         // Test for removing group not owned by origin
         assert_noop!(
-            PeaqRBAC::unassign_user_to_group(RuntimeOrigin::signed(origin2), user_id, group_id),
+            PeaqRBAC::unassign_user_to_group(
+                RuntimeOrigin::signed(origin2),
+                origin2,
+                user_id,
+                group_id
+            ),
             Error::<Test>::AssignmentDoesNotExist
         );
 
         assert_ok!(PeaqRBAC::unassign_user_to_group(
             RuntimeOrigin::signed(origin),
+            origin,
             user_id,
             group_id
         ));
 
         // Test for removing non-existing group relationship
         assert_noop!(
-            PeaqRBAC::unassign_user_to_group(RuntimeOrigin::signed(origin), user_id, group_id),
+            PeaqRBAC::unassign_user_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                user_id,
+                group_id
+            ),
             Error::<Test>::AssignmentDoesNotExist
         );
     });
@@ -985,17 +1220,20 @@ fn fetch_user_groups_test() {
 
         assert_ok!(PeaqRBAC::add_group(
             RuntimeOrigin::signed(origin),
+            origin,
             group_id,
             name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::assign_user_to_group(
             RuntimeOrigin::signed(origin),
+            origin,
             user_id,
-            group_id
+            group_id,
+            None
         ));
 
-        assert_ok!(PeaqRBAC::fetch_user_groups(
+        assert_fetch_ok!(PeaqRBAC::fetch_user_groups(
             RuntimeOrigin::signed(origin),
             origin,
             user_id
@@ -1003,7 +1241,7 @@ fn fetch_user_groups_test() {
 
         // Test for non-existing user to group relationship
         let user_id = *b"15676474666576474646673646376637";
-        assert_noop!(
+        assert_fetch_noop!(
             PeaqRBAC::fetch_user_groups(RuntimeOrigin::signed(origin), origin, user_id),
             Error::<Test>::AssignmentDoesNotExist
         );
@@ -1023,41 +1261,48 @@ fn fetch_user_permissions_test() {
 
         assert_ok!(PeaqRBAC::add_role(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::add_permission(
             RuntimeOrigin::signed(origin),
+            origin,
             permission_id,
             name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::add_group(
             RuntimeOrigin::signed(origin),
+            origin,
             group_id,
             name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::assign_user_to_group(
             RuntimeOrigin::signed(origin),
+            origin,
             user_id,
-            group_id
+            group_id,
+            None
         ));
 
         assert_ok!(PeaqRBAC::assign_role_to_group(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             group_id
         ));
 
         assert_ok!(PeaqRBAC::assign_permission_to_role(
             RuntimeOrigin::signed(origin),
+            origin,
             permission_id,
             role_id
         ));
 
-        assert_ok!(PeaqRBAC::fetch_user_permissions(
+        assert_fetch_ok!(PeaqRBAC::fetch_user_permissions(
             RuntimeOrigin::signed(origin),
             origin,
             user_id
@@ -1077,38 +1322,3971 @@ fn fetch_group_permissions_test() {
 
         assert_ok!(PeaqRBAC::add_role(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::add_permission(
             RuntimeOrigin::signed(origin),
+            origin,
             permission_id,
             name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::add_group(
             RuntimeOrigin::signed(origin),
+            origin,
             group_id,
             name.to_vec(),
         ));
 
         assert_ok!(PeaqRBAC::assign_role_to_group(
             RuntimeOrigin::signed(origin),
+            origin,
             role_id,
             group_id
         ));
 
         assert_ok!(PeaqRBAC::assign_permission_to_role(
             RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            role_id
+        ));
+
+        assert_fetch_ok!(PeaqRBAC::fetch_group_permissions(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id
+        ));
+    });
+}
+
+#[test]
+fn has_permission_test() {
+    new_test_ext().execute_with(|| {
+        let acct = "Iredia";
+        let acct2 = "Iredia2";
+        let group_id = *b"66736466618663776474645421676476";
+        let role_id = *b"46454667364666186637764721676476";
+        let role_id2 = *b"46454667364666186637764721676477";
+        let permission_id = *b"76472167646454667364666186637476";
+        let permission_id2 = *b"76472167646454667364666186637477";
+        let user_id = *b"65761367647466474646673646376637";
+        let user_id2 = *b"65761367647466474646673646376638";
+        let origin = account_key(acct);
+        let origin2 = account_key(acct2);
+        let name = b"Admin";
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec(),
+        ));
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id2,
+            name.to_vec(),
+        ));
+
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            name.to_vec(),
+        ));
+
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id2,
+            name.to_vec(),
+        ));
+
+        assert_ok!(PeaqRBAC::add_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            name.to_vec(),
+        ));
+
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
             permission_id,
             role_id
         ));
 
-        assert_ok!(PeaqRBAC::fetch_group_permissions(
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id2,
+            role_id2
+        ));
+
+        // Permission granted through a direct role assignment
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            None
+        ));
+        assert!(PeaqRBAC::has_permission(&origin, user_id, permission_id));
+        assert!(!PeaqRBAC::has_permission(&origin, user_id, permission_id2));
+        assert_eq!(
+            PeaqRBAC::get_permission_grant(&origin, user_id, permission_id),
+            Some(PermissionGrant {
+                groups: vec![],
+                roles: vec![role_id]
+            })
+        );
+
+        // Permission granted through a group
+        assert_ok!(PeaqRBAC::assign_role_to_group(
             RuntimeOrigin::signed(origin),
             origin,
+            role_id2,
             group_id
         ));
+        assert_ok!(PeaqRBAC::assign_user_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id2,
+            group_id,
+            None
+        ));
+        assert!(PeaqRBAC::has_permission(&origin, user_id2, permission_id2));
+        assert!(!PeaqRBAC::has_permission(&origin, user_id2, permission_id));
+        assert_eq!(
+            PeaqRBAC::get_permission_grant(&origin, user_id2, permission_id2),
+            Some(PermissionGrant {
+                groups: vec![group_id],
+                roles: vec![role_id2]
+            })
+        );
+
+        // Test for permission checks against another owner
+        assert!(!PeaqRBAC::has_permission(&origin2, user_id, permission_id));
+
+        // Test for disabled permissions
+        assert_ok!(PeaqRBAC::disable_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id
+        ));
+        assert!(!PeaqRBAC::has_permission(&origin, user_id, permission_id));
+        assert_eq!(
+            PeaqRBAC::get_permission_grant(&origin, user_id, permission_id),
+            None
+        );
+    });
+}
+
+#[test]
+fn check_permission_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let user_id = *b"12676474666576474646673646376637";
+        let user_id2 = *b"12676474666576474646673646376638";
+        let role_id = *b"21676474666576474646673646376637";
+        let permission_id = *b"41664667364637663721676474666576";
+        let permission_id2 = *b"41664667364637663721676474666577";
+        let name = b"ADMIN";
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec()
+        ));
+        for permission in [permission_id, permission_id2] {
+            assert_ok!(PeaqRBAC::add_permission(
+                RuntimeOrigin::signed(origin),
+                origin,
+                permission,
+                name.to_vec()
+            ));
+        }
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            role_id
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            None
+        ));
+
+        assert_eq!(
+            PeaqRBAC::check_permission(&origin, user_id, permission_id),
+            Ok(true)
+        );
+        assert_eq!(
+            PeaqRBAC::check_permission(&origin, user_id2, permission_id),
+            Ok(false)
+        );
+        assert_eq!(
+            PeaqRBAC::check_permission(&origin, user_id, permission_id2),
+            Ok(false)
+        );
+
+        // Unknown and disabled permissions are reported
+        let permission_id3 = *b"41664667364637663721676474666578";
+        assert_eq!(
+            PeaqRBAC::check_permission(&origin, user_id, permission_id3)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::EntityDoesNotExist
+        );
+        assert_ok!(PeaqRBAC::disable_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id
+        ));
+        assert_eq!(
+            PeaqRBAC::check_permission(&origin, user_id, permission_id)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::EntityDisabled
+        );
+    });
+}
+
+#[test]
+fn check_permissions_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let user_id = *b"12676474666576474646673646376637";
+        let user_id2 = *b"12676474666576474646673646376638";
+        let role_id = *b"21676474666576474646673646376637";
+        let permission_id = *b"41664667364637663721676474666576";
+        let permission_id2 = *b"41664667364637663721676474666577";
+        let name = b"ADMIN";
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec()
+        ));
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            name.to_vec()
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            role_id
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            None
+        ));
+
+        // One result per pair in the given order
+        let results = PeaqRBAC::check_permissions(
+            &origin,
+            &[
+                (user_id, permission_id),
+                (user_id2, permission_id),
+                (user_id, permission_id2),
+            ],
+        );
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0], Ok(true));
+        assert_eq!(results[1], Ok(false));
+        assert_eq!(
+            results[2].as_ref().unwrap_err().typ,
+            RbacErrorType::EntityDoesNotExist
+        );
+        assert!(PeaqRBAC::check_permissions(&origin, &[]).is_empty());
+    });
+}
+
+#[test]
+fn paginated_listing_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let user_id = *b"12676474666576474646673646376637";
+        let role_ids = [
+            *b"21676474666576474646673646376631",
+            *b"21676474666576474646673646376632",
+            *b"21676474666576474646673646376633",
+        ];
+        let unknown_id = *b"21676474666576474646673646376639";
+        let name = b"ADMIN";
+
+        // Pages are ordered by id, regardless of the order the roles were added in
+        for role_id in [role_ids[2], role_ids[0], role_ids[1]] {
+            assert_ok!(PeaqRBAC::add_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                name.to_vec()
+            ));
+            assert_ok!(PeaqRBAC::assign_role_to_user(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                user_id,
+                None
+            ));
+        }
+
+        // Page through all roles using the last id of each page as cursor
+        let page = PeaqRBAC::get_roles_page(&origin, None, 2).unwrap();
+        assert_eq!(page.len(), 2);
+        assert_eq!(page[0].id, role_ids[0]);
+        assert_eq!(page[1].id, role_ids[1]);
+        let page = PeaqRBAC::get_roles_page(&origin, Some(page[1].id), 2).unwrap();
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].id, role_ids[2]);
+        assert!(PeaqRBAC::get_roles_page(&origin, Some(role_ids[2]), 2)
+            .unwrap()
+            .is_empty());
+        assert!(PeaqRBAC::get_roles_page(&origin, None, 0)
+            .unwrap()
+            .is_empty());
+
+        // Assignments are paged by their role
+        let page = PeaqRBAC::get_user_roles_page(&origin, user_id, Some(role_ids[0]), 1).unwrap();
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].role, role_ids[1]);
+
+        // Paging resumes after a cursor, which does not exist (anymore)
+        assert_ok!(PeaqRBAC::delete_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_ids[1],
+            1
+        ));
+        let page = PeaqRBAC::get_roles_page(&origin, Some(role_ids[1]), 2).unwrap();
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].id, role_ids[2]);
+        let page = PeaqRBAC::get_user_roles_page(&origin, user_id, Some(role_ids[1]), 2).unwrap();
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].role, role_ids[2]);
+        assert!(PeaqRBAC::get_roles_page(&origin, Some(unknown_id), 2)
+            .unwrap()
+            .is_empty());
+    });
+}
+
+#[test]
+fn reverse_lookup_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let user_id = *b"12676474666576474646673646376637";
+        let user_id2 = *b"12676474666576474646673646376638";
+        let role_id = *b"21676474666576474646673646376637";
+        let permission_id = *b"41664667364637663721676474666576";
+        let group_id = *b"31676474666576474646673646376637";
+        let name = b"ADMIN";
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec()
+        ));
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            name.to_vec()
+        ));
+        assert_ok!(PeaqRBAC::add_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            name.to_vec()
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            role_id
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            group_id
+        ));
+        assert_ok!(PeaqRBAC::assign_user_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id,
+            group_id,
+            None
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id2,
+            None
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            None
+        ));
+
+        // Reverse indexes are sorted like the assignments
+        let users = PeaqRBAC::get_role_users(&origin, role_id).unwrap();
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0].user, user_id);
+        assert_eq!(users[1].user, user_id2);
+        let members = PeaqRBAC::get_group_members(&origin, group_id).unwrap();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].user, user_id);
+        let groups = PeaqRBAC::get_role_groups(&origin, role_id).unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].group, group_id);
+        let roles = PeaqRBAC::get_permission_roles(&origin, permission_id).unwrap();
+        assert_eq!(roles.len(), 1);
+        assert_eq!(roles[0].role, role_id);
+
+        // Revoking an assignment updates the index
+        assert_ok!(PeaqRBAC::unassign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id2
+        ));
+        let users = PeaqRBAC::get_role_users(&origin, role_id).unwrap();
+        assert_eq!(users.len(), 1);
+        assert_eq!(users[0].user, user_id);
+        assert_ok!(PeaqRBAC::unassign_user_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id,
+            group_id
+        ));
+        assert_eq!(
+            PeaqRBAC::get_group_members(&origin, group_id)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::AssignmentDoesNotExist
+        );
+
+        // Deleting the role purges all of its assignments and indexes
+        assert_ok!(PeaqRBAC::delete_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            1
+        ));
+        assert_eq!(
+            PeaqRBAC::get_role_users(&origin, role_id).unwrap_err().typ,
+            RbacErrorType::AssignmentDoesNotExist
+        );
+        assert_eq!(
+            PeaqRBAC::get_role_groups(&origin, role_id).unwrap_err().typ,
+            RbacErrorType::AssignmentDoesNotExist
+        );
+        assert_eq!(
+            PeaqRBAC::get_permission_roles(&origin, permission_id)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::AssignmentDoesNotExist
+        );
+        assert_eq!(
+            PeaqRBAC::get_user_roles(&origin, user_id).unwrap_err().typ,
+            RbacErrorType::AssignmentDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn user_entity_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let user_id = *b"12676474666576474646673646376637";
+        let user_id2 = *b"12676474666576474646673646376638";
+        let role_id = *b"21676474666576474646673646376637";
+        let permission_id = *b"41664667364637663721676474666576";
+        let name = b"ADMIN";
+
+        assert_ok!(PeaqRBAC::add_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id,
+            b"Alice".to_vec()
+        ));
+        assert_noop!(
+            PeaqRBAC::add_user(
+                RuntimeOrigin::signed(origin),
+                origin,
+                user_id,
+                b"Alice".to_vec()
+            ),
+            Error::<Test>::EntityAlreadyExist
+        );
+        assert_ok!(PeaqRBAC::update_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id,
+            b"Bob".to_vec()
+        ));
+        assert_eq!(
+            PeaqRBAC::get_user(&origin, user_id).unwrap().name,
+            b"Bob".to_vec()
+        );
+        assert_eq!(PeaqRBAC::get_users(&origin).unwrap().len(), 1);
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec()
+        ));
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            name.to_vec()
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            role_id
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            None
+        ));
+        assert!(PeaqRBAC::has_permission(&origin, user_id, permission_id));
+
+        // Disabling a user revokes all of its permissions at once
+        assert_ok!(PeaqRBAC::disable_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id
+        ));
+        assert!(!PeaqRBAC::has_permission(&origin, user_id, permission_id));
+        assert_eq!(
+            PeaqRBAC::check_permission(&origin, user_id, permission_id),
+            Ok(false)
+        );
+        assert!(PeaqRBAC::get_user_permissions(&origin, user_id)
+            .unwrap()
+            .is_empty());
+        assert_noop!(
+            PeaqRBAC::assign_role_to_user(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                user_id,
+                None
+            ),
+            Error::<Test>::EntityDisabled
+        );
+        DisabledPolicy::set(&DisabledEntityPolicy::Reject);
+        assert_eq!(
+            PeaqRBAC::get_user_permissions(&origin, user_id)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::EntityDisabled
+        );
+        assert!(!PeaqRBAC::has_permission(&origin, user_id, permission_id));
+        assert_eq!(
+            PeaqRBAC::check_permission(&origin, user_id, permission_id)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::EntityDisabled
+        );
+
+        // Unregistered users are rejected once registration is required
+        RequireRegisteredUsers::set(&true);
+        assert_noop!(
+            PeaqRBAC::assign_role_to_user(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                user_id2,
+                None
+            ),
+            Error::<Test>::EntityDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn user_binding_test() {
+    new_test_ext().execute_with(|| {
+        let origin = RbacOwner::get();
+        let device = account_key("Device");
+        let intruder = account_key("Intruder");
+        let user_id = *b"12676474666576474646673646376637";
+        let user_id2 = *b"12676474666576474646673646376638";
+        let role_id = *b"21676474666576474646673646376637";
+        let name = b"ADMIN";
+
+        type EnsureCanUpdate = EnsureRbacPermission<Test, RbacOwner, RbacPermissionId>;
+
+        assert_ok!(PeaqRBAC::add_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id,
+            b"Alice".to_vec()
+        ));
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec()
+        ));
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            RbacPermissionId::get(),
+            name.to_vec()
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            RbacPermissionId::get(),
+            role_id
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            None
+        ));
+
+        // Only registered users can be claimed and a newer claim replaces the previous one
+        assert_noop!(
+            PeaqRBAC::claim_user(RuntimeOrigin::signed(device), origin, user_id2),
+            Error::<Test>::EntityDoesNotExist
+        );
+        assert_ok!(PeaqRBAC::claim_user(
+            RuntimeOrigin::signed(intruder),
+            origin,
+            user_id
+        ));
+
+        // A claim has to be approved by the owner, before the account resolves to the user
+        assert_ok!(PeaqRBAC::claim_user(
+            RuntimeOrigin::signed(device),
+            origin,
+            user_id
+        ));
+        assert_noop!(
+            PeaqRBAC::approve_user_claim(RuntimeOrigin::signed(origin), origin, user_id, intruder),
+            Error::<Test>::AssignmentDoesNotExist
+        );
+        assert_eq!(PeaqRBAC::user_of_account(&origin, &device), None);
+        assert!(EnsureCanUpdate::try_origin(RuntimeOrigin::signed(device)).is_err());
+        assert_noop!(
+            PeaqRBAC::approve_user_claim(RuntimeOrigin::signed(device), origin, user_id, device),
+            Error::<Test>::EntityAuthorizationFailed
+        );
+        assert_noop!(
+            PeaqRBAC::approve_user_claim(RuntimeOrigin::signed(origin), origin, user_id2, device),
+            Error::<Test>::AssignmentDoesNotExist
+        );
+        assert_ok!(PeaqRBAC::approve_user_claim(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id,
+            device
+        ));
+        assert_eq!(PeaqRBAC::user_of_account(&origin, &device), Some(user_id));
+        assert_eq!(PeaqRBAC::account_of_user(&origin, user_id), Some(device));
+        assert_eq!(
+            EnsureCanUpdate::try_origin(RuntimeOrigin::signed(device)).ok(),
+            Some(device)
+        );
+
+        // Bound users and accounts can not be claimed again
+        assert_noop!(
+            PeaqRBAC::claim_user(RuntimeOrigin::signed(intruder), origin, user_id),
+            Error::<Test>::AssignmentAlreadyExist
+        );
+        assert_noop!(
+            PeaqRBAC::claim_user(RuntimeOrigin::signed(device), origin, user_id2),
+            Error::<Test>::AssignmentAlreadyExist
+        );
+
+        // Only the bound account or the owner may unbind the user
+        assert_noop!(
+            PeaqRBAC::unbind_user(RuntimeOrigin::signed(intruder), origin, user_id),
+            Error::<Test>::EntityAuthorizationFailed
+        );
+        assert_ok!(PeaqRBAC::unbind_user(
+            RuntimeOrigin::signed(device),
+            origin,
+            user_id
+        ));
+        assert_eq!(PeaqRBAC::user_of_account(&origin, &device), None);
+        assert!(EnsureCanUpdate::try_origin(RuntimeOrigin::signed(device)).is_err());
+        assert_noop!(
+            PeaqRBAC::unbind_user(RuntimeOrigin::signed(origin), origin, user_id),
+            Error::<Test>::AssignmentDoesNotExist
+        );
+
+        // Disabled users can not be bound
+        assert_ok!(PeaqRBAC::disable_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id
+        ));
+        assert_noop!(
+            PeaqRBAC::claim_user(RuntimeOrigin::signed(device), origin, user_id),
+            Error::<Test>::EntityDisabled
+        );
+
+        // An account does not resolve to its implicit user, once another account is bound to it
+        let implicit_id = intruder.0;
+        assert_ok!(PeaqRBAC::add_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            implicit_id,
+            b"Bob".to_vec()
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            implicit_id,
+            None
+        ));
+        assert_eq!(
+            EnsureCanUpdate::try_origin(RuntimeOrigin::signed(intruder)).ok(),
+            Some(intruder)
+        );
+        assert_ok!(PeaqRBAC::claim_user(
+            RuntimeOrigin::signed(device),
+            origin,
+            implicit_id
+        ));
+        assert_ok!(PeaqRBAC::approve_user_claim(
+            RuntimeOrigin::signed(origin),
+            origin,
+            implicit_id,
+            device
+        ));
+        assert!(EnsureCanUpdate::try_origin(RuntimeOrigin::signed(intruder)).is_err());
+        assert_eq!(
+            EnsureCanUpdate::try_origin(RuntimeOrigin::signed(device)).ok(),
+            Some(device)
+        );
+    });
+}
+
+#[test]
+fn user_binding_claims_of_same_account_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let device = account_key("Device");
+        let user_id = *b"12676474666576474646673646376637";
+        let user_id2 = *b"12676474666576474646673646376638";
+
+        for user in [user_id, user_id2] {
+            assert_ok!(PeaqRBAC::add_user(
+                RuntimeOrigin::signed(origin),
+                origin,
+                user,
+                b"Alice".to_vec()
+            ));
+            assert_ok!(PeaqRBAC::claim_user(
+                RuntimeOrigin::signed(device),
+                origin,
+                user
+            ));
+        }
+
+        // An account gets bound to a single user, even if it claimed several ones
+        assert_ok!(PeaqRBAC::approve_user_claim(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id,
+            device
+        ));
+        assert_noop!(
+            PeaqRBAC::approve_user_claim(RuntimeOrigin::signed(origin), origin, user_id2, device),
+            Error::<Test>::AssignmentAlreadyExist
+        );
+        assert_eq!(PeaqRBAC::user_of_account(&origin, &device), Some(user_id));
+        assert_eq!(PeaqRBAC::account_of_user(&origin, user_id2), None);
+
+        // The remaining claim can be approved, once the account has been unbound
+        assert_ok!(PeaqRBAC::unbind_user(
+            RuntimeOrigin::signed(device),
+            origin,
+            user_id
+        ));
+        assert_ok!(PeaqRBAC::approve_user_claim(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id2,
+            device
+        ));
+        assert_eq!(PeaqRBAC::user_of_account(&origin, &device), Some(user_id2));
+    });
+}
+
+#[test]
+fn explain_user_permission_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let user_id = *b"12676474666576474646673646376637";
+        let group_id = *b"66736466618663776474645421676476";
+        let role_id = *b"21676474666576474646673646376637";
+        let role_id2 = *b"21676474666576474646673646376638";
+        let role_id3 = *b"21676474666576474646673646376639";
+        let permission_id = *b"41664667364637663721676474666576";
+        let permission_id2 = *b"41664667364637663721676474666577";
+        let name = b"ADMIN";
+
+        for role in [role_id, role_id2, role_id3] {
+            assert_ok!(PeaqRBAC::add_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role,
+                name.to_vec()
+            ));
+        }
+        for permission in [permission_id, permission_id2] {
+            assert_ok!(PeaqRBAC::add_permission(
+                RuntimeOrigin::signed(origin),
+                origin,
+                permission,
+                name.to_vec()
+            ));
+        }
+        assert_ok!(PeaqRBAC::add_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            name.to_vec()
+        ));
+
+        // Direct role grants the permission, as does a group role by inheritance
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            role_id
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            role_id3
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id2,
+            role_id3
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            None
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id2,
+            group_id
+        ));
+        assert_ok!(PeaqRBAC::assign_user_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id,
+            group_id,
+            None
+        ));
+
+        assert_eq!(
+            PeaqRBAC::explain_user_permission(&origin, user_id, permission_id),
+            Ok(vec![
+                PermissionGrant {
+                    groups: vec![],
+                    roles: vec![role_id]
+                },
+                PermissionGrant {
+                    groups: vec![group_id],
+                    roles: vec![role_id2, role_id3]
+                },
+            ])
+        );
+        assert_eq!(
+            PeaqRBAC::get_permission_grant(&origin, user_id, permission_id),
+            Some(PermissionGrant {
+                groups: vec![],
+                roles: vec![role_id]
+            })
+        );
+
+        // Permission without any grant path
+        assert_eq!(
+            PeaqRBAC::explain_user_permission(&origin, user_id, permission_id2),
+            Ok(vec![])
+        );
+
+        // Test for non-existing permission
+        assert_eq!(
+            PeaqRBAC::explain_user_permission(&origin, user_id, role_id)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::EntityDoesNotExist
+        );
+
+        // A role reached by several inheritance paths is explained by each of them
+        assert_ok!(PeaqRBAC::assign_role_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            role_id2
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            role_id3
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id2,
+            role_id3
+        ));
+        assert_eq!(
+            PeaqRBAC::explain_user_permission(&origin, user_id, permission_id2),
+            Ok(vec![
+                PermissionGrant {
+                    groups: vec![],
+                    roles: vec![role_id, role_id3]
+                },
+                PermissionGrant {
+                    groups: vec![],
+                    roles: vec![role_id, role_id2, role_id3]
+                },
+                PermissionGrant {
+                    groups: vec![group_id],
+                    roles: vec![role_id2, role_id3]
+                },
+            ])
+        );
+        assert_eq!(
+            PeaqRBAC::get_permission_grant(&origin, user_id, permission_id2),
+            Some(PermissionGrant {
+                groups: vec![],
+                roles: vec![role_id, role_id3]
+            })
+        );
+
+        // Disabled roles on a path are handled like by get_user_permissions
+        assert_ok!(PeaqRBAC::disable_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id2
+        ));
+        assert_eq!(
+            PeaqRBAC::explain_user_permission(&origin, user_id, permission_id2),
+            Ok(vec![PermissionGrant {
+                groups: vec![],
+                roles: vec![role_id, role_id3]
+            }])
+        );
+        DisabledPolicy::set(&DisabledEntityPolicy::Reject);
+        assert_eq!(
+            PeaqRBAC::explain_user_permission(&origin, user_id, permission_id2)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::EntityDisabled
+        );
+        assert_eq!(
+            PeaqRBAC::get_user_permissions(&origin, user_id)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::EntityDisabled
+        );
+        assert!(!PeaqRBAC::has_permission(&origin, user_id, permission_id2));
+    });
+}
+
+#[test]
+fn export_domain_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let origin2 = account_key("Iredia2");
+        let user_id = *b"12676474666576474646673646376637";
+        let group_id = *b"66736466618663776474645421676476";
+        let group_id2 = *b"66736466618663776474645421676477";
+        let role_id = *b"21676474666576474646673646376637";
+        let role_id2 = *b"21676474666576474646673646376638";
+        let permission_id = *b"41664667364637663721676474666576";
+        let name = b"ADMIN";
+
+        // Test for an empty domain
+        assert_eq!(PeaqRBAC::export_domain(&origin), Ok(RbacDomain::default()));
+
+        // Entities get added in reverse order, to be sorted by the export
+        for role in [role_id2, role_id] {
+            assert_ok!(PeaqRBAC::add_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role,
+                name.to_vec()
+            ));
+        }
+        for group in [group_id2, group_id] {
+            assert_ok!(PeaqRBAC::add_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                group,
+                name.to_vec()
+            ));
+        }
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            name.to_vec()
+        ));
+        assert_ok!(PeaqRBAC::add_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id,
+            b"Alice".to_vec()
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            role_id
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id2,
+            role_id
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            Some(100)
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id2,
+            group_id
+        ));
+        assert_ok!(PeaqRBAC::assign_group_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            group_id2
+        ));
+        assert_ok!(PeaqRBAC::assign_user_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id,
+            group_id,
+            None
+        ));
+
+        let entity = |id| Entity {
+            id,
+            name: name.to_vec(),
+            enabled: true,
+        };
+        let domain = PeaqRBAC::export_domain(&origin).unwrap();
+        assert_eq!(
+            domain,
+            RbacDomain {
+                roles: vec![entity(role_id), entity(role_id2)],
+                permissions: vec![entity(permission_id)],
+                groups: vec![entity(group_id), entity(group_id2)],
+                users: vec![Entity {
+                    id: user_id,
+                    name: b"Alice".to_vec(),
+                    enabled: true,
+                }],
+                role_users: vec![Role2User {
+                    role: role_id,
+                    user: user_id,
+                    expires_at: Some(100),
+                }],
+                group_users: vec![User2Group {
+                    user: user_id,
+                    group: group_id,
+                    expires_at: None,
+                }],
+                group_roles: vec![Role2Group {
+                    role: role_id2,
+                    group: group_id,
+                }],
+                role_permissions: vec![Permission2Role {
+                    permission: permission_id,
+                    role: role_id,
+                }],
+                role_roles: vec![Role2Role {
+                    role: role_id2,
+                    inherited_role: role_id,
+                }],
+                group_groups: vec![Group2Group {
+                    group: group_id,
+                    parent: group_id2,
+                }],
+                constraints: vec![],
+            }
+        );
+        assert_eq!(RbacDomain::decode(&mut &domain.encode()[..]), Ok(domain));
+
+        // Domains of other owners are not part of the export
+        assert_eq!(PeaqRBAC::export_domain(&origin2), Ok(RbacDomain::default()));
+    });
+}
+
+#[test]
+fn import_domain_test() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let origin = account_key("Iredia");
+        let origin2 = account_key("Iredia2");
+        let user_id = *b"12676474666576474646673646376637";
+        let group_id = *b"66736466618663776474645421676476";
+        let role_id = *b"21676474666576474646673646376637";
+        let role_id2 = *b"21676474666576474646673646376638";
+        let permission_id = *b"41664667364637663721676474666576";
+        let name = b"ADMIN";
+
+        for role in [role_id, role_id2] {
+            assert_ok!(PeaqRBAC::add_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role,
+                name.to_vec()
+            ));
+        }
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            name.to_vec()
+        ));
+        assert_ok!(PeaqRBAC::add_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            name.to_vec()
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            role_id
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            group_id
+        ));
+        assert_ok!(PeaqRBAC::assign_user_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id,
+            group_id,
+            None
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id2,
+            user_id,
+            Some(100)
+        ));
+        assert_ok!(PeaqRBAC::disable_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id2
+        ));
+
+        // A domain gets cloned by importing its export
+        let domain = PeaqRBAC::export_domain(&origin).unwrap();
+        assert_ok!(PeaqRBAC::import_domain(
+            RuntimeOrigin::signed(origin2),
+            origin2,
+            domain.clone(),
+            ImportMode::Fail
+        ));
+        assert_eq!(PeaqRBAC::export_domain(&origin2), Ok(domain.clone()));
+        assert!(PeaqRBAC::has_permission(&origin2, user_id, permission_id));
+        System::assert_last_event(
+            Event::<Test>::DomainImported(origin2, domain.item_count()).into(),
+        );
+
+        // Test for existing entities
+        assert_noop!(
+            PeaqRBAC::import_domain(
+                RuntimeOrigin::signed(origin2),
+                origin2,
+                domain.clone(),
+                ImportMode::Fail
+            ),
+            Error::<Test>::EntityAlreadyExist
+        );
+        assert_ok!(PeaqRBAC::import_domain(
+            RuntimeOrigin::signed(origin2),
+            origin2,
+            domain.clone(),
+            ImportMode::Skip
+        ));
+        assert_eq!(PeaqRBAC::export_domain(&origin2), Ok(domain.clone()));
+
+        let mut renamed = domain.clone();
+        renamed.roles[0].name = b"OWNER".to_vec();
+        renamed.role_users[0].expires_at = Some(200);
+        assert_ok!(PeaqRBAC::import_domain(
+            RuntimeOrigin::signed(origin2),
+            origin2,
+            renamed.clone(),
+            ImportMode::Skip
+        ));
+        assert_eq!(PeaqRBAC::export_domain(&origin2), Ok(domain.clone()));
+        assert_ok!(PeaqRBAC::import_domain(
+            RuntimeOrigin::signed(origin2),
+            origin2,
+            renamed.clone(),
+            ImportMode::Overwrite
+        ));
+        assert_eq!(PeaqRBAC::export_domain(&origin2), Ok(renamed));
+
+        // Test for names exceeding the limit
+        let mut invalid = RbacDomain::default();
+        invalid.groups.push(Entity {
+            id: group_id,
+            name: [b'A'; 65].to_vec(),
+            enabled: true,
+        });
+        assert_noop!(
+            PeaqRBAC::import_domain(
+                RuntimeOrigin::signed(origin2),
+                origin2,
+                invalid,
+                ImportMode::Overwrite
+            ),
+            Error::<Test>::EntityNameExceedMax64
+        );
+
+        // Nothing gets imported, if a single item fails
+        let mut partial = RbacDomain::default();
+        partial.roles.push(Entity {
+            id: role_id,
+            name: name.to_vec(),
+            enabled: true,
+        });
+        partial.role_permissions.push(Permission2Role {
+            permission: permission_id,
+            role: role_id,
+        });
+        let origin3 = account_key("Iredia3");
+        assert_noop!(
+            PeaqRBAC::import_domain(
+                RuntimeOrigin::signed(origin3),
+                origin3,
+                partial,
+                ImportMode::Fail
+            ),
+            Error::<Test>::EntityDoesNotExist
+        );
+        assert!(PeaqRBAC::role_of(origin3).is_empty());
+
+        // A user cannot be given more roles than fit its list
+        let bound = <Test as crate::Config>::BoundedDataLen::get();
+        let mut oversized = RbacDomain::default();
+        for i in 0..=bound {
+            let mut role = role_id;
+            role[..4].copy_from_slice(&i.to_le_bytes());
+            oversized.role_users.push(Role2User {
+                role,
+                user: user_id,
+                expires_at: None,
+            });
+        }
+        assert_noop!(
+            PeaqRBAC::import_domain(
+                RuntimeOrigin::signed(origin3),
+                origin3,
+                oversized,
+                ImportMode::Skip
+            ),
+            Error::<Test>::StorageExceedsMaxBounds
+        );
+
+        // Test for importing into another owner's domain
+        assert_noop!(
+            PeaqRBAC::import_domain(
+                RuntimeOrigin::signed(origin3),
+                origin2,
+                domain,
+                ImportMode::Skip
+            ),
+            Error::<Test>::EntityAuthorizationFailed
+        );
+    });
+}
+
+/// Minimal xorshift generator, to derive reproducible random domains from a seed.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn one_in(&mut self, n: u64) -> bool {
+        self.next() % n == 0
+    }
+}
+
+fn numbered_id(tag: u8, index: usize) -> [u8; 32] {
+    let mut id = [tag; 32];
+    id[31] = index as u8;
+    id
+}
+
+/// Adds the linked indices to the set, until no more can be reached.
+fn reachable(mut set: Vec<usize>, links: &[(usize, usize)]) -> Vec<usize> {
+    let mut grown = true;
+    while grown {
+        grown = false;
+        for (from, to) in links {
+            if set.contains(from) && !set.contains(to) {
+                set.push(*to);
+                grown = true;
+            }
+        }
+    }
+    set
+}
+
+#[test]
+fn user_permissions_match_reference_test() {
+    // A chain of four entities stays within MaxRoleDepth and MaxGroupDepth
+    const ROLES: usize = 4;
+    const GROUPS: usize = 4;
+    const PERMISSIONS: usize = 6;
+
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..32 {
+        new_test_ext().execute_with(|| {
+            let origin = account_key("Iredia");
+            let user_id = *b"12676474666576474646673646376637";
+            let signed = || RuntimeOrigin::signed(origin);
+            let role = |i| numbered_id(b'R', i);
+            let group = |i| numbered_id(b'G', i);
+            let permission = |i| numbered_id(b'P', i);
+
+            let mut user_roles = vec![];
+            let mut user_groups = vec![];
+            let mut role_roles = vec![];
+            let mut group_groups = vec![];
+            let mut group_roles = vec![];
+            let mut role_permissions = vec![];
+            let mut disabled = vec![];
+
+            for r in 0..ROLES {
+                assert_ok!(PeaqRBAC::add_role(signed(), origin, role(r), b"R".to_vec()));
+                if rng.one_in(3) {
+                    assert_ok!(PeaqRBAC::assign_role_to_user(
+                        signed(),
+                        origin,
+                        role(r),
+                        user_id,
+                        None
+                    ));
+                    user_roles.push(r);
+                }
+            }
+            for g in 0..GROUPS {
+                assert_ok!(PeaqRBAC::add_group(
+                    signed(),
+                    origin,
+                    group(g),
+                    b"G".to_vec()
+                ));
+                if rng.one_in(3) {
+                    assert_ok!(PeaqRBAC::assign_user_to_group(
+                        signed(),
+                        origin,
+                        user_id,
+                        group(g),
+                        None
+                    ));
+                    user_groups.push(g);
+                }
+            }
+            for p in 0..PERMISSIONS {
+                assert_ok!(PeaqRBAC::add_permission(
+                    signed(),
+                    origin,
+                    permission(p),
+                    b"P".to_vec()
+                ));
+            }
+
+            // Links only point to higher indices, which keeps both hierarchies acyclic
+            for from in 0..ROLES {
+                for to in from + 1..ROLES {
+                    if rng.one_in(3) {
+                        assert_ok!(PeaqRBAC::assign_role_to_role(
+                            signed(),
+                            origin,
+                            role(from),
+                            role(to)
+                        ));
+                        role_roles.push((from, to));
+                    }
+                }
+            }
+            for from in 0..GROUPS {
+                for to in from + 1..GROUPS {
+                    if rng.one_in(3) {
+                        assert_ok!(PeaqRBAC::assign_group_to_group(
+                            signed(),
+                            origin,
+                            group(from),
+                            group(to)
+                        ));
+                        group_groups.push((from, to));
+                    }
+                }
+            }
+            for g in 0..GROUPS {
+                for r in 0..ROLES {
+                    if rng.one_in(3) {
+                        assert_ok!(PeaqRBAC::assign_role_to_group(
+                            signed(),
+                            origin,
+                            role(r),
+                            group(g)
+                        ));
+                        group_roles.push((g, r));
+                    }
+                }
+            }
+            for r in 0..ROLES {
+                for p in 0..PERMISSIONS {
+                    if rng.one_in(2) {
+                        assert_ok!(PeaqRBAC::assign_permission_to_role(
+                            signed(),
+                            origin,
+                            permission(p),
+                            role(r)
+                        ));
+                        role_permissions.push((r, p));
+                    }
+                }
+            }
+            for p in 0..PERMISSIONS {
+                if rng.one_in(4) {
+                    assert_ok!(PeaqRBAC::disable_permission(
+                        signed(),
+                        origin,
+                        permission(p)
+                    ));
+                    disabled.push(p);
+                }
+            }
+
+            // Naive reference: closure of all reachable roles and their enabled permissions
+            let groups = reachable(user_groups, &group_groups);
+            let mut roles = user_roles;
+            for (g, r) in group_roles.iter() {
+                if groups.contains(g) {
+                    roles.push(*r);
+                }
+            }
+            let roles = reachable(roles, &role_roles);
+            let mut expected: Vec<[u8; 32]> = role_permissions
+                .iter()
+                .filter(|(r, p)| roles.contains(r) && !disabled.contains(p))
+                .map(|(_, p)| permission(*p))
+                .collect();
+            expected.sort();
+            expected.dedup();
+
+            let resolved: Vec<[u8; 32]> = PeaqRBAC::get_user_permissions(&origin, user_id)
+                .unwrap()
+                .iter()
+                .map(|p| p.id)
+                .collect();
+            assert_eq!(resolved, expected);
+        });
+    }
+}
+
+parameter_types! {
+    pub RbacOwner: sr25519::Public = account_key("Iredia");
+    pub const RbacPermissionId: [u8; 32] = *b"76472167646454667364666186637478";
+}
+
+#[test]
+fn ensure_rbac_permission_test() {
+    new_test_ext().execute_with(|| {
+        let role_id = *b"46454667364666186637764721676478";
+        let origin = RbacOwner::get();
+        let device = account_key("Device");
+        // AccountToUser of the mock runtime maps an account to its public key
+        let user_id = device.0;
+        let name = b"Admin";
+
+        type EnsureCanUpdate = EnsureRbacPermission<Test, RbacOwner, RbacPermissionId>;
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec(),
+        ));
+
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            RbacPermissionId::get(),
+            name.to_vec(),
+        ));
+
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            RbacPermissionId::get(),
+            role_id
+        ));
+
+        // Test for user without the permission
+        assert!(EnsureCanUpdate::try_origin(RuntimeOrigin::signed(device)).is_err());
+
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            None
+        ));
+
+        assert_eq!(
+            EnsureCanUpdate::try_origin(RuntimeOrigin::signed(device)).ok(),
+            Some(device)
+        );
+
+        // Test for unsigned origins
+        assert!(EnsureCanUpdate::try_origin(RuntimeOrigin::root()).is_err());
+        assert!(EnsureCanUpdate::try_origin(RuntimeOrigin::none()).is_err());
+    });
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+#[test]
+fn ensure_rbac_permission_successful_origin_test() {
+    new_test_ext().execute_with(|| {
+        type EnsureCanUpdate = EnsureRbacPermission<Test, RbacOwner, RbacPermissionId>;
+
+        let o = EnsureCanUpdate::try_successful_origin().unwrap();
+        assert!(EnsureCanUpdate::try_origin(o).is_ok());
+
+        // Provisioning twice reuses the existing domain
+        let o = EnsureCanUpdate::try_successful_origin().unwrap();
+        assert!(EnsureCanUpdate::try_origin(o).is_ok());
+    });
+}
+
+#[test]
+fn delete_role_test() {
+    new_test_ext().execute_with(|| {
+        let acct = "Iredia";
+        let acct2 = "Iredia2";
+        let group_id = *b"66736466618663776474645421676476";
+        let role_id = *b"25676474666576474646673646376637";
+        let permission_id = *b"76472167646454667364666186637476";
+        let user_id = *b"65761367647466474646673646376637";
+        let origin = account_key(acct);
+        let origin2 = account_key(acct2);
+        let name = b"ADMIN";
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            role_id
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            None
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            group_id
+        ));
+
+        // Test for deletion of role not owned by origin
+        assert_noop!(
+            PeaqRBAC::delete_role(RuntimeOrigin::signed(origin2), origin2, role_id, 0),
+            Error::<Test>::EntityDoesNotExist
+        );
+
+        // Test for a witness below the number of users holding the role
+        assert_noop!(
+            PeaqRBAC::delete_role(RuntimeOrigin::signed(origin), origin, role_id, 0),
+            Error::<Test>::InvalidAssignmentWitness
+        );
+
+        // Disabled roles can be deleted as well
+        assert_ok!(PeaqRBAC::disable_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id
+        ));
+        assert_ok!(PeaqRBAC::delete_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            1
+        ));
+
+        // All assignments of the role have been removed
+        assert_eq!(
+            PeaqRBAC::get_user_roles(&origin, user_id).unwrap_err().typ,
+            RbacErrorType::AssignmentDoesNotExist
+        );
+        assert_eq!(
+            PeaqRBAC::get_group_roles(&origin, group_id)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::AssignmentDoesNotExist
+        );
+        assert_eq!(
+            PeaqRBAC::get_role_permissions(&origin, role_id)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::AssignmentDoesNotExist
+        );
+        assert!(PeaqRBAC::role_of(origin).is_empty());
+
+        // Test for deletion of non-existing role
+        assert_noop!(
+            PeaqRBAC::delete_role(RuntimeOrigin::signed(origin), origin, role_id, 0),
+            Error::<Test>::EntityDoesNotExist
+        );
+
+        // The role id can be used again
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec(),
+        ));
+        assert!(!PeaqRBAC::has_permission(&origin, user_id, permission_id));
+    });
+}
+
+#[test]
+fn delete_permission_test() {
+    new_test_ext().execute_with(|| {
+        let acct = "Iredia";
+        let acct2 = "Iredia2";
+        let role_id = *b"25676474666576474646673646376637";
+        let permission_id = *b"42464667364637663721676474666576";
+        let origin = account_key(acct);
+        let origin2 = account_key(acct2);
+        let name = b"CAN_DELETE";
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            role_id
+        ));
+
+        // Test for deletion of permission not owned by origin
+        assert_noop!(
+            PeaqRBAC::delete_permission(RuntimeOrigin::signed(origin2), origin2, permission_id),
+            Error::<Test>::EntityDoesNotExist
+        );
+
+        assert_ok!(PeaqRBAC::delete_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id
+        ));
+
+        assert_eq!(
+            PeaqRBAC::get_role_permissions(&origin, role_id)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::AssignmentDoesNotExist
+        );
+        assert!(PeaqRBAC::permission_of(origin).is_empty());
+
+        // Test for deletion of non-existing permission
+        assert_noop!(
+            PeaqRBAC::delete_permission(RuntimeOrigin::signed(origin), origin, permission_id),
+            Error::<Test>::EntityDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn delete_group_test() {
+    new_test_ext().execute_with(|| {
+        let acct = "Iredia";
+        let acct2 = "Iredia2";
+        let group_id = *b"19663776474646673646665421676476";
+        let role_id = *b"25676474666576474646673646376637";
+        let user_id = *b"12676474666576474646673646376637";
+        let user_id2 = *b"12676474666576474646673646376638";
+        let origin = account_key(acct);
+        let origin2 = account_key(acct2);
+        let name = b"Users";
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            group_id
+        ));
+        assert_ok!(PeaqRBAC::assign_user_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id,
+            group_id,
+            None
+        ));
+        assert_ok!(PeaqRBAC::assign_user_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id2,
+            group_id,
+            None
+        ));
+
+        // The same group id of another owner must not be affected
+        assert_ok!(PeaqRBAC::add_group(
+            RuntimeOrigin::signed(origin2),
+            origin2,
+            group_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::assign_user_to_group(
+            RuntimeOrigin::signed(origin2),
+            origin2,
+            user_id,
+            group_id,
+            None
+        ));
+
+        assert_ok!(PeaqRBAC::delete_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            1
+        ));
+
+        assert_eq!(
+            PeaqRBAC::get_user_groups(&origin, user_id).unwrap_err().typ,
+            RbacErrorType::AssignmentDoesNotExist
+        );
+        assert_eq!(
+            PeaqRBAC::get_user_groups(&origin, user_id2)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::AssignmentDoesNotExist
+        );
+        assert_eq!(
+            PeaqRBAC::get_group_roles(&origin, group_id)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::AssignmentDoesNotExist
+        );
+        assert!(PeaqRBAC::get_user_groups(&origin2, user_id).is_ok());
+
+        // Test for deletion of non-existing group
+        assert_noop!(
+            PeaqRBAC::delete_group(RuntimeOrigin::signed(origin), origin, group_id, 0),
+            Error::<Test>::EntityDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn enable_role_test() {
+    new_test_ext().execute_with(|| {
+        let acct = "Iredia";
+        let acct2 = "Iredia2";
+        let role_id = *b"26676474666576474646673646376637";
+        let origin = account_key(acct);
+        let origin2 = account_key(acct2);
+        let name = b"ADMIN";
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec(),
+        ));
+
+        // Test for enabling an enabled role
+        assert_noop!(
+            PeaqRBAC::enable_role(RuntimeOrigin::signed(origin), origin, role_id),
+            Error::<Test>::EntityAlreadyEnabled
+        );
+
+        assert_ok!(PeaqRBAC::disable_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id
+        ));
+
+        // Test for enabling role not owned by origin
+        assert_noop!(
+            PeaqRBAC::enable_role(RuntimeOrigin::signed(origin2), origin2, role_id),
+            Error::<Test>::EntityDoesNotExist
+        );
+
+        assert_ok!(PeaqRBAC::enable_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id
+        ));
+
+        // Enabled role can be updated and fetched again
+        assert_ok!(PeaqRBAC::update_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec()
+        ));
+        assert!(PeaqRBAC::get_role(&origin, role_id).is_ok());
+        assert!(PeaqRBAC::role_of(origin)[0].enabled);
+    });
+}
+
+#[test]
+fn enable_permission_test() {
+    new_test_ext().execute_with(|| {
+        let acct = "Iredia";
+        let acct2 = "Iredia2";
+        let permission_id = *b"43464667364637663721676474666576";
+        let origin = account_key(acct);
+        let origin2 = account_key(acct2);
+        let name = b"CAN_UPDATE";
+
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            name.to_vec(),
+        ));
+
+        // Test for enabling an enabled permission
+        assert_noop!(
+            PeaqRBAC::enable_permission(RuntimeOrigin::signed(origin), origin, permission_id),
+            Error::<Test>::EntityAlreadyEnabled
+        );
+
+        assert_ok!(PeaqRBAC::disable_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id
+        ));
+
+        // Test for enabling permission not owned by origin
+        assert_noop!(
+            PeaqRBAC::enable_permission(RuntimeOrigin::signed(origin2), origin2, permission_id),
+            Error::<Test>::EntityDoesNotExist
+        );
+
+        assert_ok!(PeaqRBAC::enable_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id
+        ));
+
+        assert!(PeaqRBAC::get_permission(&origin, permission_id).is_ok());
+        assert!(PeaqRBAC::permission_of(origin)[0].enabled);
+    });
+}
+
+#[test]
+fn enable_group_test() {
+    new_test_ext().execute_with(|| {
+        let acct = "Iredia";
+        let acct2 = "Iredia2";
+        let group_id = *b"20663776474646673646665421676476";
+        let origin = account_key(acct);
+        let origin2 = account_key(acct2);
+        let name = b"Users";
+
+        assert_ok!(PeaqRBAC::add_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            name.to_vec(),
+        ));
+
+        // Test for enabling an enabled group
+        assert_noop!(
+            PeaqRBAC::enable_group(RuntimeOrigin::signed(origin), origin, group_id),
+            Error::<Test>::EntityAlreadyEnabled
+        );
+
+        assert_ok!(PeaqRBAC::disable_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id
+        ));
+
+        // Test for enabling group not owned by origin
+        assert_noop!(
+            PeaqRBAC::enable_group(RuntimeOrigin::signed(origin2), origin2, group_id),
+            Error::<Test>::EntityDoesNotExist
+        );
+
+        assert_ok!(PeaqRBAC::enable_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id
+        ));
+
+        assert!(PeaqRBAC::get_group(&origin, group_id).is_ok());
+        assert!(PeaqRBAC::group_of(origin)[0].enabled);
+    });
+}
+
+#[test]
+fn assign_disabled_entity_test() {
+    new_test_ext().execute_with(|| {
+        let acct = "Iredia";
+        let group_id = *b"21663776474646673646665421676476";
+        let role_id = *b"27676474666576474646673646376637";
+        let permission_id = *b"45464667364637663721676474666576";
+        let user_id = *b"14676474666576474646673646376637";
+        let origin = account_key(acct);
+        let name = b"ADMIN";
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            name.to_vec(),
+        ));
+
+        assert_ok!(PeaqRBAC::disable_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id
+        ));
+        assert_ok!(PeaqRBAC::disable_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id
+        ));
+        assert_ok!(PeaqRBAC::disable_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id
+        ));
+
+        assert_noop!(
+            PeaqRBAC::assign_role_to_user(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                user_id,
+                None
+            ),
+            Error::<Test>::EntityDisabled
+        );
+        assert_noop!(
+            PeaqRBAC::assign_role_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                group_id
+            ),
+            Error::<Test>::EntityDisabled
+        );
+        assert_noop!(
+            PeaqRBAC::assign_user_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                user_id,
+                group_id,
+                None
+            ),
+            Error::<Test>::EntityDisabled
+        );
+        assert_noop!(
+            PeaqRBAC::assign_permission_to_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                permission_id,
+                role_id
+            ),
+            Error::<Test>::EntityDisabled
+        );
+
+        // Disabled permission to enabled role
+        assert_ok!(PeaqRBAC::enable_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id
+        ));
+        assert_noop!(
+            PeaqRBAC::assign_permission_to_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                permission_id,
+                role_id
+            ),
+            Error::<Test>::EntityDisabled
+        );
+    });
+}
+
+#[test]
+fn resolve_disabled_entity_test() {
+    new_test_ext().execute_with(|| {
+        let acct = "Iredia";
+        let group_id = *b"22663776474646673646665421676476";
+        let role_id = *b"28676474666576474646673646376637";
+        let role_id2 = *b"28676474666576474646673646376638";
+        let permission_id = *b"46464667364637663721676474666576";
+        let permission_id2 = *b"46464667364637663721676474666577";
+        let user_id = *b"16676474666576474646673646376637";
+        let origin = account_key(acct);
+        let name = b"ADMIN";
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id2,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id2,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            role_id
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id2,
+            role_id
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id2,
+            role_id2
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            None
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id2,
+            group_id
+        ));
+        assert_ok!(PeaqRBAC::assign_user_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id,
+            group_id,
+            None
+        ));
+
+        assert_ok!(PeaqRBAC::disable_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id2
+        ));
+
+        // Disabled permission gets skipped by default
+        let permissions = PeaqRBAC::get_user_permissions(&origin, user_id).unwrap();
+        assert_eq!(permissions.len(), 1);
+        assert_eq!(permissions[0].id, permission_id);
+        assert!(PeaqRBAC::has_permission(&origin, user_id, permission_id));
+        assert!(!PeaqRBAC::has_permission(&origin, user_id, permission_id2));
+
+        // Disabled group gets skipped by default
+        assert_ok!(PeaqRBAC::enable_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id2
+        ));
+        assert_ok!(PeaqRBAC::disable_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id
+        ));
+        assert_ok!(PeaqRBAC::disable_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id
+        ));
+        assert!(PeaqRBAC::get_user_permissions(&origin, user_id)
+            .unwrap()
+            .is_empty());
+        assert!(!PeaqRBAC::has_permission(&origin, user_id, permission_id));
+
+        // Disabled entities abort resolution, when rejected by policy
+        DisabledPolicy::set(&DisabledEntityPolicy::Reject);
+        assert_eq!(
+            PeaqRBAC::get_user_permissions(&origin, user_id)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::EntityDisabled
+        );
+    });
+}
+
+#[test]
+fn assign_role_to_role_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let role_id = *b"21676474666576474646673646376637";
+        let role_id2 = *b"21676474666576474646673646376638";
+        let role_id3 = *b"21676474666576474646673646376639";
+        let name = b"ADMIN";
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id2,
+            name.to_vec(),
+        ));
+
+        // Test for assignment of non-existing role
+        assert_noop!(
+            PeaqRBAC::assign_role_to_role(RuntimeOrigin::signed(origin), origin, role_id, role_id3),
+            Error::<Test>::EntityDoesNotExist
+        );
+
+        assert_ok!(PeaqRBAC::assign_role_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            role_id2
+        ));
+
+        // Test for duplicate assignment
+        assert_noop!(
+            PeaqRBAC::assign_role_to_role(RuntimeOrigin::signed(origin), origin, role_id, role_id2),
+            Error::<Test>::AssignmentAlreadyExist
+        );
+
+        // Test for cycles, including a role inheriting itself
+        assert_noop!(
+            PeaqRBAC::assign_role_to_role(RuntimeOrigin::signed(origin), origin, role_id2, role_id),
+            Error::<Test>::CycleDetected
+        );
+        assert_noop!(
+            PeaqRBAC::assign_role_to_role(RuntimeOrigin::signed(origin), origin, role_id, role_id),
+            Error::<Test>::CycleDetected
+        );
+
+        let roles = PeaqRBAC::get_role_roles(&origin, role_id).unwrap();
+        assert_eq!(roles.len(), 1);
+        assert_eq!(roles[0].inherited_role, role_id2);
+
+        assert_ok!(PeaqRBAC::unassign_role_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            role_id2
+        ));
+        assert_noop!(
+            PeaqRBAC::unassign_role_to_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                role_id2
+            ),
+            Error::<Test>::AssignmentDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn role_hierarchy_depth_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let roles = [
+            *b"21676474666576474646673646376630",
+            *b"21676474666576474646673646376631",
+            *b"21676474666576474646673646376632",
+            *b"21676474666576474646673646376633",
+            *b"21676474666576474646673646376634",
+        ];
+        let name = b"ADMIN";
+
+        for role_id in roles.iter() {
+            assert_ok!(PeaqRBAC::add_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                *role_id,
+                name.to_vec(),
+            ));
+        }
+
+        // MaxRoleDepth is 3 within the mock
+        assert_ok!(PeaqRBAC::assign_role_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            roles[1],
+            roles[2]
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            roles[2],
+            roles[3]
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            roles[0],
+            roles[1]
+        ));
+
+        // Depth is exceeded below and above the assigned role
+        assert_noop!(
+            PeaqRBAC::assign_role_to_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                roles[3],
+                roles[4]
+            ),
+            Error::<Test>::MaxDepthExceeded
+        );
+        assert_noop!(
+            PeaqRBAC::assign_role_to_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                roles[4],
+                roles[0]
+            ),
+            Error::<Test>::MaxDepthExceeded
+        );
+    });
+}
+
+#[test]
+fn resolve_inherited_permissions_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let user_id = *b"12676474666576474646673646376637";
+        let user_id2 = *b"12676474666576474646673646376638";
+        let role_id = *b"21676474666576474646673646376637";
+        let role_id2 = *b"21676474666576474646673646376638";
+        let role_id3 = *b"21676474666576474646673646376639";
+        let permission_id = *b"41664667364637663721676474666576";
+        let permission_id2 = *b"41664667364637663721676474666577";
+        let group_id = *b"11664667364637663721676474666576";
+        let name = b"ADMIN";
+
+        for role_id in [role_id, role_id2, role_id3] {
+            assert_ok!(PeaqRBAC::add_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                name.to_vec(),
+            ));
+        }
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id2,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            name.to_vec(),
+        ));
+
+        // role_id inherits role_id2 which inherits role_id3, the senior role
+        // itself has no permissions assigned
+        assert_ok!(PeaqRBAC::assign_role_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            role_id2
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id2,
+            role_id3
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            role_id2
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id2,
+            role_id3
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            None
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            group_id
+        ));
+        assert_ok!(PeaqRBAC::assign_user_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id2,
+            group_id,
+            None
+        ));
+
+        let permissions = PeaqRBAC::get_user_permissions(&origin, user_id).unwrap();
+        assert_eq!(permissions.len(), 2);
+        assert_eq!(
+            PeaqRBAC::get_group_permissions(&origin, group_id)
+                .unwrap()
+                .len(),
+            2
+        );
+        assert_eq!(
+            PeaqRBAC::get_permission_grant(&origin, user_id2, permission_id2),
+            Some(PermissionGrant {
+                groups: vec![group_id],
+                roles: vec![role_id, role_id2, role_id3]
+            })
+        );
+
+        // Disabled role does not pass on its inherited roles
+        assert_ok!(PeaqRBAC::disable_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id2
+        ));
+        assert!(PeaqRBAC::get_user_permissions(&origin, user_id)
+            .unwrap()
+            .is_empty());
+        assert!(!PeaqRBAC::has_permission(&origin, user_id, permission_id2));
+
+        // Deleted role gets removed from the hierarchy
+        assert_ok!(PeaqRBAC::delete_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id2,
+            1
+        ));
+        assert_eq!(
+            PeaqRBAC::get_role_roles(&origin, role_id).unwrap_err().typ,
+            RbacErrorType::AssignmentDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn assign_group_to_group_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let group_id = *b"11664667364637663721676474666570";
+        let group_id2 = *b"11664667364637663721676474666571";
+        let group_id3 = *b"11664667364637663721676474666572";
+        let group_id4 = *b"11664667364637663721676474666573";
+        let group_id5 = *b"11664667364637663721676474666574";
+        let name = b"Users";
+
+        for group_id in [group_id, group_id2, group_id3, group_id4, group_id5] {
+            assert_ok!(PeaqRBAC::add_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                group_id,
+                name.to_vec(),
+            ));
+        }
+
+        // group_id is a member of group_id2, which is a member of group_id3
+        assert_ok!(PeaqRBAC::assign_group_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            group_id2
+        ));
+        assert_ok!(PeaqRBAC::assign_group_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id2,
+            group_id3
+        ));
+
+        // Test for duplicate assignment and cycles
+        assert_noop!(
+            PeaqRBAC::assign_group_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                group_id,
+                group_id2
+            ),
+            Error::<Test>::AssignmentAlreadyExist
+        );
+        assert_noop!(
+            PeaqRBAC::assign_group_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                group_id3,
+                group_id
+            ),
+            Error::<Test>::CycleDetected
+        );
+
+        // MaxGroupDepth is 3 within the mock
+        assert_ok!(PeaqRBAC::assign_group_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id3,
+            group_id4
+        ));
+        assert_noop!(
+            PeaqRBAC::assign_group_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                group_id4,
+                group_id5
+            ),
+            Error::<Test>::MaxDepthExceeded
+        );
+
+        let groups = PeaqRBAC::get_group_groups(&origin, group_id).unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].parent, group_id2);
+
+        assert_ok!(PeaqRBAC::unassign_group_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            group_id2
+        ));
+        assert_noop!(
+            PeaqRBAC::unassign_group_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                group_id,
+                group_id2
+            ),
+            Error::<Test>::AssignmentDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn resolve_nested_groups_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let user_id = *b"12676474666576474646673646376637";
+        let role_id = *b"21676474666576474646673646376637";
+        let permission_id = *b"41664667364637663721676474666576";
+        let team_id = *b"11664667364637663721676474666570";
+        let department_id = *b"11664667364637663721676474666571";
+        let company_id = *b"11664667364637663721676474666572";
+        let name = b"Users";
+
+        for group_id in [team_id, department_id, company_id] {
+            assert_ok!(PeaqRBAC::add_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                group_id,
+                name.to_vec(),
+            ));
+        }
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            role_id
+        ));
+
+        // Only the company group holds a role, the user joins the team
+        assert_ok!(PeaqRBAC::assign_group_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            team_id,
+            department_id
+        ));
+        assert_ok!(PeaqRBAC::assign_group_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            department_id,
+            company_id
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            company_id
+        ));
+        assert_ok!(PeaqRBAC::assign_user_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id,
+            team_id,
+            None
+        ));
+
+        let groups = PeaqRBAC::get_user_groups(&origin, user_id).unwrap();
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[2].group, company_id);
+
+        let permissions = PeaqRBAC::get_user_permissions(&origin, user_id).unwrap();
+        assert_eq!(permissions.len(), 1);
+        assert_eq!(
+            PeaqRBAC::get_group_permissions(&origin, team_id)
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(
+            PeaqRBAC::get_permission_grant(&origin, user_id, permission_id),
+            Some(PermissionGrant {
+                groups: vec![team_id, department_id, company_id],
+                roles: vec![role_id]
+            })
+        );
+
+        // Disabled group does not pass on its memberships
+        assert_ok!(PeaqRBAC::disable_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            department_id
+        ));
+        assert!(PeaqRBAC::get_user_permissions(&origin, user_id)
+            .unwrap()
+            .is_empty());
+        assert!(!PeaqRBAC::has_permission(&origin, user_id, permission_id));
+        let groups = PeaqRBAC::get_user_groups(&origin, user_id).unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].group, team_id);
+
+        // Deleted group gets removed from the hierarchy
+        assert_ok!(PeaqRBAC::delete_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            department_id,
+            1
+        ));
+        assert_eq!(
+            PeaqRBAC::get_user_groups(&origin, user_id).unwrap().len(),
+            1
+        );
+        assert_eq!(
+            PeaqRBAC::get_group_groups(&origin, team_id)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::AssignmentDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn add_role_constraint_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let constraint_id = *b"51676474666576474646673646376637";
+        let role_id = *b"21676474666576474646673646376637";
+        let role_id2 = *b"21676474666576474646673646376638";
+        let role_id3 = *b"21676474666576474646673646376639";
+        let name = b"ADMIN";
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id2,
+            name.to_vec(),
+        ));
+
+        // Test for non-existing roles and sets with less than two distinct roles
+        assert_noop!(
+            PeaqRBAC::add_role_constraint(
+                RuntimeOrigin::signed(origin),
+                origin,
+                constraint_id,
+                ConstraintKind::Static,
+                vec![role_id, role_id3]
+            ),
+            Error::<Test>::EntityDoesNotExist
+        );
+        assert_noop!(
+            PeaqRBAC::add_role_constraint(
+                RuntimeOrigin::signed(origin),
+                origin,
+                constraint_id,
+                ConstraintKind::Static,
+                vec![role_id, role_id]
+            ),
+            Error::<Test>::InvalidRoleConstraint
+        );
+        let bound = <Test as crate::Config>::BoundedDataLen::get();
+        assert_noop!(
+            PeaqRBAC::add_role_constraint(
+                RuntimeOrigin::signed(origin),
+                origin,
+                constraint_id,
+                ConstraintKind::Static,
+                vec![role_id; bound as usize + 1]
+            ),
+            Error::<Test>::StorageExceedsMaxBounds
+        );
+
+        assert_ok!(PeaqRBAC::add_role_constraint(
+            RuntimeOrigin::signed(origin),
+            origin,
+            constraint_id,
+            ConstraintKind::Static,
+            vec![role_id, role_id2]
+        ));
+        assert_noop!(
+            PeaqRBAC::add_role_constraint(
+                RuntimeOrigin::signed(origin),
+                origin,
+                constraint_id,
+                ConstraintKind::Dynamic,
+                vec![role_id, role_id2]
+            ),
+            Error::<Test>::EntityAlreadyExist
+        );
+        assert_eq!(PeaqRBAC::get_role_constraints(&origin).unwrap().len(), 1);
+
+        // Deleting a role drops the constraint, which is left with a single role
+        assert_ok!(PeaqRBAC::delete_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id2,
+            1
+        ));
+        assert!(PeaqRBAC::get_role_constraints(&origin).unwrap().is_empty());
+        assert_noop!(
+            PeaqRBAC::remove_role_constraint(RuntimeOrigin::signed(origin), origin, constraint_id),
+            Error::<Test>::EntityDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn static_separation_of_duty_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let constraint_id = *b"51676474666576474646673646376637";
+        let user_id = *b"12676474666576474646673646376637";
+        let user_id2 = *b"12676474666576474646673646376638";
+        let approver_id = *b"21676474666576474646673646376637";
+        let requester_id = *b"21676474666576474646673646376638";
+        let group_id = *b"11664667364637663721676474666570";
+        let group_id2 = *b"11664667364637663721676474666571";
+        let name = b"ADMIN";
+
+        for role_id in [approver_id, requester_id] {
+            assert_ok!(PeaqRBAC::add_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                name.to_vec(),
+            ));
+        }
+        for group_id in [group_id, group_id2] {
+            assert_ok!(PeaqRBAC::add_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                group_id,
+                name.to_vec(),
+            ));
+        }
+        assert_ok!(PeaqRBAC::add_role_constraint(
+            RuntimeOrigin::signed(origin),
+            origin,
+            constraint_id,
+            ConstraintKind::Static,
+            vec![approver_id, requester_id]
+        ));
+
+        // Direct role assignments
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            approver_id,
+            user_id,
+            None
+        ));
+        assert_noop!(
+            PeaqRBAC::assign_role_to_user(
+                RuntimeOrigin::signed(origin),
+                origin,
+                requester_id,
+                user_id,
+                None
+            ),
+            Error::<Test>::SeparationOfDutyViolation
+        );
+
+        // Group-derived roles
+        assert_ok!(PeaqRBAC::assign_role_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            requester_id,
+            group_id
+        ));
+        assert_noop!(
+            PeaqRBAC::assign_user_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                user_id,
+                group_id,
+                None
+            ),
+            Error::<Test>::SeparationOfDutyViolation
+        );
+        assert_noop!(
+            PeaqRBAC::assign_role_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                approver_id,
+                group_id
+            ),
+            Error::<Test>::SeparationOfDutyViolation
+        );
+
+        // Members of a group gaining a conflicting role, also through nesting
+        assert_ok!(PeaqRBAC::assign_user_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id,
+            group_id2,
+            None
+        ));
+        assert_ok!(PeaqRBAC::assign_user_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id2,
+            group_id,
+            None
+        ));
+        assert_noop!(
+            PeaqRBAC::assign_group_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                group_id2,
+                group_id
+            ),
+            Error::<Test>::SeparationOfDutyViolation
+        );
+
+        // No violation once the constraint is removed
+        assert_ok!(PeaqRBAC::remove_role_constraint(
+            RuntimeOrigin::signed(origin),
+            origin,
+            constraint_id
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            requester_id,
+            user_id,
+            None
+        ));
+
+        // A static constraint cannot be added, while a user holds both roles
+        assert_noop!(
+            PeaqRBAC::add_role_constraint(
+                RuntimeOrigin::signed(origin),
+                origin,
+                constraint_id,
+                ConstraintKind::Static,
+                vec![approver_id, requester_id]
+            ),
+            Error::<Test>::SeparationOfDutyViolation
+        );
+        assert_ok!(PeaqRBAC::add_role_constraint(
+            RuntimeOrigin::signed(origin),
+            origin,
+            constraint_id,
+            ConstraintKind::Dynamic,
+            vec![approver_id, requester_id]
+        ));
+    });
+}
+
+#[test]
+fn static_separation_of_duty_bound_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let constraint_id = *b"51676474666576474646673646376637";
+        let user_id = *b"12676474666576474646673646376637";
+        let approver_id = *b"21676474666576474646673646376637";
+        let requester_id = *b"21676474666576474646673646376638";
+        let group_id = *b"11664667364637663721676474666570";
+        let name = b"ADMIN";
+        let bound = <Test as crate::Config>::BoundedDataLen::get();
+
+        for role_id in [approver_id, requester_id] {
+            assert_ok!(PeaqRBAC::add_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                name.to_vec(),
+            ));
+        }
+        assert_ok!(PeaqRBAC::add_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_role_constraint(
+            RuntimeOrigin::signed(origin),
+            origin,
+            constraint_id,
+            ConstraintKind::Static,
+            vec![approver_id, requester_id]
+        ));
+
+        // Checking more members than fit a single check fails
+        for i in 0..=bound {
+            let mut user_id = user_id;
+            user_id[..4].copy_from_slice(&i.to_le_bytes());
+            assert_ok!(PeaqRBAC::assign_user_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                user_id,
+                group_id,
+                None
+            ));
+        }
+        assert_noop!(
+            PeaqRBAC::assign_role_to_group(
+                RuntimeOrigin::signed(origin),
+                origin,
+                approver_id,
+                group_id
+            ),
+            Error::<Test>::StorageExceedsMaxBounds
+        );
+
+        // Without static constraints, members do not need to be checked
+        assert_ok!(PeaqRBAC::remove_role_constraint(
+            RuntimeOrigin::signed(origin),
+            origin,
+            constraint_id
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            approver_id,
+            group_id
+        ));
+    });
+}
+
+#[test]
+fn dynamic_separation_of_duty_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let constraint_id = *b"51676474666576474646673646376637";
+        let user_id = *b"12676474666576474646673646376637";
+        let approver_id = *b"21676474666576474646673646376637";
+        let requester_id = *b"21676474666576474646673646376638";
+        let permission_id = *b"41664667364637663721676474666576";
+        let name = b"ADMIN";
+
+        for role_id in [approver_id, requester_id] {
+            assert_ok!(PeaqRBAC::add_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                name.to_vec(),
+            ));
+        }
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            approver_id
+        ));
+        assert_ok!(PeaqRBAC::add_role_constraint(
+            RuntimeOrigin::signed(origin),
+            origin,
+            constraint_id,
+            ConstraintKind::Dynamic,
+            vec![approver_id, requester_id]
+        ));
+
+        // Dynamic constraints do not restrict assignments
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            approver_id,
+            user_id,
+            None
+        ));
+        assert!(PeaqRBAC::has_permission(&origin, user_id, permission_id));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            requester_id,
+            user_id,
+            None
+        ));
+
+        // ...but resolution and permission checks
+        assert!(!PeaqRBAC::has_permission(&origin, user_id, permission_id));
+        assert_eq!(
+            PeaqRBAC::get_user_permissions(&origin, user_id)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::SeparationOfDutyViolation
+        );
+
+        // Disabled roles are not active
+        assert_ok!(PeaqRBAC::disable_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            requester_id
+        ));
+        assert!(PeaqRBAC::has_permission(&origin, user_id, permission_id));
+    });
+}
+
+#[test]
+fn separation_of_duty_inherited_roles_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let constraint_id = *b"51676474666576474646673646376637";
+        let constraint_id2 = *b"51676474666576474646673646376638";
+        let user_id = *b"12676474666576474646673646376637";
+        let user_id2 = *b"12676474666576474646673646376638";
+        let group_id = *b"66736466618663776474645421676476";
+        let approver_id = *b"21676474666576474646673646376637";
+        let requester_id = *b"21676474666576474646673646376638";
+        let auditor_id = *b"21676474666576474646673646376639";
+        let reviewer_id = *b"21676474666576474646673646376630";
+        let permission_id = *b"41664667364637663721676474666576";
+        let name = b"ADMIN";
+
+        for role_id in [approver_id, requester_id, auditor_id, reviewer_id] {
+            assert_ok!(PeaqRBAC::add_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                name.to_vec(),
+            ));
+        }
+        assert_ok!(PeaqRBAC::add_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_role_constraint(
+            RuntimeOrigin::signed(origin),
+            origin,
+            constraint_id,
+            ConstraintKind::Static,
+            vec![approver_id, requester_id]
+        ));
+
+        // A role inheriting both roles of a constraint is a violation by itself
+        assert_ok!(PeaqRBAC::assign_role_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            auditor_id,
+            requester_id
+        ));
+        assert_noop!(
+            PeaqRBAC::assign_role_to_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                auditor_id,
+                approver_id
+            ),
+            Error::<Test>::SeparationOfDutyViolation
+        );
+
+        // Users gain the inherited roles as well
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            auditor_id,
+            user_id,
+            None
+        ));
+        assert_eq!(
+            PeaqRBAC::get_user_effective_roles(&origin, user_id),
+            vec![auditor_id, requester_id]
+        );
+        assert_noop!(
+            PeaqRBAC::assign_role_to_user(
+                RuntimeOrigin::signed(origin),
+                origin,
+                approver_id,
+                user_id,
+                None
+            ),
+            Error::<Test>::SeparationOfDutyViolation
+        );
+
+        // Linking roles is checked against the users and groups holding them
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            approver_id,
+            user_id2,
+            None
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            reviewer_id,
+            user_id2,
+            None
+        ));
+        assert_noop!(
+            PeaqRBAC::assign_role_to_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                reviewer_id,
+                requester_id
+            ),
+            Error::<Test>::SeparationOfDutyViolation
+        );
+        assert_ok!(PeaqRBAC::unassign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            reviewer_id,
+            user_id2
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            reviewer_id,
+            group_id
+        ));
+        assert_ok!(PeaqRBAC::assign_user_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id2,
+            group_id,
+            None
+        ));
+        assert_noop!(
+            PeaqRBAC::assign_role_to_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                reviewer_id,
+                requester_id
+            ),
+            Error::<Test>::SeparationOfDutyViolation
+        );
+
+        // Dynamic constraints consider inherited roles, when permissions get resolved
+        assert_ok!(PeaqRBAC::remove_role_constraint(
+            RuntimeOrigin::signed(origin),
+            origin,
+            constraint_id
+        ));
+        assert_ok!(PeaqRBAC::add_role_constraint(
+            RuntimeOrigin::signed(origin),
+            origin,
+            constraint_id2,
+            ConstraintKind::Dynamic,
+            vec![approver_id, requester_id]
+        ));
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            approver_id
+        ));
+        assert!(PeaqRBAC::has_permission(&origin, user_id2, permission_id));
+        assert_ok!(PeaqRBAC::assign_role_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            reviewer_id,
+            requester_id
+        ));
+        assert!(!PeaqRBAC::has_permission(&origin, user_id2, permission_id));
+        assert_eq!(
+            PeaqRBAC::get_user_permissions(&origin, user_id2)
+                .unwrap_err()
+                .typ,
+            RbacErrorType::SeparationOfDutyViolation
+        );
+    });
+}
+
+#[test]
+fn expiring_role_assignment_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let user_id = *b"12676474666576474646673646376637";
+        let role_id = *b"21676474666576474646673646376637";
+        let permission_id = *b"41664667364637663721676474666576";
+        let name = b"ADMIN";
+
+        System::set_block_number(1);
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            role_id
+        ));
+
+        // Expiry has to be in the future
+        assert_noop!(
+            PeaqRBAC::assign_role_to_user(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                user_id,
+                Some(1)
+            ),
+            Error::<Test>::InvalidExpiry
+        );
+
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            Some(10)
+        ));
+        assert_noop!(
+            PeaqRBAC::assign_role_to_user(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                user_id,
+                None
+            ),
+            Error::<Test>::AssignmentAlreadyExist
+        );
+
+        System::set_block_number(9);
+        assert!(PeaqRBAC::has_permission(&origin, user_id, permission_id));
+        assert_eq!(PeaqRBAC::get_user_roles(&origin, user_id).unwrap().len(), 1);
+
+        // Assignment is ignored from the expiry block on, even if not pruned yet
+        System::set_block_number(10);
+        assert!(!PeaqRBAC::has_permission(&origin, user_id, permission_id));
+        assert!(PeaqRBAC::get_user_roles(&origin, user_id)
+            .unwrap()
+            .is_empty());
+
+        // Expired assignment gets replaced
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            None
+        ));
+        assert!(PeaqRBAC::has_permission(&origin, user_id, permission_id));
+    });
+}
+
+#[test]
+fn prune_expired_assignments_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let user_id = *b"12676474666576474646673646376637";
+        let role_id = *b"21676474666576474646673646376637";
+        let group_id = *b"31676474666576474646673646376637";
+        let name = b"ADMIN";
+
+        // Assignments get pruned, even if queued before the first idle block
+        System::set_block_number(1);
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::add_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            name.to_vec(),
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            Some(5)
+        ));
+        assert_ok!(PeaqRBAC::assign_user_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id,
+            group_id,
+            Some(5)
+        ));
+
+        // Nothing to prune before the expiry block
+        System::set_block_number(4);
+        PeaqRBAC::on_idle(4, Weight::MAX);
+        assert!(PeaqRBAC::get_user_roles(&origin, user_id).is_ok());
+        assert!(PeaqRBAC::get_user_groups(&origin, user_id).is_ok());
+
+        System::set_block_number(5);
+        PeaqRBAC::on_idle(5, Weight::MAX);
+        assert_eq!(
+            PeaqRBAC::get_user_roles(&origin, user_id).unwrap_err().typ,
+            RbacErrorType::AssignmentDoesNotExist
+        );
+        assert_eq!(
+            PeaqRBAC::get_user_groups(&origin, user_id).unwrap_err().typ,
+            RbacErrorType::AssignmentDoesNotExist
+        );
+        System::assert_has_event(
+            Event::<Test>::AssignmentExpired(
+                origin,
+                Assignment::Role2User {
+                    role: role_id,
+                    user: user_id,
+                },
+            )
+            .into(),
+        );
+        System::assert_has_event(
+            Event::<Test>::AssignmentExpired(
+                origin,
+                Assignment::User2Group {
+                    user: user_id,
+                    group: group_id,
+                },
+            )
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn prune_expired_assignments_partially_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let user_id = *b"12676474666576474646673646376637";
+        let name = b"ADMIN";
+        let db_weight = <Test as frame_system::Config>::DbWeight::get();
+
+        System::set_block_number(1);
+
+        for i in 0..3u8 {
+            let mut role_id = *b"21676474666576474646673646376637";
+            role_id[0] = i;
+            assert_ok!(PeaqRBAC::add_role(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                name.to_vec(),
+            ));
+            assert_ok!(PeaqRBAC::assign_role_to_user(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                user_id,
+                Some(5)
+            ));
+        }
+
+        // Advances the cursor to the block of the queue
+        System::set_block_number(4);
+        PeaqRBAC::on_idle(4, Weight::MAX);
+
+        // The remaining weight suffices for a single assignment of the queue
+        System::set_block_number(5);
+        let remaining = db_weight
+            .reads_writes(1, 1)
+            .saturating_add(db_weight.reads_writes(1, 1))
+            .saturating_add(db_weight.reads_writes(1, 2));
+        PeaqRBAC::on_idle(5, remaining);
+        let key = PeaqRBAC::generate_key(&origin, &user_id, Tag::Role2User);
+        assert_eq!(PeaqRBAC::role_to_user_of(key).len(), 2);
+        assert_eq!(PeaqRBAC::expiry_of(5, origin).len(), 2);
+
+        // The rest of the queue is pruned later on
+        System::set_block_number(6);
+        PeaqRBAC::on_idle(6, Weight::MAX);
+        assert!(PeaqRBAC::get_user_roles(&origin, user_id).is_err());
+        assert!(PeaqRBAC::expiry_of(5, origin).is_empty());
+    });
+}
+
+#[test]
+fn expiry_queues_per_owner_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let origin2 = account_key("Iredia2");
+        let user_id = *b"12676474666576474646673646376637";
+        let role_id = *b"21676474666576474646673646376637";
+        let name = b"ADMIN";
+        let bound = <Test as crate::Config>::BoundedDataLen::get();
+
+        System::set_block_number(1);
+
+        for owner in [origin, origin2] {
+            assert_ok!(PeaqRBAC::add_role(
+                RuntimeOrigin::signed(owner),
+                owner,
+                role_id,
+                name.to_vec(),
+            ));
+        }
+
+        // Another owner fills its queue of the block
+        for i in 0..bound {
+            let mut user_id = user_id;
+            user_id[..4].copy_from_slice(&i.to_le_bytes());
+            assert_ok!(PeaqRBAC::assign_role_to_user(
+                RuntimeOrigin::signed(origin2),
+                origin2,
+                role_id,
+                user_id,
+                Some(5)
+            ));
+        }
+        assert_noop!(
+            PeaqRBAC::assign_role_to_user(
+                RuntimeOrigin::signed(origin2),
+                origin2,
+                role_id,
+                user_id,
+                Some(5)
+            ),
+            Error::<Test>::StorageExceedsMaxBounds
+        );
+
+        // ...which does not affect the queue of the owner
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            Some(5)
+        ));
+        assert_eq!(PeaqRBAC::expiry_of(5, origin).len(), 1);
+    });
+}
+
+#[test]
+fn delegated_administration_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let admin = account_key("Iredia2");
+        let user_id = *b"12676474666576474646673646376637";
+        let role_id = *b"21676474666576474646673646376637";
+        let permission_id = *b"41664667364637663721676474666576";
+        let name = b"ADMIN";
+
+        // Without delegation other accounts cannot manage the owner's entities
+        assert_noop!(
+            PeaqRBAC::add_role(RuntimeOrigin::signed(admin), origin, role_id, name.to_vec()),
+            Error::<Test>::EntityAuthorizationFailed
+        );
+
+        assert_ok!(PeaqRBAC::add_delegate(
+            RuntimeOrigin::signed(origin),
+            admin,
+            Capabilities {
+                manage_users: true,
+                manage_roles: true,
+                manage_permissions: false,
+            }
+        ));
+
+        // Entities are created within the owner's tree
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(admin),
+            origin,
+            role_id,
+            name.to_vec()
+        ));
+        assert!(PeaqRBAC::get_role(&origin, role_id).is_ok());
+        assert!(PeaqRBAC::get_role(&admin, role_id).is_err());
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(admin),
+            origin,
+            role_id,
+            user_id,
+            None
+        ));
+
+        // Capabilities are scoped
+        assert_noop!(
+            PeaqRBAC::add_permission(
+                RuntimeOrigin::signed(admin),
+                origin,
+                permission_id,
+                name.to_vec()
+            ),
+            Error::<Test>::EntityAuthorizationFailed
+        );
+
+        // Delegations can only be removed by the owner who added them
+        assert_noop!(
+            PeaqRBAC::remove_delegate(RuntimeOrigin::signed(admin), origin),
+            Error::<Test>::AssignmentDoesNotExist
+        );
+
+        assert_ok!(PeaqRBAC::remove_delegate(
+            RuntimeOrigin::signed(origin),
+            admin
+        ));
+        assert_noop!(
+            PeaqRBAC::unassign_role_to_user(RuntimeOrigin::signed(admin), origin, role_id, user_id),
+            Error::<Test>::EntityAuthorizationFailed
+        );
+    });
+}
+
+#[test]
+fn ownership_transfer_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let new_owner = account_key("Iredia2");
+        let admin = account_key("Iredia3");
+        let other = account_key("Iredia4");
+        let user_id = *b"12676474666576474646673646376637";
+        let role_id = *b"21676474666576474646673646376637";
+        let group_id = *b"31676474666576474646673646376637";
+        let permission_id = *b"41664667364637663721676474666576";
+        let name = b"ADMIN";
+
+        System::set_block_number(1);
+        // Assignments of other domains are not touched by the transfer
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(other),
+            other,
+            role_id,
+            name.to_vec()
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(other),
+            other,
+            role_id,
+            user_id,
+            None
+        ));
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec()
+        ));
+        assert_ok!(PeaqRBAC::add_permission(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            name.to_vec()
+        ));
+        assert_ok!(PeaqRBAC::add_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            group_id,
+            name.to_vec()
+        ));
+        assert_ok!(PeaqRBAC::assign_permission_to_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            permission_id,
+            role_id
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            group_id
+        ));
+        assert_ok!(PeaqRBAC::assign_user_to_group(
+            RuntimeOrigin::signed(origin),
+            origin,
+            user_id,
+            group_id,
+            Some(10)
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            None
+        ));
+        assert_ok!(PeaqRBAC::add_delegate(
+            RuntimeOrigin::signed(origin),
+            admin,
+            Capabilities::default()
+        ));
+
+        // Transfers have to be proposed to the accepting account
+        assert_noop!(
+            PeaqRBAC::propose_ownership_transfer(RuntimeOrigin::signed(origin), origin),
+            Error::<Test>::InvalidOwnershipTransfer
+        );
+        assert_noop!(
+            PeaqRBAC::accept_ownership_transfer(RuntimeOrigin::signed(new_owner), origin),
+            Error::<Test>::OwnershipTransferDoesNotExist
+        );
+        assert_ok!(PeaqRBAC::propose_ownership_transfer(
+            RuntimeOrigin::signed(origin),
+            new_owner
+        ));
+        assert_noop!(
+            PeaqRBAC::accept_ownership_transfer(RuntimeOrigin::signed(admin), origin),
+            Error::<Test>::OwnershipTransferDoesNotExist
+        );
+
+        // Delegates of the new owner would be overwritten by the moved ones
+        assert_ok!(PeaqRBAC::add_delegate(
+            RuntimeOrigin::signed(new_owner),
+            admin,
+            Capabilities::default()
+        ));
+        assert_noop!(
+            PeaqRBAC::accept_ownership_transfer(RuntimeOrigin::signed(new_owner), origin),
+            Error::<Test>::InvalidOwnershipTransfer
+        );
+        assert_ok!(PeaqRBAC::remove_delegate(
+            RuntimeOrigin::signed(new_owner),
+            admin
+        ));
+        assert_ok!(PeaqRBAC::accept_ownership_transfer(
+            RuntimeOrigin::signed(new_owner),
+            origin
+        ));
+
+        // Both domains are locked during the transfer
+        assert_noop!(
+            PeaqRBAC::unassign_role_to_user(
+                RuntimeOrigin::signed(origin),
+                origin,
+                role_id,
+                user_id
+            ),
+            Error::<Test>::OwnershipTransferInProgress
+        );
+        assert_noop!(
+            PeaqRBAC::add_role(
+                RuntimeOrigin::signed(new_owner),
+                new_owner,
+                role_id,
+                name.to_vec()
+            ),
+            Error::<Test>::OwnershipTransferInProgress
+        );
+
+        // Moves the domain across multiple calls
+        assert_ok!(PeaqRBAC::continue_ownership_transfer(
+            RuntimeOrigin::signed(new_owner),
+            origin,
+            2
+        ));
+        assert!(PeaqRBAC::ownership_transfer_of(origin).is_some());
+        // The role's assignments are moved one at a time ahead of the role
+        assert!(PeaqRBAC::get_role(&origin, role_id).is_ok());
+        assert_eq!(
+            PeaqRBAC::get_role_users(&new_owner, role_id).unwrap().len(),
+            1
+        );
+        assert_ok!(PeaqRBAC::continue_ownership_transfer(
+            RuntimeOrigin::signed(new_owner),
+            origin,
+            100
+        ));
+        assert!(PeaqRBAC::ownership_transfer_of(origin).is_none());
+        System::assert_last_event(
+            Event::<Test>::OwnershipTransferCompleted(origin, new_owner).into(),
+        );
+
+        assert!(PeaqRBAC::get_role(&origin, role_id).is_err());
+        assert!(PeaqRBAC::get_role(&new_owner, role_id).is_ok());
+        assert!(!PeaqRBAC::has_permission(&origin, user_id, permission_id));
+        assert!(PeaqRBAC::has_permission(&new_owner, user_id, permission_id));
+        assert_eq!(
+            PeaqRBAC::get_user_groups(&new_owner, user_id)
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(PeaqRBAC::admin_to_owner_of(new_owner, admin).is_some());
+        assert!(PeaqRBAC::admin_to_owner_of(origin, admin).is_none());
+        assert_eq!(PeaqRBAC::get_user_roles(&other, user_id).unwrap().len(), 1);
+
+        // Expiry queue follows the assignment
+        System::set_block_number(10);
+        PeaqRBAC::on_idle(10, Weight::MAX);
+        assert!(PeaqRBAC::get_user_groups(&new_owner, user_id).is_err());
+
+        // The domain can be managed by the new owner only
+        assert_ok!(PeaqRBAC::unassign_role_to_user(
+            RuntimeOrigin::signed(new_owner),
+            new_owner,
+            role_id,
+            user_id
+        ));
+    });
+}
+
+#[test]
+fn integrity_test() {
+    new_test_ext().execute_with(|| {
+        <PeaqRBAC as Hooks<u64>>::integrity_test();
+    });
+}
+
+#[test]
+fn batch_apply_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let admin = account_key("Iredia2");
+        let user_id = *b"12676474666576474646673646376637";
+        let role_id = *b"21676474666576474646673646376637";
+        let group_id = *b"31676474666576474646673646376637";
+        let permission_id = *b"41664667364637663721676474666576";
+        let name = b"ADMIN";
+
+        let ops = vec![
+            RbacOp::AddRole {
+                role: role_id,
+                name: name.to_vec(),
+            },
+            RbacOp::AddPermission {
+                permission: permission_id,
+                name: name.to_vec(),
+            },
+            RbacOp::AddGroup {
+                group: group_id,
+                name: name.to_vec(),
+            },
+            RbacOp::AssignPermissionToRole {
+                permission: permission_id,
+                role: role_id,
+            },
+            RbacOp::AssignRoleToGroup {
+                role: role_id,
+                group: group_id,
+            },
+            RbacOp::AssignUserToGroup {
+                user: user_id,
+                group: group_id,
+                expires_at: Some(10),
+            },
+            RbacOp::AssignRoleToUser {
+                role: role_id,
+                user: user_id,
+                expires_at: None,
+            },
+        ];
+        assert_ok!(PeaqRBAC::batch_apply(
+            RuntimeOrigin::signed(origin),
+            origin,
+            ops.try_into().unwrap()
+        ));
+        assert!(PeaqRBAC::has_permission(&origin, user_id, permission_id));
+        // Expiring assignments are queued like by the corresponding calls
+        assert_eq!(PeaqRBAC::expiry_of(10, origin).len(), 1);
+
+        // Fails as a whole with the index of the failing operation
+        let ops = vec![
+            RbacOp::UnassignUserToGroup {
+                user: user_id,
+                group: group_id,
+            },
+            RbacOp::AddRole {
+                role: role_id,
+                name: name.to_vec(),
+            },
+        ];
+        assert_noop!(
+            PeaqRBAC::batch_apply(
+                RuntimeOrigin::signed(origin),
+                origin,
+                ops.try_into().unwrap()
+            ),
+            Error::<Test>::BatchOpFailed {
+                index: 1,
+                error: RbacErrorType::EntityAlreadyExist
+            }
+        );
+        assert!(PeaqRBAC::has_permission(&origin, user_id, permission_id));
+
+        // Capabilities of delegates are checked per operation
+        assert_ok!(PeaqRBAC::add_delegate(
+            RuntimeOrigin::signed(origin),
+            admin,
+            Capabilities {
+                manage_users: true,
+                manage_roles: false,
+                manage_permissions: false,
+            }
+        ));
+        let ops = vec![
+            RbacOp::UnassignUserToGroup {
+                user: user_id,
+                group: group_id,
+            },
+            RbacOp::DisableRole { role: role_id },
+        ];
+        assert_noop!(
+            PeaqRBAC::batch_apply(
+                RuntimeOrigin::signed(admin),
+                origin,
+                ops.try_into().unwrap()
+            ),
+            Error::<Test>::BatchOpFailed {
+                index: 1,
+                error: RbacErrorType::EntityAuthorizationFailed
+            }
+        );
+    });
+}
+
+#[test]
+fn migrate_to_v3_test() {
+    new_test_ext().execute_with(|| {
+        let origin = account_key("Iredia");
+        let user_id = *b"12676474666576474646673646376637";
+        let user_id2 = *b"12676474666576474646673646376638";
+        let role_id = *b"21676474666576474646673646376637";
+        let unknown_id = *b"21676474666576474646673646376639";
+        let name = b"ADMIN";
+
+        assert_ok!(PeaqRBAC::add_role(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            name.to_vec()
+        ));
+        assert_ok!(PeaqRBAC::assign_role_to_user(
+            RuntimeOrigin::signed(origin),
+            origin,
+            role_id,
+            user_id,
+            None
+        ));
+
+        // Drop the index and add an assignment of a role, which does not exist anymore
+        let _ = RoleUsersStore::<Test>::clear(u32::MAX, None);
+        let key = PeaqRBAC::generate_key(&origin, &user_id2, Tag::Role2User);
+        let orphan = Role2User {
+            role: unknown_id,
+            user: user_id2,
+            expires_at: None,
+        };
+        Role2UserStore::<Test>::insert(key, BoundedVec::truncate_from(vec![orphan]));
+        StorageVersion::new(2).put::<PeaqRBAC>();
+
+        MigrateToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(PeaqRBAC::on_chain_storage_version(), StorageVersion::new(3));
+        assert_eq!(
+            PeaqRBAC::get_role_users(&origin, role_id).unwrap(),
+            vec![Role2User {
+                role: role_id,
+                user: user_id,
+                expires_at: None
+            }]
+        );
+        assert!(PeaqRBAC::get_role_users(&origin, unknown_id).is_err());
     });
 }
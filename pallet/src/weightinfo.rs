@@ -32,4 +32,28 @@ pub trait WeightInfo {
     fn fetch_user_groups() -> Weight;
     fn fetch_user_permissions() -> Weight;
     fn fetch_group_permissions() -> Weight;
+    fn has_permission(r: u32, g: u32, d: u32) -> Weight;
+    fn delete_role(u: u32) -> Weight;
+    fn delete_permission() -> Weight;
+    fn delete_group(u: u32) -> Weight;
+    fn enable_role() -> Weight;
+    fn enable_permission() -> Weight;
+    fn enable_group() -> Weight;
+    fn assign_role_to_role() -> Weight;
+    fn unassign_role_to_role() -> Weight;
+    fn assign_group_to_group() -> Weight;
+    fn unassign_group_to_group() -> Weight;
+    fn add_role_constraint(r: u32) -> Weight;
+    fn remove_role_constraint() -> Weight;
+    fn add_delegate() -> Weight;
+    fn remove_delegate() -> Weight;
+    fn propose_ownership_transfer() -> Weight;
+    fn accept_ownership_transfer() -> Weight;
+    fn continue_ownership_transfer(e: u32) -> Weight;
+    fn add_user() -> Weight;
+    fn update_user() -> Weight;
+    fn disable_user() -> Weight;
+    fn claim_user() -> Weight;
+    fn approve_user_claim() -> Weight;
+    fn unbind_user() -> Weight;
 }
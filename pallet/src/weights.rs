@@ -31,6 +31,12 @@ use core::marker::PhantomData;
 /// Weight functions for `peaq_pallet_rbac`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
 	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac RoleStore (r:1 w:1)
@@ -42,9 +48,15 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 25_370_000 picoseconds.
 		Weight::from_parts(25_732_000, 0)
 			.saturating_add(Weight::from_parts(0, 3507))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
 	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac RoleStore (r:1 w:1)
@@ -56,9 +68,15 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 38_314_000 picoseconds.
 		Weight::from_parts(39_387_000, 0)
 			.saturating_add(Weight::from_parts(0, 3734))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
 	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac RoleStore (r:1 w:1)
@@ -70,7 +88,7 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 39_744_000 picoseconds.
 		Weight::from_parts(40_437_000, 0)
 			.saturating_add(Weight::from_parts(0, 3734))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:0)
@@ -95,22 +113,52 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 3697))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
-	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:0)
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac KeysLookUpStore (r:2 w:0)
 	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleConstraintStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac RoleConstraintStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac Role2UserStore (r:1 w:1)
 	/// Proof Skipped: PeaqRbac Role2UserStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac User2GroupStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac User2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Group2GroupStore (r:256 w:0)
+	/// Proof Skipped: PeaqRbac Group2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2GroupStore (r:256 w:0)
+	/// Proof Skipped: PeaqRbac Role2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2RoleStore (r:4 w:0)
+	/// Proof Skipped: PeaqRbac Role2RoleStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac ExpiryStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac ExpiryStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac ExpiryCursor (r:1 w:1)
+	/// Proof Skipped: PeaqRbac ExpiryCursor (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleUsersStore (r:0 w:1)
+	/// Proof Skipped: PeaqRbac RoleUsersStore (max_values: None, max_size: None, mode: Measured)
 	fn assign_role_to_user() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `181`
-		//  Estimated: `3646`
-		// Minimum execution time: 24_055_000 picoseconds.
-		Weight::from_parts(24_463_000, 0)
-			.saturating_add(Weight::from_parts(0, 3646))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+		//  Measured:  `53470`
+		//  Estimated: `1334285`
+		// Minimum execution time: 4_731_562_000 picoseconds.
+		Weight::from_parts(4_802_137_000, 0)
+			.saturating_add(Weight::from_parts(0, 1334285))
+			.saturating_add(T::DbWeight::get().reads(526))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac Role2UserStore (r:1 w:1)
 	/// Proof Skipped: PeaqRbac Role2UserStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleUsersStore (r:0 w:1)
+	/// Proof Skipped: PeaqRbac RoleUsersStore (max_values: None, max_size: None, mode: Measured)
 	fn unassign_role_to_user() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `251`
@@ -118,25 +166,55 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 24_151_000 picoseconds.
 		Weight::from_parts(24_448_000, 0)
 			.saturating_add(Weight::from_parts(0, 3716))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac KeysLookUpStore (r:2 w:0)
 	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
-	/// Storage: PeaqRbac Role2GroupStore (r:1 w:1)
+	/// Storage: PeaqRbac RoleConstraintStore (r:257 w:0)
+	/// Proof Skipped: PeaqRbac RoleConstraintStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2GroupStore (r:513 w:1)
 	/// Proof Skipped: PeaqRbac Role2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac GroupStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Group2GroupStore (r:1022 w:0)
+	/// Proof Skipped: PeaqRbac Group2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac GroupUsersStore (r:767 w:0)
+	/// Proof Skipped: PeaqRbac GroupUsersStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2UserStore (r:256 w:0)
+	/// Proof Skipped: PeaqRbac Role2UserStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac User2GroupStore (r:256 w:0)
+	/// Proof Skipped: PeaqRbac User2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2RoleStore (r:512 w:0)
+	/// Proof Skipped: PeaqRbac Role2RoleStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleGroupsStore (r:0 w:1)
+	/// Proof Skipped: PeaqRbac RoleGroupsStore (max_values: None, max_size: None, mode: Measured)
 	fn assign_role_to_group() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `304`
-		//  Estimated: `6244`
-		// Minimum execution time: 27_972_000 picoseconds.
-		Weight::from_parts(29_753_000, 0)
-			.saturating_add(Weight::from_parts(0, 6244))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(1))
+		//  Measured:  `402117`
+		//  Estimated: `9348630`
+		// Minimum execution time: 31_926_417_000 picoseconds.
+		Weight::from_parts(32_318_904_000, 0)
+			.saturating_add(Weight::from_parts(0, 9348630))
+			.saturating_add(T::DbWeight::get().reads(3590))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac Role2GroupStore (r:1 w:1)
 	/// Proof Skipped: PeaqRbac Role2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleGroupsStore (r:0 w:1)
+	/// Proof Skipped: PeaqRbac RoleGroupsStore (max_values: None, max_size: None, mode: Measured)
 	fn unassign_role_to_group() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `284`
@@ -144,8 +222,8 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 25_932_000 picoseconds.
 		Weight::from_parts(27_224_000, 0)
 			.saturating_add(Weight::from_parts(0, 3749))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: PeaqRbac Role2UserStore (r:1 w:0)
 	/// Proof Skipped: PeaqRbac Role2UserStore (max_values: None, max_size: None, mode: Measured)
@@ -158,6 +236,12 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 3716))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
 	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac PermissionStore (r:1 w:1)
@@ -169,9 +253,15 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 25_332_000 picoseconds.
 		Weight::from_parts(26_098_000, 0)
 			.saturating_add(Weight::from_parts(0, 3507))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
 	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac PermissionStore (r:1 w:1)
@@ -183,9 +273,15 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 40_613_000 picoseconds.
 		Weight::from_parts(44_704_000, 0)
 			.saturating_add(Weight::from_parts(0, 3744))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
 	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac PermissionStore (r:1 w:1)
@@ -197,7 +293,7 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 40_220_000 picoseconds.
 		Weight::from_parts(41_348_000, 0)
 			.saturating_add(Weight::from_parts(0, 3744))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:0)
@@ -222,10 +318,18 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 3707))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac KeysLookUpStore (r:2 w:0)
 	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac Permission2RoleStore (r:1 w:1)
 	/// Proof Skipped: PeaqRbac Permission2RoleStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac PermissionRolesStore (r:0 w:1)
+	/// Proof Skipped: PeaqRbac PermissionRolesStore (max_values: None, max_size: None, mode: Measured)
 	fn assign_permission_to_role() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `304`
@@ -233,11 +337,19 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 27_785_000 picoseconds.
 		Weight::from_parts(28_486_000, 0)
 			.saturating_add(Weight::from_parts(0, 6244))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac Permission2RoleStore (r:1 w:1)
 	/// Proof Skipped: PeaqRbac Permission2RoleStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac PermissionRolesStore (r:0 w:1)
+	/// Proof Skipped: PeaqRbac PermissionRolesStore (max_values: None, max_size: None, mode: Measured)
 	fn unassign_permission_to_role() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `284`
@@ -245,8 +357,8 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 24_329_000 picoseconds.
 		Weight::from_parts(26_495_000, 0)
 			.saturating_add(Weight::from_parts(0, 3749))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: PeaqRbac Permission2RoleStore (r:1 w:0)
 	/// Proof Skipped: PeaqRbac Permission2RoleStore (max_values: None, max_size: None, mode: Measured)
@@ -259,6 +371,12 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 3749))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
 	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac GroupStore (r:1 w:1)
@@ -270,9 +388,15 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 25_672_000 picoseconds.
 		Weight::from_parts(28_467_000, 0)
 			.saturating_add(Weight::from_parts(0, 3507))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
 	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac GroupStore (r:1 w:1)
@@ -284,9 +408,15 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 38_607_000 picoseconds.
 		Weight::from_parts(40_654_000, 0)
 			.saturating_add(Weight::from_parts(0, 3734))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
 	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac GroupStore (r:1 w:1)
@@ -298,7 +428,7 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 37_844_000 picoseconds.
 		Weight::from_parts(38_574_000, 0)
 			.saturating_add(Weight::from_parts(0, 3734))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:0)
@@ -323,22 +453,52 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 3697))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
-	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:0)
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac KeysLookUpStore (r:2 w:0)
 	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleConstraintStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac RoleConstraintStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac User2GroupStore (r:1 w:1)
 	/// Proof Skipped: PeaqRbac User2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2UserStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Role2UserStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Group2GroupStore (r:257 w:0)
+	/// Proof Skipped: PeaqRbac Group2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2GroupStore (r:258 w:0)
+	/// Proof Skipped: PeaqRbac Role2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2RoleStore (r:4 w:0)
+	/// Proof Skipped: PeaqRbac Role2RoleStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac ExpiryStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac ExpiryStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac ExpiryCursor (r:1 w:1)
+	/// Proof Skipped: PeaqRbac ExpiryCursor (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: PeaqRbac GroupUsersStore (r:0 w:1)
+	/// Proof Skipped: PeaqRbac GroupUsersStore (max_values: None, max_size: None, mode: Measured)
 	fn assign_user_to_group() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `181`
-		//  Estimated: `3646`
-		// Minimum execution time: 25_279_000 picoseconds.
-		Weight::from_parts(26_027_000, 0)
-			.saturating_add(Weight::from_parts(0, 3646))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+		//  Measured:  `53912`
+		//  Estimated: `1339472`
+		// Minimum execution time: 4_774_308_000 picoseconds.
+		Weight::from_parts(4_846_590_000, 0)
+			.saturating_add(Weight::from_parts(0, 1339472))
+			.saturating_add(T::DbWeight::get().reads(529))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
 	/// Storage: PeaqRbac User2GroupStore (r:1 w:1)
 	/// Proof Skipped: PeaqRbac User2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac GroupUsersStore (r:0 w:1)
+	/// Proof Skipped: PeaqRbac GroupUsersStore (max_values: None, max_size: None, mode: Measured)
 	fn unassign_user_to_group() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `251`
@@ -346,8 +506,8 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 25_095_000 picoseconds.
 		Weight::from_parts(25_682_000, 0)
 			.saturating_add(Weight::from_parts(0, 3716))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: PeaqRbac User2GroupStore (r:1 w:0)
 	/// Proof Skipped: PeaqRbac User2GroupStore (max_values: None, max_size: None, mode: Measured)
@@ -405,4 +565,591 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 3749))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
+	/// Storage: PeaqRbac KeysLookUpStore (r:206 w:0)
+	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleConstraintStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac RoleConstraintStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2UserStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Role2UserStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2RoleStore (r:104 w:0)
+	/// Proof Skipped: PeaqRbac Role2RoleStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Permission2RoleStore (r:104 w:0)
+	/// Proof Skipped: PeaqRbac Permission2RoleStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac User2GroupStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac User2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Group2GroupStore (r:100 w:0)
+	/// Proof Skipped: PeaqRbac Group2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2GroupStore (r:100 w:0)
+	/// Proof Skipped: PeaqRbac Role2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[1, 100]`.
+	/// The range of component `g` is `[1, 100]`.
+	/// The range of component `d` is `[0, 3]`.
+	fn has_permission(r: u32, g: u32, d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1189 + r * (232 ±0) + g * (199 ±0) + d * (233 ±0)`
+		//  Estimated: `14106 + r * (7656 ±0) + g * (7464 ±0) + d * (7659 ±0)`
+		// Minimum execution time: 71_254_000 picoseconds.
+		Weight::from_parts(24_871_305, 0)
+			.saturating_add(Weight::from_parts(0, 14106))
+			// Standard Error: 9_847
+			.saturating_add(Weight::from_parts(23_512_904, 0).saturating_mul(r.into()))
+			// Standard Error: 9_847
+			.saturating_add(Weight::from_parts(22_937_716, 0).saturating_mul(g.into()))
+			// Standard Error: 331_460
+			.saturating_add(Weight::from_parts(23_861_029, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(g.into())))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 7656).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 7464).saturating_mul(g.into()))
+			.saturating_add(Weight::from_parts(0, 7659).saturating_mul(d.into()))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac RoleStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleConstraintStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac RoleConstraintStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Permission2RoleStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac Permission2RoleStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac PermissionRolesStore (r:0 w:256)
+	/// Proof Skipped: PeaqRbac PermissionRolesStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2RoleStore (r:256 w:256)
+	/// Proof Skipped: PeaqRbac Role2RoleStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleGroupsStore (r:257 w:256)
+	/// Proof Skipped: PeaqRbac RoleGroupsStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2GroupStore (r:256 w:256)
+	/// Proof Skipped: PeaqRbac Role2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleUsersStore (r:101 w:100)
+	/// Proof Skipped: PeaqRbac RoleUsersStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2UserStore (r:100 w:100)
+	/// Proof Skipped: PeaqRbac Role2UserStore (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `u` is `[0, 100]`.
+	fn delete_role(u: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `143582 + u * (143 ±0)`
+		//  Estimated: `2004417 + u * (2618 ±0)`
+		// Minimum execution time: 7_431_906_000 picoseconds.
+		Weight::from_parts(7_486_212_418, 0)
+			.saturating_add(Weight::from_parts(0, 2004417))
+			// Standard Error: 12_884
+			.saturating_add(Weight::from_parts(15_902_377, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(777))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().writes(1029))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(u.into())))
+			.saturating_add(Weight::from_parts(0, 2618).saturating_mul(u.into()))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac PermissionStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac PermissionStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac PermissionRolesStore (r:257 w:256)
+	/// Proof Skipped: PeaqRbac PermissionRolesStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Permission2RoleStore (r:256 w:256)
+	/// Proof Skipped: PeaqRbac Permission2RoleStore (max_values: None, max_size: None, mode: Measured)
+	fn delete_permission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `112338`
+		//  Estimated: `1343573`
+		// Minimum execution time: 4_012_845_000 picoseconds.
+		Weight::from_parts(4_073_519_000, 0)
+			.saturating_add(Weight::from_parts(0, 1343573))
+			.saturating_add(T::DbWeight::get().reads(518))
+			.saturating_add(T::DbWeight::get().writes(514))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac GroupStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2GroupStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac Role2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleGroupsStore (r:0 w:256)
+	/// Proof Skipped: PeaqRbac RoleGroupsStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Group2GroupStore (r:256 w:256)
+	/// Proof Skipped: PeaqRbac Group2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac GroupUsersStore (r:101 w:100)
+	/// Proof Skipped: PeaqRbac GroupUsersStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac User2GroupStore (r:100 w:100)
+	/// Proof Skipped: PeaqRbac User2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `u` is `[0, 100]`.
+	fn delete_group(u: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `71276 + u * (143 ±0)`
+		//  Estimated: `678912 + u * (2618 ±0)`
+		// Minimum execution time: 2_146_281_000 picoseconds.
+		Weight::from_parts(2_171_840_240, 0)
+			.saturating_add(Weight::from_parts(0, 678912))
+			// Standard Error: 11_306
+			.saturating_add(Weight::from_parts(15_644_781, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(263))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().writes(515))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(u.into())))
+			.saturating_add(Weight::from_parts(0, 2618).saturating_mul(u.into()))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac RoleStore (max_values: None, max_size: None, mode: Measured)
+	fn enable_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `270`
+		//  Estimated: `3735`
+		// Minimum execution time: 39_215_000 picoseconds.
+		Weight::from_parts(40_102_000, 0)
+			.saturating_add(Weight::from_parts(0, 3735))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac PermissionStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac PermissionStore (max_values: None, max_size: None, mode: Measured)
+	fn enable_permission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `270`
+		//  Estimated: `3735`
+		// Minimum execution time: 38_870_000 picoseconds.
+		Weight::from_parts(39_654_000, 0)
+			.saturating_add(Weight::from_parts(0, 3735))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac GroupStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac GroupStore (max_values: None, max_size: None, mode: Measured)
+	fn enable_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `270`
+		//  Estimated: `3735`
+		// Minimum execution time: 38_991_000 picoseconds.
+		Weight::from_parts(39_803_000, 0)
+			.saturating_add(Weight::from_parts(0, 3735))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac KeysLookUpStore (r:2 w:0)
+	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleConstraintStore (r:510 w:0)
+	/// Proof Skipped: PeaqRbac RoleConstraintStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2RoleStore (r:1278 w:1)
+	/// Proof Skipped: PeaqRbac Role2RoleStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac RoleStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleGroupsStore (r:253 w:0)
+	/// Proof Skipped: PeaqRbac RoleGroupsStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleUsersStore (r:253 w:0)
+	/// Proof Skipped: PeaqRbac RoleUsersStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac GroupStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Group2GroupStore (r:1022 w:0)
+	/// Proof Skipped: PeaqRbac Group2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac GroupUsersStore (r:767 w:0)
+	/// Proof Skipped: PeaqRbac GroupUsersStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2GroupStore (r:512 w:0)
+	/// Proof Skipped: PeaqRbac Role2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2UserStore (r:256 w:0)
+	/// Proof Skipped: PeaqRbac Role2UserStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac User2GroupStore (r:256 w:0)
+	/// Proof Skipped: PeaqRbac User2GroupStore (max_values: None, max_size: None, mode: Measured)
+	fn assign_role_to_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `597384`
+		//  Estimated: `13926745`
+		// Minimum execution time: 47_615_380_000 picoseconds.
+		Weight::from_parts(48_207_733_000, 0)
+			.saturating_add(Weight::from_parts(0, 13926745))
+			.saturating_add(T::DbWeight::get().reads(5114))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2RoleStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac Role2RoleStore (max_values: None, max_size: None, mode: Measured)
+	fn unassign_role_to_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `284`
+		//  Estimated: `3749`
+		// Minimum execution time: 25_861_000 picoseconds.
+		Weight::from_parts(26_903_000, 0)
+			.saturating_add(Weight::from_parts(0, 3749))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac KeysLookUpStore (r:2 w:0)
+	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleConstraintStore (r:256 w:0)
+	/// Proof Skipped: PeaqRbac RoleConstraintStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Group2GroupStore (r:1025 w:1)
+	/// Proof Skipped: PeaqRbac Group2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac GroupStore (r:2 w:0)
+	/// Proof Skipped: PeaqRbac GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2GroupStore (r:514 w:0)
+	/// Proof Skipped: PeaqRbac Role2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac GroupUsersStore (r:764 w:0)
+	/// Proof Skipped: PeaqRbac GroupUsersStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2UserStore (r:255 w:0)
+	/// Proof Skipped: PeaqRbac Role2UserStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac User2GroupStore (r:255 w:0)
+	/// Proof Skipped: PeaqRbac User2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2RoleStore (r:510 w:0)
+	/// Proof Skipped: PeaqRbac Role2RoleStore (max_values: None, max_size: None, mode: Measured)
+	fn assign_group_to_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `401650`
+		//  Estimated: `9336242`
+		// Minimum execution time: 31_811_295_000 picoseconds.
+		Weight::from_parts(32_201_466_000, 0)
+			.saturating_add(Weight::from_parts(0, 9336242))
+			.saturating_add(T::DbWeight::get().reads(3586))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Group2GroupStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac Group2GroupStore (max_values: None, max_size: None, mode: Measured)
+	fn unassign_group_to_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `284`
+		//  Estimated: `3749`
+		// Minimum execution time: 25_790_000 picoseconds.
+		Weight::from_parts(26_812_000, 0)
+			.saturating_add(Weight::from_parts(0, 3749))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleConstraintStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac RoleConstraintStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac KeysLookUpStore (r:256 w:0)
+	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac RoleStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2RoleStore (r:512 w:0)
+	/// Proof Skipped: PeaqRbac Role2RoleStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleGroupsStore (r:256 w:0)
+	/// Proof Skipped: PeaqRbac RoleGroupsStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleUsersStore (r:256 w:0)
+	/// Proof Skipped: PeaqRbac RoleUsersStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac GroupStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Group2GroupStore (r:1022 w:0)
+	/// Proof Skipped: PeaqRbac Group2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac GroupUsersStore (r:767 w:0)
+	/// Proof Skipped: PeaqRbac GroupUsersStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2GroupStore (r:512 w:0)
+	/// Proof Skipped: PeaqRbac Role2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2UserStore (r:256 w:0)
+	/// Proof Skipped: PeaqRbac Role2UserStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac User2GroupStore (r:256 w:0)
+	/// Proof Skipped: PeaqRbac User2GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[2, 256]`.
+	fn add_role_constraint(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `401842 + r * (1040 ±0)`
+		//  Estimated: `9341158 + r * (10479 ±0)`
+		// Minimum execution time: 31_958_073_000 picoseconds.
+		Weight::from_parts(32_004_512_306, 0)
+			.saturating_add(Weight::from_parts(0, 9341158))
+			// Standard Error: 21_674
+			.saturating_add(Weight::from_parts(36_418_209, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3328))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 10479).saturating_mul(r.into()))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleConstraintStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac RoleConstraintStore (max_values: None, max_size: None, mode: Measured)
+	fn remove_role_constraint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `261`
+		//  Estimated: `3726`
+		// Minimum execution time: 22_318_000 picoseconds.
+		Weight::from_parts(23_004_000, 0)
+			.saturating_add(Weight::from_parts(0, 3726))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: PeaqRbac Admin2OwnerStore (r:0 w:1)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	fn add_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_171_000 picoseconds.
+		Weight::from_parts(12_603_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	fn remove_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178`
+		//  Estimated: `3643`
+		// Minimum execution time: 16_402_000 picoseconds.
+		Weight::from_parts(16_911_000, 0)
+			.saturating_add(Weight::from_parts(0, 3643))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	fn propose_ownership_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `7082`
+		// Minimum execution time: 18_212_000 picoseconds.
+		Weight::from_parts(18_846_000, 0)
+			.saturating_add(Weight::from_parts(0, 7082))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:2 w:1)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:2 w:1)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac RoleStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac PermissionStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac PermissionStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac GroupStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac GroupStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleConstraintStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac RoleConstraintStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	fn accept_ownership_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		//  Estimated: `21874`
+		// Minimum execution time: 36_531_000 picoseconds.
+		Weight::from_parts(37_402_000, 0)
+			.saturating_add(Weight::from_parts(0, 21874))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleConstraintStore (r:100 w:0)
+	/// Proof Skipped: PeaqRbac RoleConstraintStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleStore (r:100 w:0)
+	/// Proof Skipped: PeaqRbac RoleStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac RoleUsersStore (r:100 w:200)
+	/// Proof Skipped: PeaqRbac RoleUsersStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Role2UserStore (r:200 w:200)
+	/// Proof Skipped: PeaqRbac Role2UserStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac ExpiryStore (r:200 w:200)
+	/// Proof Skipped: PeaqRbac ExpiryStore (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `e` is `[1, 100]`.
+	fn continue_ownership_transfer(e: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `327 + e * (245 ±0)`
+		//  Estimated: `6267 + e * (5190 ±0)`
+		// Minimum execution time: 48_911_000 picoseconds.
+		Weight::from_parts(24_306_218, 0)
+			.saturating_add(Weight::from_parts(0, 6267))
+			// Standard Error: 27_815
+			.saturating_add(Weight::from_parts(41_752_904, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 5190).saturating_mul(e.into()))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac UserStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac UserStore (max_values: None, max_size: None, mode: Measured)
+	fn add_user() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3507`
+		// Minimum execution time: 25_809_000 picoseconds.
+		Weight::from_parts(28_512_000, 0)
+			.saturating_add(Weight::from_parts(0, 3507))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac UserStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac UserStore (max_values: None, max_size: None, mode: Measured)
+	fn update_user() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `269`
+		//  Estimated: `3734`
+		// Minimum execution time: 38_215_000 picoseconds.
+		Weight::from_parts(40_163_000, 0)
+			.saturating_add(Weight::from_parts(0, 3734))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac UserStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac UserStore (max_values: None, max_size: None, mode: Measured)
+	fn disable_user() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `269`
+		//  Estimated: `3734`
+		// Minimum execution time: 37_402_000 picoseconds.
+		Weight::from_parts(38_291_000, 0)
+			.saturating_add(Weight::from_parts(0, 3734))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Account2UserStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Account2UserStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac User2AccountStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac User2AccountStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac UserClaimStore (r:0 w:1)
+	/// Proof Skipped: PeaqRbac UserClaimStore (max_values: None, max_size: None, mode: Measured)
+	fn claim_user() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `269`
+		//  Estimated: `3734`
+		// Minimum execution time: 31_127_000 picoseconds.
+		Weight::from_parts(32_604_000, 0)
+			.saturating_add(Weight::from_parts(0, 3734))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac UserClaimStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac UserClaimStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac User2AccountStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac User2AccountStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac KeysLookUpStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac KeysLookUpStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Account2UserStore (r:1 w:1)
+	/// Proof Skipped: PeaqRbac Account2UserStore (max_values: None, max_size: None, mode: Measured)
+	fn approve_user_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 36_880_000 picoseconds.
+		Weight::from_parts(38_045_000, 0)
+			.saturating_add(Weight::from_parts(0, 3877))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: PeaqRbac IncomingOwnershipStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac IncomingOwnershipStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Admin2OwnerStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac Admin2OwnerStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac OwnershipTransferStore (r:1 w:0)
+	/// Proof Skipped: PeaqRbac OwnershipTransferStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac User2AccountStore (r:2 w:1)
+	/// Proof Skipped: PeaqRbac User2AccountStore (max_values: None, max_size: None, mode: Measured)
+	/// Storage: PeaqRbac Account2UserStore (r:0 w:1)
+	/// Proof Skipped: PeaqRbac Account2UserStore (max_values: None, max_size: None, mode: Measured)
+	fn unbind_user() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `335`
+		//  Estimated: `3800`
+		// Minimum execution time: 27_391_000 picoseconds.
+		Weight::from_parts(28_710_000, 0)
+			.saturating_add(Weight::from_parts(0, 3800))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
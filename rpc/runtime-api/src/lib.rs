@@ -8,38 +8,97 @@ use codec::Codec;
 use peaq_pallet_rbac::{
     error::RbacError,
     rbac::Result as RbacResult,
-    structs::{Entity, Permission2Role, Role2Group, Role2User, User2Group},
+    structs::{
+        Entity, LegacyRole2User, LegacyUser2Group, Permission2Role, PermissionGrant, RbacDomain,
+        Role2Group, Role2User, User2Group,
+    },
 };
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
-    pub trait PeaqRBACRuntimeApi<AccountId, EntityId>
+    /// Version 2 resolves expiring assignments by `BlockNumber`, methods added later on are
+    /// annotated with the version introducing them. Runtimes implement the latest version
+    /// by `#[api_version(9)]`.
+    #[api_version(2)]
+    pub trait PeaqRBACRuntimeApi<AccountId, EntityId, BlockNumber>
     where
         AccountId: Codec,
-        EntityId: Codec
+        EntityId: Codec,
+        BlockNumber: Codec
     {
         fn fetch_role(account: AccountId, entity: EntityId) -> RbacResult<Entity<EntityId>, RbacError>;
 
         fn fetch_roles(owner: AccountId) -> RbacResult<Vec<Entity<EntityId>>, RbacError>;
 
-        fn fetch_user_roles(owner: AccountId, user_id: EntityId) -> RbacResult<Vec<Role2User<EntityId>>, RbacError>;
+        #[api_version(5)]
+        fn fetch_roles_page(owner: AccountId, start_after: Option<EntityId>, limit: u32) -> RbacResult<Vec<Entity<EntityId>>, RbacError>;
+
+        #[changed_in(2)]
+        fn fetch_user_roles(owner: AccountId, user_id: EntityId) -> RbacResult<Vec<LegacyRole2User<EntityId>>, RbacError>;
+
+        fn fetch_user_roles(owner: AccountId, user_id: EntityId) -> RbacResult<Vec<Role2User<EntityId, BlockNumber>>, RbacError>;
+
+        #[api_version(5)]
+        fn fetch_user_roles_page(owner: AccountId, user_id: EntityId, start_after: Option<EntityId>, limit: u32) -> RbacResult<Vec<Role2User<EntityId, BlockNumber>>, RbacError>;
 
         fn fetch_permission(owner: AccountId, permission_id: EntityId) -> RbacResult<Entity<EntityId>, RbacError>;
 
         fn fetch_permissions(owner: AccountId) -> RbacResult<Vec<Entity<EntityId>>, RbacError>;
 
+        #[api_version(5)]
+        fn fetch_permissions_page(owner: AccountId, start_after: Option<EntityId>, limit: u32) -> RbacResult<Vec<Entity<EntityId>>, RbacError>;
+
         fn fetch_role_permissions(owner: AccountId, role_id: EntityId) -> RbacResult<Vec<Permission2Role<EntityId>>, RbacError>;
 
         fn fetch_group(owner: AccountId, group_id: EntityId) -> RbacResult<Entity<EntityId>, RbacError>;
 
         fn fetch_groups(owner: AccountId) -> RbacResult<Vec<Entity<EntityId>>, RbacError>;
 
+        #[api_version(5)]
+        fn fetch_groups_page(owner: AccountId, start_after: Option<EntityId>, limit: u32) -> RbacResult<Vec<Entity<EntityId>>, RbacError>;
+
         fn fetch_group_roles(owner: AccountId, group_id: EntityId) -> RbacResult<Vec<Role2Group<EntityId>>, RbacError>;
 
-        fn fetch_user_groups(owner: AccountId, user_id: EntityId) -> RbacResult<Vec<User2Group<EntityId>>, RbacError>;
+        #[api_version(5)]
+        fn fetch_group_roles_page(owner: AccountId, group_id: EntityId, start_after: Option<EntityId>, limit: u32) -> RbacResult<Vec<Role2Group<EntityId>>, RbacError>;
+
+        #[changed_in(2)]
+        fn fetch_user_groups(owner: AccountId, user_id: EntityId) -> RbacResult<Vec<LegacyUser2Group<EntityId>>, RbacError>;
+
+        fn fetch_user_groups(owner: AccountId, user_id: EntityId) -> RbacResult<Vec<User2Group<EntityId, BlockNumber>>, RbacError>;
+
+        #[api_version(6)]
+        fn fetch_role_users(owner: AccountId, role_id: EntityId) -> RbacResult<Vec<Role2User<EntityId, BlockNumber>>, RbacError>;
+
+        #[api_version(6)]
+        fn fetch_group_members(owner: AccountId, group_id: EntityId) -> RbacResult<Vec<User2Group<EntityId, BlockNumber>>, RbacError>;
+
+        #[api_version(6)]
+        fn fetch_role_groups(owner: AccountId, role_id: EntityId) -> RbacResult<Vec<Role2Group<EntityId>>, RbacError>;
+
+        #[api_version(6)]
+        fn fetch_permission_roles(owner: AccountId, permission_id: EntityId) -> RbacResult<Vec<Permission2Role<EntityId>>, RbacError>;
 
         fn fetch_user_permissions(owner: AccountId, user_id: EntityId) -> RbacResult<Vec<Entity<EntityId>>, RbacError>;
 
         fn fetch_group_permissions(owner: AccountId, group_id: EntityId) -> RbacResult<Vec<Entity<EntityId>>, RbacError>;
+
+        #[api_version(7)]
+        fn fetch_user(owner: AccountId, user_id: EntityId) -> RbacResult<Entity<EntityId>, RbacError>;
+
+        #[api_version(7)]
+        fn fetch_users(owner: AccountId) -> RbacResult<Vec<Entity<EntityId>>, RbacError>;
+
+        #[api_version(3)]
+        fn check_permission(owner: AccountId, user_id: EntityId, permission_id: EntityId) -> RbacResult<bool, RbacError>;
+
+        #[api_version(4)]
+        fn check_permissions(owner: AccountId, checks: Vec<(EntityId, EntityId)>) -> Vec<RbacResult<bool, RbacError>>;
+
+        #[api_version(8)]
+        fn explain_user_permission(owner: AccountId, user_id: EntityId, permission_id: EntityId) -> RbacResult<Vec<PermissionGrant<EntityId>>, RbacError>;
+
+        #[api_version(9)]
+        fn export_domain(owner: AccountId) -> RbacResult<RbacDomain<EntityId, BlockNumber>, RbacError>;
     }
 }
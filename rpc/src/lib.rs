@@ -1,40 +1,67 @@
 //! RPC of Peaq-pallet-RBAC.
-use codec::Codec;
+use codec::{Codec, Decode, DecodeAll};
 use jsonrpsee::{
     core::{async_trait, Error as JsonRpseeError, RpcResult},
     proc_macros::rpc,
     types::error::{CallError, ErrorObject},
 };
-use sp_api::{ApiError, ProvideRuntimeApi};
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiError, ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
 use sp_std::vec::Vec;
 use std::{convert::From, sync::Arc};
 
 use peaq_pallet_rbac::{
-    error::RbacError,
+    error::{RbacError, RbacErrorType},
     rbac::Result as RbacResult,
-    structs::{Entity, Permission2Role, Role2Group, Role2User, User2Group},
+    structs::{
+        Entity, Permission2Role, PermissionGrant, RbacDomain, Role2Group, Role2User, User2Group,
+    },
 };
 pub use peaq_pallet_rbac_runtime_api::PeaqRBACRuntimeApi;
 
-pub type Result<T> = RpcResult<RbacResult<T, RbacError>>;
+/// Response of the RPC methods. By default the `RbacResult` of the runtime is nested, in flat
+/// mode the value is returned directly and RBAC errors become JSON-RPC errors.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Response<T> {
+    Nested(RbacResult<T, RbacError>),
+    Flat(T),
+}
+
+pub type Result<T> = RpcResult<Response<T>>;
 
-/// Trait defines RBAC-RPC interface
+/// Maximum number of checks of a single `peaqrbac_checkPermissions` request
+pub const MAX_PERMISSION_CHECKS: usize = 256;
+
+/// Trait defines RBAC-RPC interface, methods are evaluated at the best block if `at` is omitted
+/// and `*Page` methods return at most `limit` items ordered by id, following the id `start_after`
 #[rpc(client, server)]
-pub trait PeaqRBACApi<BlockHash, AccountId, EntityId> {
+pub trait PeaqRBACApi<BlockHash, AccountId, EntityId, BlockNumber> {
     /// RPC method for extrinsic call fetchRole
     #[method(name = "peaqrbac_fetchRole")]
     fn fetch_role(
         &self,
         account: AccountId,
         entity: EntityId,
-        at: BlockHash,
+        at: Option<BlockHash>,
     ) -> Result<Entity<EntityId>>;
 
     /// RPC method for extrinsic call fetchRoles
     #[method(name = "peaqrbac_fetchRoles")]
-    fn fetch_roles(&self, owner: AccountId, at: BlockHash) -> Result<Vec<Entity<EntityId>>>;
+    fn fetch_roles(&self, owner: AccountId, at: Option<BlockHash>)
+        -> Result<Vec<Entity<EntityId>>>;
+
+    /// RPC method for extrinsic call fetchRolesPage
+    #[method(name = "peaqrbac_fetchRolesPage")]
+    fn fetch_roles_page(
+        &self,
+        owner: AccountId,
+        start_after: Option<EntityId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Entity<EntityId>>>;
 
     /// RPC method for extrinsic call fetchUserRoles
     #[method(name = "peaqrbac_fetchUserRoles")]
@@ -42,8 +69,19 @@ pub trait PeaqRBACApi<BlockHash, AccountId, EntityId> {
         &self,
         owner: AccountId,
         user_id: EntityId,
-        at: BlockHash,
-    ) -> Result<Vec<Role2User<EntityId>>>;
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Role2User<EntityId, BlockNumber>>>;
+
+    /// RPC method for extrinsic call fetchUserRolesPage
+    #[method(name = "peaqrbac_fetchUserRolesPage")]
+    fn fetch_user_roles_page(
+        &self,
+        owner: AccountId,
+        user_id: EntityId,
+        start_after: Option<EntityId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Role2User<EntityId, BlockNumber>>>;
 
     /// RPC method for extrinsic call fetchPermission
     #[method(name = "peaqrbac_fetchPermission")]
@@ -51,12 +89,26 @@ pub trait PeaqRBACApi<BlockHash, AccountId, EntityId> {
         &self,
         owner: AccountId,
         permission_id: EntityId,
-        at: BlockHash,
+        at: Option<BlockHash>,
     ) -> Result<Entity<EntityId>>;
 
     /// RPC method for extrinsic call fetchPermissions
     #[method(name = "peaqrbac_fetchPermissions")]
-    fn fetch_permissions(&self, owner: AccountId, at: BlockHash) -> Result<Vec<Entity<EntityId>>>;
+    fn fetch_permissions(
+        &self,
+        owner: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Entity<EntityId>>>;
+
+    /// RPC method for extrinsic call fetchPermissionsPage
+    #[method(name = "peaqrbac_fetchPermissionsPage")]
+    fn fetch_permissions_page(
+        &self,
+        owner: AccountId,
+        start_after: Option<EntityId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Entity<EntityId>>>;
 
     /// RPC method for extrinsic call fetchRolePermissions
     #[method(name = "peaqrbac_fetchRolePermissions")]
@@ -64,7 +116,7 @@ pub trait PeaqRBACApi<BlockHash, AccountId, EntityId> {
         &self,
         owner: AccountId,
         role_id: EntityId,
-        at: BlockHash,
+        at: Option<BlockHash>,
     ) -> Result<Vec<Permission2Role<EntityId>>>;
 
     /// RPC method for extrinsic call fetchGroup
@@ -73,12 +125,26 @@ pub trait PeaqRBACApi<BlockHash, AccountId, EntityId> {
         &self,
         owner: AccountId,
         group_id: EntityId,
-        at: BlockHash,
+        at: Option<BlockHash>,
     ) -> Result<Entity<EntityId>>;
 
     /// RPC method for extrinsic call fetchGroups
     #[method(name = "peaqrbac_fetchGroups")]
-    fn fetch_groups(&self, owner: AccountId, at: BlockHash) -> Result<Vec<Entity<EntityId>>>;
+    fn fetch_groups(
+        &self,
+        owner: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Entity<EntityId>>>;
+
+    /// RPC method for extrinsic call fetchGroupsPage
+    #[method(name = "peaqrbac_fetchGroupsPage")]
+    fn fetch_groups_page(
+        &self,
+        owner: AccountId,
+        start_after: Option<EntityId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Entity<EntityId>>>;
 
     /// RPC method for extrinsic call fetchGroupRoles
     #[method(name = "peaqrbac_fetchGroupRoles")]
@@ -86,7 +152,18 @@ pub trait PeaqRBACApi<BlockHash, AccountId, EntityId> {
         &self,
         owner: AccountId,
         group_id: EntityId,
-        at: BlockHash,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Role2Group<EntityId>>>;
+
+    /// RPC method for extrinsic call fetchGroupRolesPage
+    #[method(name = "peaqrbac_fetchGroupRolesPage")]
+    fn fetch_group_roles_page(
+        &self,
+        owner: AccountId,
+        group_id: EntityId,
+        start_after: Option<EntityId>,
+        limit: u32,
+        at: Option<BlockHash>,
     ) -> Result<Vec<Role2Group<EntityId>>>;
 
     /// RPC method for extrinsic call fetchUserGroups
@@ -95,8 +172,44 @@ pub trait PeaqRBACApi<BlockHash, AccountId, EntityId> {
         &self,
         owner: AccountId,
         user_id: EntityId,
-        at: BlockHash,
-    ) -> Result<Vec<User2Group<EntityId>>>;
+        at: Option<BlockHash>,
+    ) -> Result<Vec<User2Group<EntityId, BlockNumber>>>;
+
+    /// RPC method for extrinsic call fetchRoleUsers
+    #[method(name = "peaqrbac_fetchRoleUsers")]
+    fn fetch_role_users(
+        &self,
+        owner: AccountId,
+        role_id: EntityId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Role2User<EntityId, BlockNumber>>>;
+
+    /// RPC method for extrinsic call fetchGroupMembers
+    #[method(name = "peaqrbac_fetchGroupMembers")]
+    fn fetch_group_members(
+        &self,
+        owner: AccountId,
+        group_id: EntityId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<User2Group<EntityId, BlockNumber>>>;
+
+    /// RPC method for extrinsic call fetchRoleGroups
+    #[method(name = "peaqrbac_fetchRoleGroups")]
+    fn fetch_role_groups(
+        &self,
+        owner: AccountId,
+        role_id: EntityId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Role2Group<EntityId>>>;
+
+    /// RPC method for extrinsic call fetchPermissionRoles
+    #[method(name = "peaqrbac_fetchPermissionRoles")]
+    fn fetch_permission_roles(
+        &self,
+        owner: AccountId,
+        permission_id: EntityId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<Permission2Role<EntityId>>>;
 
     /// RPC method for extrinsic call fetchUserPermissions
     #[method(name = "peaqrbac_fetchUserPermissions")]
@@ -104,7 +217,7 @@ pub trait PeaqRBACApi<BlockHash, AccountId, EntityId> {
         &self,
         owner: AccountId,
         user_id: EntityId,
-        at: BlockHash,
+        at: Option<BlockHash>,
     ) -> Result<Vec<Entity<EntityId>>>;
 
     /// RPC method for extrinsic call fetchGroupPermissions
@@ -113,13 +226,66 @@ pub trait PeaqRBACApi<BlockHash, AccountId, EntityId> {
         &self,
         owner: AccountId,
         group_id: EntityId,
-        at: BlockHash,
+        at: Option<BlockHash>,
     ) -> Result<Vec<Entity<EntityId>>>;
+
+    /// RPC method for extrinsic call fetchUser
+    #[method(name = "peaqrbac_fetchUser")]
+    fn fetch_user(
+        &self,
+        owner: AccountId,
+        user_id: EntityId,
+        at: Option<BlockHash>,
+    ) -> Result<Entity<EntityId>>;
+
+    /// RPC method for extrinsic call fetchUsers
+    #[method(name = "peaqrbac_fetchUsers")]
+    fn fetch_users(&self, owner: AccountId, at: Option<BlockHash>)
+        -> Result<Vec<Entity<EntityId>>>;
+
+    /// RPC method for checking a single permission of a user
+    #[method(name = "peaqrbac_checkPermission")]
+    fn check_permission(
+        &self,
+        owner: AccountId,
+        user_id: EntityId,
+        permission_id: EntityId,
+        at: Option<BlockHash>,
+    ) -> Result<bool>;
+
+    /// RPC method for checking up to `MAX_PERMISSION_CHECKS` (userId, permissionId) pairs with
+    /// a single runtime call, each check is responded to on its own, see [`Response`]
+    #[method(name = "peaqrbac_checkPermissions")]
+    fn check_permissions(
+        &self,
+        owner: AccountId,
+        checks: Vec<(EntityId, EntityId)>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Response<bool>>>;
+
+    /// RPC method for explaining all the ways a user is granted a permission
+    #[method(name = "peaqrbac_explainUserPermission")]
+    fn explain_user_permission(
+        &self,
+        owner: AccountId,
+        user_id: EntityId,
+        permission_id: EntityId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<PermissionGrant<EntityId>>>;
+
+    /// RPC method for exporting all entities and assignments of an owner's domain
+    #[method(name = "peaqrbac_exportDomain")]
+    fn export_domain(
+        &self,
+        owner: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<RbacDomain<EntityId, BlockNumber>>;
 }
 
 /// A struct that implements the [`PeaqRBACApi`].
 pub struct PeaqRBAC<Client, Block> {
     client: Arc<Client>,
+    flat: bool,
     _marker: std::marker::PhantomData<Block>,
 }
 
@@ -128,23 +294,115 @@ impl<Client, Block> PeaqRBAC<Client, Block> {
     pub fn new(client: Arc<Client>) -> Self {
         PeaqRBAC {
             client,
+            flat: false,
             _marker: Default::default(),
         }
     }
+
+    /// Create new `PeaqRBAC`, which responds in flat mode, see [`Response`].
+    pub fn new_flat(client: Arc<Client>) -> Self {
+        PeaqRBAC {
+            client,
+            flat: true,
+            _marker: Default::default(),
+        }
+    }
+
+    /// Wraps the result of the runtime according to the response mode
+    fn respond<EntityId, T>(&self, result: RbacResult<T, RbacError>) -> Result<T>
+    where
+        EntityId: Decode + Serialize,
+    {
+        if self.flat {
+            result.map(Response::Flat).map_err(map_rbac_err::<EntityId>)
+        } else {
+            Ok(Response::Nested(result))
+        }
+    }
+}
+
+impl<Client, Block> PeaqRBAC<Client, Block>
+where
+    Block: BlockT,
+    Client: ProvideRuntimeApi<Block>,
+{
+    /// Fails if the runtime API at `at` is older than the `version` introducing a method
+    fn ensure_api_version<AccountId, EntityId, BlockNumber>(
+        &self,
+        api: &Client::Api,
+        at: <Block as BlockT>::Hash,
+        version: u32,
+    ) -> RpcResult<()>
+    where
+        Client::Api: PeaqRBACRuntimeApi<Block, AccountId, EntityId, BlockNumber>,
+        AccountId: Codec,
+        EntityId: Codec,
+        BlockNumber: Codec,
+    {
+        let api_version = api
+            .api_version::<dyn PeaqRBACRuntimeApi<Block, AccountId, EntityId, BlockNumber>>(at)
+            .map_err(map_api_err)?;
+
+        match api_version {
+            Some(api_version) if api_version >= version => Ok(()),
+            _ => Err(JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Method not supported by the runtime.",
+                Some(format!(
+                    "Requires version {version} of the runtime API, found {api_version:?}"
+                )),
+            )))),
+        }
+    }
 }
 
 pub enum Error {
     RuntimeError,
+    TooManyChecks,
+    RbacError(RbacErrorType),
 }
 
 impl From<Error> for i32 {
     fn from(e: Error) -> i32 {
         match e {
             Error::RuntimeError => 1,
+            Error::TooManyChecks => 2,
+            Error::RbacError(typ) => match typ {
+                RbacErrorType::EntityAlreadyExist => 101,
+                RbacErrorType::EntityDoesNotExist => 102,
+                RbacErrorType::EntityAuthorizationFailed => 103,
+                RbacErrorType::EntityDisabled => 104,
+                RbacErrorType::AssignmentAlreadyExist => 105,
+                RbacErrorType::AssignmentDoesNotExist => 106,
+                RbacErrorType::NameExceedMaxChar => 107,
+                RbacErrorType::StorageExceedsMaxBounds => 108,
+                RbacErrorType::EntityAlreadyEnabled => 109,
+                RbacErrorType::CycleDetected => 110,
+                RbacErrorType::MaxDepthExceeded => 111,
+                RbacErrorType::SeparationOfDutyViolation => 112,
+                RbacErrorType::InvalidRoleConstraint => 113,
+                RbacErrorType::InvalidExpiry => 114,
+            },
         }
     }
 }
 
+/// Decoded param of an RBAC error, raw bytes are hex encoded if they cannot be decoded
+#[derive(Serialize)]
+#[serde(untagged)]
+enum ErrorParam<EntityId> {
+    Entity(EntityId),
+    Name(String),
+    Raw(String),
+}
+
+/// Data of an RBAC error in flat mode
+#[derive(Serialize)]
+struct ErrorData<EntityId> {
+    typ: RbacErrorType,
+    param: ErrorParam<EntityId>,
+}
+
 /// Default error mapping in rpc methods
 #[inline]
 fn map_api_err(api_err: ApiError) -> JsonRpseeError {
@@ -155,137 +413,460 @@ fn map_api_err(api_err: ApiError) -> JsonRpseeError {
     )))
 }
 
+/// Error mapping of RBAC errors in flat mode, each error type has its own error code
+fn map_rbac_err<EntityId: Decode + Serialize>(err: RbacError) -> JsonRpseeError {
+    let param = match err.typ {
+        RbacErrorType::NameExceedMaxChar => Vec::<u8>::decode_all(&mut &err.param[..])
+            .ok()
+            .map(|name| ErrorParam::Name(String::from_utf8_lossy(&name).into_owned())),
+        _ => EntityId::decode_all(&mut &err.param[..])
+            .ok()
+            .map(ErrorParam::Entity),
+    }
+    .unwrap_or_else(|| {
+        let hex: String = err.param.iter().map(|b| format!("{b:02x}")).collect();
+        ErrorParam::Raw(format!("0x{hex}"))
+    });
+
+    JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+        Error::RbacError(err.typ.clone()).into(),
+        format!("{:?}", err.typ),
+        Some(ErrorData {
+            typ: err.typ,
+            param,
+        }),
+    )))
+}
+
 #[async_trait]
-impl<Client, Block, AccountId, EntityId>
-    PeaqRBACApiServer<<Block as BlockT>::Hash, AccountId, EntityId> for PeaqRBAC<Client, Block>
+impl<Client, Block, AccountId, EntityId, BlockNumber>
+    PeaqRBACApiServer<<Block as BlockT>::Hash, AccountId, EntityId, BlockNumber>
+    for PeaqRBAC<Client, Block>
 where
     Block: BlockT,
     Client: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-    Client::Api: PeaqRBACRuntimeApi<Block, AccountId, EntityId>,
+    Client::Api: PeaqRBACRuntimeApi<Block, AccountId, EntityId, BlockNumber>,
     AccountId: Codec,
-    EntityId: Codec,
+    EntityId: Codec + Serialize,
+    BlockNumber: Codec,
 {
     fn fetch_role(
         &self,
         account: AccountId,
         entity: EntityId,
-        at: <Block as BlockT>::Hash,
+        at: Option<<Block as BlockT>::Hash>,
     ) -> Result<Entity<EntityId>> {
         let api = self.client.runtime_api();
-        api.fetch_role(at, account, entity).map_err(map_api_err)
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api.fetch_role(at, account, entity).map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
     }
 
     fn fetch_roles(
         &self,
         owner: AccountId,
-        at: <Block as BlockT>::Hash,
+        at: Option<<Block as BlockT>::Hash>,
     ) -> Result<Vec<Entity<EntityId>>> {
         let api = self.client.runtime_api();
-        api.fetch_roles(at, owner).map_err(map_api_err)
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api.fetch_roles(at, owner).map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
+    }
+
+    fn fetch_roles_page(
+        &self,
+        owner: AccountId,
+        start_after: Option<EntityId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<Entity<EntityId>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, EntityId, BlockNumber>(&api, at, 5)?;
+        let result = api
+            .fetch_roles_page(at, owner, start_after, limit)
+            .map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
     }
 
     fn fetch_user_roles(
         &self,
         owner: AccountId,
         user_id: EntityId,
-        at: <Block as BlockT>::Hash,
-    ) -> Result<Vec<Role2User<EntityId>>> {
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<Role2User<EntityId, BlockNumber>>> {
         let api = self.client.runtime_api();
-        api.fetch_user_roles(at, owner, user_id)
-            .map_err(map_api_err)
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let api_version = api
+            .api_version::<dyn PeaqRBACRuntimeApi<Block, AccountId, EntityId, BlockNumber>>(at)
+            .map_err(map_api_err)?;
+        let result = match api_version {
+            Some(api_version) if api_version < 2 => {
+                #[allow(deprecated)]
+                let result = api
+                    .fetch_user_roles_before_version_2(at, owner, user_id)
+                    .map_err(map_api_err)?;
+                result.map(|v| v.into_iter().map(Into::into).collect())
+            }
+            _ => api
+                .fetch_user_roles(at, owner, user_id)
+                .map_err(map_api_err)?,
+        };
+        self.respond::<EntityId, _>(result)
+    }
+
+    fn fetch_user_roles_page(
+        &self,
+        owner: AccountId,
+        user_id: EntityId,
+        start_after: Option<EntityId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<Role2User<EntityId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, EntityId, BlockNumber>(&api, at, 5)?;
+        let result = api
+            .fetch_user_roles_page(at, owner, user_id, start_after, limit)
+            .map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
     }
 
     fn fetch_permission(
         &self,
         owner: AccountId,
         permission_id: EntityId,
-        at: <Block as BlockT>::Hash,
+        at: Option<<Block as BlockT>::Hash>,
     ) -> Result<Entity<EntityId>> {
         let api = self.client.runtime_api();
-        api.fetch_permission(at, owner, permission_id)
-            .map_err(map_api_err)
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api
+            .fetch_permission(at, owner, permission_id)
+            .map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
     }
 
     fn fetch_permissions(
         &self,
         owner: AccountId,
-        at: <Block as BlockT>::Hash,
+        at: Option<<Block as BlockT>::Hash>,
     ) -> Result<Vec<Entity<EntityId>>> {
         let api = self.client.runtime_api();
-        api.fetch_permissions(at, owner).map_err(map_api_err)
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api.fetch_permissions(at, owner).map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
+    }
+
+    fn fetch_permissions_page(
+        &self,
+        owner: AccountId,
+        start_after: Option<EntityId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<Entity<EntityId>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, EntityId, BlockNumber>(&api, at, 5)?;
+        let result = api
+            .fetch_permissions_page(at, owner, start_after, limit)
+            .map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
     }
 
     fn fetch_role_permissions(
         &self,
         owner: AccountId,
         role_id: EntityId,
-        at: <Block as BlockT>::Hash,
+        at: Option<<Block as BlockT>::Hash>,
     ) -> Result<Vec<Permission2Role<EntityId>>> {
         let api = self.client.runtime_api();
-        api.fetch_role_permissions(at, owner, role_id)
-            .map_err(map_api_err)
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api
+            .fetch_role_permissions(at, owner, role_id)
+            .map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
     }
 
     fn fetch_group(
         &self,
         owner: AccountId,
         group_id: EntityId,
-        at: <Block as BlockT>::Hash,
+        at: Option<<Block as BlockT>::Hash>,
     ) -> Result<Entity<EntityId>> {
         let api = self.client.runtime_api();
-        api.fetch_group(at, owner, group_id).map_err(map_api_err)
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api.fetch_group(at, owner, group_id).map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
     }
 
     fn fetch_groups(
         &self,
         owner: AccountId,
-        at: <Block as BlockT>::Hash,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<Entity<EntityId>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api.fetch_groups(at, owner).map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
+    }
+
+    fn fetch_groups_page(
+        &self,
+        owner: AccountId,
+        start_after: Option<EntityId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
     ) -> Result<Vec<Entity<EntityId>>> {
         let api = self.client.runtime_api();
-        api.fetch_groups(at, owner).map_err(map_api_err)
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, EntityId, BlockNumber>(&api, at, 5)?;
+        let result = api
+            .fetch_groups_page(at, owner, start_after, limit)
+            .map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
     }
 
     fn fetch_group_roles(
         &self,
         owner: AccountId,
         group_id: EntityId,
-        at: <Block as BlockT>::Hash,
+        at: Option<<Block as BlockT>::Hash>,
     ) -> Result<Vec<Role2Group<EntityId>>> {
         let api = self.client.runtime_api();
-        api.fetch_group_roles(at, owner, group_id)
-            .map_err(map_api_err)
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api
+            .fetch_group_roles(at, owner, group_id)
+            .map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
+    }
+
+    fn fetch_group_roles_page(
+        &self,
+        owner: AccountId,
+        group_id: EntityId,
+        start_after: Option<EntityId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<Role2Group<EntityId>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, EntityId, BlockNumber>(&api, at, 5)?;
+        let result = api
+            .fetch_group_roles_page(at, owner, group_id, start_after, limit)
+            .map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
     }
 
     fn fetch_user_groups(
         &self,
         owner: AccountId,
         user_id: EntityId,
-        at: <Block as BlockT>::Hash,
-    ) -> Result<Vec<User2Group<EntityId>>> {
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<User2Group<EntityId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let api_version = api
+            .api_version::<dyn PeaqRBACRuntimeApi<Block, AccountId, EntityId, BlockNumber>>(at)
+            .map_err(map_api_err)?;
+        let result = match api_version {
+            Some(api_version) if api_version < 2 => {
+                #[allow(deprecated)]
+                let result = api
+                    .fetch_user_groups_before_version_2(at, owner, user_id)
+                    .map_err(map_api_err)?;
+                result.map(|v| v.into_iter().map(Into::into).collect())
+            }
+            _ => api
+                .fetch_user_groups(at, owner, user_id)
+                .map_err(map_api_err)?,
+        };
+        self.respond::<EntityId, _>(result)
+    }
+
+    fn fetch_role_users(
+        &self,
+        owner: AccountId,
+        role_id: EntityId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<Role2User<EntityId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, EntityId, BlockNumber>(&api, at, 6)?;
+        let result = api
+            .fetch_role_users(at, owner, role_id)
+            .map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
+    }
+
+    fn fetch_group_members(
+        &self,
+        owner: AccountId,
+        group_id: EntityId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<User2Group<EntityId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, EntityId, BlockNumber>(&api, at, 6)?;
+        let result = api
+            .fetch_group_members(at, owner, group_id)
+            .map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
+    }
+
+    fn fetch_role_groups(
+        &self,
+        owner: AccountId,
+        role_id: EntityId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<Role2Group<EntityId>>> {
         let api = self.client.runtime_api();
-        api.fetch_user_groups(at, owner, user_id)
-            .map_err(map_api_err)
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, EntityId, BlockNumber>(&api, at, 6)?;
+        let result = api
+            .fetch_role_groups(at, owner, role_id)
+            .map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
+    }
+
+    fn fetch_permission_roles(
+        &self,
+        owner: AccountId,
+        permission_id: EntityId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<Permission2Role<EntityId>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, EntityId, BlockNumber>(&api, at, 6)?;
+        let result = api
+            .fetch_permission_roles(at, owner, permission_id)
+            .map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
     }
 
     fn fetch_user_permissions(
         &self,
         owner: AccountId,
         user_id: EntityId,
-        at: <Block as BlockT>::Hash,
+        at: Option<<Block as BlockT>::Hash>,
     ) -> Result<Vec<Entity<EntityId>>> {
         let api = self.client.runtime_api();
-        api.fetch_user_permissions(at, owner, user_id)
-            .map_err(map_api_err)
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api
+            .fetch_user_permissions(at, owner, user_id)
+            .map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
     }
 
     fn fetch_group_permissions(
         &self,
         owner: AccountId,
         group_id: EntityId,
-        at: <Block as BlockT>::Hash,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<Entity<EntityId>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let result = api
+            .fetch_group_permissions(at, owner, group_id)
+            .map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
+    }
+
+    fn fetch_user(
+        &self,
+        owner: AccountId,
+        user_id: EntityId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Entity<EntityId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, EntityId, BlockNumber>(&api, at, 7)?;
+        let result = api.fetch_user(at, owner, user_id).map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
+    }
+
+    fn fetch_users(
+        &self,
+        owner: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
     ) -> Result<Vec<Entity<EntityId>>> {
         let api = self.client.runtime_api();
-        api.fetch_group_permissions(at, owner, group_id)
-            .map_err(map_api_err)
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, EntityId, BlockNumber>(&api, at, 7)?;
+        let result = api.fetch_users(at, owner).map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
+    }
+
+    fn check_permission(
+        &self,
+        owner: AccountId,
+        user_id: EntityId,
+        permission_id: EntityId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<bool> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, EntityId, BlockNumber>(&api, at, 3)?;
+        let result = api
+            .check_permission(at, owner, user_id, permission_id)
+            .map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
+    }
+
+    fn check_permissions(
+        &self,
+        owner: AccountId,
+        checks: Vec<(EntityId, EntityId)>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Response<bool>>> {
+        if checks.len() > MAX_PERMISSION_CHECKS {
+            return Err(JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+                Error::TooManyChecks.into(),
+                "Too many checks.",
+                Some(format!(
+                    "Got {} checks, at most {MAX_PERMISSION_CHECKS} are allowed",
+                    checks.len()
+                )),
+            ))));
+        }
+
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, EntityId, BlockNumber>(&api, at, 4)?;
+        let results = api
+            .check_permissions(at, owner, checks)
+            .map_err(map_api_err)?;
+        // In flat mode the first failing check fails the whole request
+        results
+            .into_iter()
+            .map(|result| self.respond::<EntityId, _>(result))
+            .collect()
+    }
+
+    fn explain_user_permission(
+        &self,
+        owner: AccountId,
+        user_id: EntityId,
+        permission_id: EntityId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<PermissionGrant<EntityId>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, EntityId, BlockNumber>(&api, at, 8)?;
+        let result = api
+            .explain_user_permission(at, owner, user_id, permission_id)
+            .map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
+    }
+
+    fn export_domain(
+        &self,
+        owner: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<RbacDomain<EntityId, BlockNumber>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version::<AccountId, EntityId, BlockNumber>(&api, at, 9)?;
+        let result = api.export_domain(at, owner).map_err(map_api_err)?;
+        self.respond::<EntityId, _>(result)
     }
 }